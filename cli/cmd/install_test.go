@@ -80,7 +80,9 @@ func TestRender(t *testing.T) {
 					Request: "memory-request",
 				},
 			},
-			ProbeNetworks: []string{"1.0.0.0/0", "2.0.0.0/0"},
+			AdmissionRefChecks:              "warn",
+			AdmissionRejectDuplicateMatches: true,
+			ProbeNetworks:                   []string{"1.0.0.0/0", "2.0.0.0/0"},
 		},
 		Proxy: &charts.Proxy{
 			Image: &charts.Image{
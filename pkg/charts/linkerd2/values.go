@@ -223,10 +223,12 @@ type (
 
 	// PolicyController contains the fields to configure the policy controller container
 	PolicyController struct {
-		Image         *Image     `json:"image"`
-		Resources     *Resources `json:"resources"`
-		LogLevel      string     `json:"logLevel"`
-		ProbeNetworks []string   `json:"probeNetworks"`
+		Image                           *Image     `json:"image"`
+		Resources                       *Resources `json:"resources"`
+		LogLevel                        string     `json:"logLevel"`
+		AdmissionRefChecks              string     `json:"admissionRefChecks"`
+		AdmissionRejectDuplicateMatches bool       `json:"admissionRejectDuplicateMatches"`
+		ProbeNetworks                   []string   `json:"probeNetworks"`
 	}
 
 	// Image contains the details to define a container image
@@ -114,7 +114,9 @@ func TestNewValues(t *testing.T) {
 					Request: "",
 				},
 			},
-			ProbeNetworks: []string{"0.0.0.0/0", "::/0"},
+			AdmissionRefChecks:              "disabled",
+			AdmissionRejectDuplicateMatches: false,
+			ProbeNetworks:                   []string{"0.0.0.0/0", "::/0"},
 		},
 		Proxy: &Proxy{
 			EnableExternalProfiles: false,
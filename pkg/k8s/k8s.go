@@ -39,6 +39,9 @@ const (
 	HTTPRoute             = "httproute"
 
 	PolicyAPIGroup         = "policy.linkerd.io"
+	// Servers are read at v1beta2, which lacks v1beta3's port lists and
+	// Service references, so the policy controller's admission webhook
+	// rejects opaque Servers that use them.
 	PolicyServerCRDVersion = "v1beta2"
 
 	ServiceProfileAPIVersion = "linkerd.io/v1alpha2"
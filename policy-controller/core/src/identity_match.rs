@@ -12,6 +12,25 @@ pub enum IdentityMatch {
 
 // === impl IdentityMatch ===
 
+impl IdentityMatch {
+    /// Returns true if the given client identity satisfies this match.
+    pub fn matches(&self, id: &str) -> bool {
+        match self {
+            Self::Exact(name) => name == id,
+            Self::Suffix(suffix) if suffix.is_empty() => true,
+            Self::Suffix(suffix) => {
+                let labels = id.split('.').collect::<Vec<_>>();
+                labels.len() > suffix.len()
+                    && labels
+                        .iter()
+                        .rev()
+                        .zip(suffix.iter().rev())
+                        .all(|(l, s)| l == s)
+            }
+        }
+    }
+}
+
 impl FromStr for IdentityMatch {
     type Err = Infallible;
 
@@ -84,4 +103,16 @@ mod tests {
             Ok(IdentityMatch::Exact("foo.*.example.com".to_string()))
         );
     }
+
+    #[test]
+    fn matches() {
+        let id = "default.ns.serviceaccount.identity.linkerd.cluster.local";
+        let m = |s: &str| s.parse::<IdentityMatch>().unwrap();
+        assert!(m("*").matches(id));
+        assert!(m(id).matches(id));
+        assert!(m("*.ns.serviceaccount.identity.linkerd.cluster.local").matches(id));
+        assert!(!m("*.default.ns.serviceaccount.identity.linkerd.cluster.local").matches(id));
+        assert!(!m("*.other.serviceaccount.identity.linkerd.cluster.local").matches(id));
+        assert!(!m("other.ns.serviceaccount.identity.linkerd.cluster.local").matches(id));
+    }
 }
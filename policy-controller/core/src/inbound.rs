@@ -5,6 +5,7 @@ use crate::{
         FailureInjectorFilter, GroupKindName, HeaderModifierFilter, HostMatch, HttpRouteMatch,
         PathMatch, RequestRedirectFilter,
     },
+    timestamped::Timestamped,
 };
 use ahash::AHashMap as HashMap;
 use anyhow::Result;
@@ -80,7 +81,8 @@ pub trait DiscoverInboundServer<T> {
     async fn watch_inbound_server(&self, target: T) -> Result<Option<InboundServerStream>>;
 }
 
-pub type InboundServerStream = Pin<Box<dyn Stream<Item = InboundServer> + Send + Sync + 'static>>;
+pub type InboundServerStream =
+    Pin<Box<dyn Stream<Item = Timestamped<InboundServer>> + Send + Sync + 'static>>;
 
 /// Inbound server configuration.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -6,8 +6,12 @@ pub mod inbound;
 mod network_match;
 pub mod outbound;
 pub mod routes;
+pub mod simulate;
+mod timestamped;
 
-pub use self::{identity_match::IdentityMatch, network_match::NetworkMatch};
+pub use self::{
+    identity_match::IdentityMatch, network_match::NetworkMatch, timestamped::Timestamped,
+};
 pub use ipnet::{IpNet, Ipv4Net, Ipv6Net};
 
 pub const POLICY_CONTROLLER_NAME: &str = "linkerd.io/policy-controller";
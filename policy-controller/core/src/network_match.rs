@@ -12,6 +12,13 @@ pub struct NetworkMatch {
 
 // === impl NetworkMatch ===
 
+impl NetworkMatch {
+    /// Returns true if the address is in the network and not excluded.
+    pub fn matches(&self, addr: IpAddr) -> bool {
+        self.net.contains(&addr) && !self.except.iter().any(|net| net.contains(&addr))
+    }
+}
+
 impl From<IpAddr> for NetworkMatch {
     fn from(net: IpAddr) -> Self {
         IpNet::from(net).into()
@@ -1,6 +1,9 @@
-use crate::routes::{
-    FailureInjectorFilter, GroupKindNamespaceName, HeaderModifierFilter, HostMatch, HttpRouteMatch,
-    RequestRedirectFilter,
+use crate::{
+    routes::{
+        FailureInjectorFilter, GroupKindNamespaceName, HeaderModifierFilter, HostMatch,
+        HttpRouteMatch, RequestRedirectFilter,
+    },
+    timestamped::Timestamped,
 };
 use ahash::AHashMap as HashMap;
 use anyhow::Result;
@@ -16,9 +19,19 @@ pub trait DiscoverOutboundPolicy<T> {
     async fn watch_outbound_policy(&self, target: T) -> Result<Option<OutboundPolicyStream>>;
 
     fn lookup_ip(&self, addr: IpAddr, port: NonZeroU16, source_namespace: String) -> Option<T>;
+
+    /// Resolves a port on a Service, returning `None` if the Service (or a
+    /// named port) is not known.
+    fn lookup_service_port(
+        &self,
+        namespace: &str,
+        name: &str,
+        port: &ServicePortRef,
+    ) -> Option<NonZeroU16>;
 }
 
-pub type OutboundPolicyStream = Pin<Box<dyn Stream<Item = OutboundPolicy> + Send + Sync + 'static>>;
+pub type OutboundPolicyStream =
+    Pin<Box<dyn Stream<Item = Timestamped<OutboundPolicy>> + Send + Sync + 'static>>;
 
 pub struct OutboundDiscoverTarget {
     pub service_name: String,
@@ -27,6 +40,13 @@ pub struct OutboundDiscoverTarget {
     pub source_namespace: String,
 }
 
+/// Identifies a Service port by its number or by its name in the Service spec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ServicePortRef {
+    Number(NonZeroU16),
+    Name(String),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TypedOutboundRoute {
     Http(OutboundRoute<HttpRouteMatch>),
@@ -91,6 +91,50 @@ pub enum QueryParamMatch {
     Regex(String, Regex),
 }
 
+/// Describes how specific a route hostname is.
+///
+/// Variants are ordered from least to most specific, per the Gateway API:
+/// non-wildcard hostnames are preferred, and then longer hostnames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HostMatchPrecedence {
+    /// The route does not constrain hostnames.
+    Any,
+
+    /// A wildcard hostname with the given number of characters.
+    Suffix(usize),
+
+    /// A non-wildcard hostname with the given number of characters.
+    Exact(usize),
+}
+
+/// Describes how specific a route rule's path match is.
+///
+/// Variants are ordered from least to most specific. The Gateway API leaves
+/// the precedence of regular expressions to implementations; they are
+/// considered less specific than any prefix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PathMatchPrecedence {
+    Any,
+    Regex,
+
+    /// A prefix with the given number of characters.
+    Prefix(usize),
+
+    /// An exact path with the given number of characters.
+    Exact(usize),
+}
+
+/// Orders matching route rules by the Gateway API's precedence rules so that
+/// the most specific match may be selected. Fields are compared in order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RouteMatchPrecedence {
+    pub host: HostMatchPrecedence,
+    pub path: PathMatchPrecedence,
+    pub method: bool,
+    pub headers: usize,
+    pub query_params: usize,
+}
+
 // === impl GroupKindName ===
 
 impl Ord for GroupKindName {
@@ -126,8 +170,109 @@ impl GroupKindName {
     }
 }
 
+// === impl HostMatch ===
+
+impl HostMatch {
+    /// Returns the precedence of the most specific of `hostnames` that
+    /// matches `host`, if any. A route without hostnames matches all hosts.
+    pub fn find(hostnames: &[Self], host: Option<&str>) -> Option<HostMatchPrecedence> {
+        if hostnames.is_empty() {
+            return Some(HostMatchPrecedence::Any);
+        }
+        let host = host?;
+        hostnames.iter().filter_map(|h| h.matches(host)).max()
+    }
+
+    /// Returns the precedence of the most specific of `hostnames`,
+    /// irrespective of any request.
+    pub fn max_precedence(hostnames: &[Self]) -> HostMatchPrecedence {
+        hostnames
+            .iter()
+            .map(Self::precedence)
+            .max()
+            .unwrap_or(HostMatchPrecedence::Any)
+    }
+
+    pub fn precedence(&self) -> HostMatchPrecedence {
+        match self {
+            Self::Exact(h) => HostMatchPrecedence::Exact(h.len()),
+            // Account for the `*` and the separators between labels.
+            Self::Suffix { reverse_labels } => HostMatchPrecedence::Suffix(
+                reverse_labels.iter().map(|l| l.len() + 1).sum::<usize>() + 1,
+            ),
+        }
+    }
+
+    pub fn matches(&self, host: &str) -> Option<HostMatchPrecedence> {
+        let host = host.strip_suffix('.').unwrap_or(host);
+        match self {
+            Self::Exact(h) => h.eq_ignore_ascii_case(host).then(|| self.precedence()),
+            Self::Suffix { reverse_labels } => {
+                let labels = host.split('.').rev().collect::<Vec<_>>();
+                // A wildcard must match at least one additional label.
+                if labels.len() <= reverse_labels.len() {
+                    return None;
+                }
+                reverse_labels
+                    .iter()
+                    .zip(labels)
+                    .all(|(s, l)| s.eq_ignore_ascii_case(l))
+                    .then(|| self.precedence())
+            }
+        }
+    }
+}
+
 // === impl HttpRouteMatch ===
 
+impl HttpRouteMatch {
+    /// Returns the precedence of this match, irrespective of any request.
+    pub fn precedence(&self, host: HostMatchPrecedence) -> RouteMatchPrecedence {
+        RouteMatchPrecedence {
+            host,
+            path: self
+                .path
+                .as_ref()
+                .map(PathMatch::precedence)
+                .unwrap_or(PathMatchPrecedence::Any),
+            method: self.method.is_some(),
+            headers: self.headers.len(),
+            query_params: self.query_params.len(),
+        }
+    }
+
+    /// Returns the precedence of this match if the request satisfies all of
+    /// its criteria.
+    pub fn matches<B>(
+        &self,
+        host: HostMatchPrecedence,
+        req: &http::Request<B>,
+    ) -> Option<RouteMatchPrecedence> {
+        if let Some(path) = &self.path {
+            if !path.matches(req.uri().path()) {
+                return None;
+            }
+        }
+
+        if let Some(method) = &self.method {
+            if req.method() != method {
+                return None;
+            }
+        }
+
+        if !self.headers.iter().all(|h| h.matches(req.headers())) {
+            return None;
+        }
+
+        let query = req.uri().query();
+        if !self.query_params.iter().all(|q| q.matches(query)) {
+            return None;
+        }
+
+        Some(self.precedence(host))
+    }
+}
+
 impl Default for HttpRouteMatch {
     fn default() -> Self {
         Self {
@@ -158,6 +303,32 @@ impl PathMatch {
     pub fn regex(s: &str) -> Result<Self> {
         Ok(Self::Regex(Regex::new(s)?))
     }
+
+    pub fn precedence(&self) -> PathMatchPrecedence {
+        match self {
+            Self::Exact(p) => PathMatchPrecedence::Exact(p.len()),
+            Self::Prefix(p) => PathMatchPrecedence::Prefix(p.len()),
+            Self::Regex(_) => PathMatchPrecedence::Regex,
+        }
+    }
+
+    pub fn matches(&self, path: &str) -> bool {
+        match self {
+            Self::Exact(p) => p == path,
+            Self::Prefix(prefix) => {
+                // Prefixes match on path elements, so `/foo` matches `/foo`
+                // and `/foo/bar` but not `/foobar`.
+                let trimmed = prefix.trim_end_matches('/');
+                path.strip_prefix(trimmed)
+                    .map(|rest| rest.is_empty() || rest.starts_with('/'))
+                    .unwrap_or(false)
+            }
+            Self::Regex(re) => re
+                .find(path)
+                .map(|m| m.start() == 0 && m.end() == path.len())
+                .unwrap_or(false),
+        }
+    }
 }
 
 // === impl HeaderMatch ===
@@ -174,6 +345,19 @@ impl PartialEq for HeaderMatch {
 
 impl Eq for HeaderMatch {}
 
+impl HeaderMatch {
+    pub fn matches(&self, headers: &http::HeaderMap) -> bool {
+        match self {
+            Self::Exact(name, value) => headers.get_all(name).iter().any(|v| v == value),
+            Self::Regex(name, re) => headers
+                .get_all(name)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .any(|v| re.is_match(v)),
+        }
+    }
+}
+
 // === impl QueryParamMatch ===
 
 impl PartialEq for QueryParamMatch {
@@ -187,3 +371,96 @@ impl PartialEq for QueryParamMatch {
 }
 
 impl Eq for QueryParamMatch {}
+
+impl QueryParamMatch {
+    pub fn matches(&self, query: Option<&str>) -> bool {
+        let mut params = query.into_iter().flat_map(|q| q.split('&')).map(|kv| {
+            let (k, v) = kv.split_once('=').unwrap_or((kv, ""));
+            (k, v)
+        });
+        match self {
+            Self::Exact(name, value) => params.any(|(k, v)| k == name && v == value),
+            Self::Regex(name, re) => params.any(|(k, v)| k == name && re.is_match(v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(path: PathMatch) -> HttpRouteMatch {
+        HttpRouteMatch {
+            path: Some(path),
+            ..HttpRouteMatch::default()
+        }
+    }
+
+    #[test]
+    fn host_precedence() {
+        let exact = HostMatch::Exact("foo.example.com".to_string());
+        let short_exact = HostMatch::Exact("example.com".to_string());
+        let suffix = HostMatch::Suffix {
+            reverse_labels: vec!["com".to_string(), "example".to_string()],
+        };
+        assert!(exact.precedence() > short_exact.precedence());
+        assert!(short_exact.precedence() > suffix.precedence());
+        assert_eq!(suffix.precedence(), HostMatchPrecedence::Suffix(13));
+        assert!(suffix.precedence() > HostMatch::max_precedence(&[]));
+    }
+
+    #[test]
+    fn match_precedence() {
+        let host = HostMatchPrecedence::Any;
+        let exact = path(PathMatch::Exact("/foo".to_string()));
+        let long_prefix = path(PathMatch::Prefix("/foo/bar".to_string()));
+        let prefix = path(PathMatch::Prefix("/foo".to_string()));
+        let regex = path(PathMatch::regex("/foo/.*").unwrap());
+        let prefix_method = HttpRouteMatch {
+            method: Some(Method::GET),
+            ..prefix.clone()
+        };
+        let prefix_header = HttpRouteMatch {
+            headers: vec![HeaderMatch::Exact(
+                HeaderName::from_static("x-foo"),
+                HeaderValue::from_static("bar"),
+            )],
+            ..prefix.clone()
+        };
+        let prefix_query = HttpRouteMatch {
+            query_params: vec![QueryParamMatch::Exact("foo".to_string(), "bar".to_string())],
+            ..prefix.clone()
+        };
+
+        let ordered = [
+            &exact,
+            &long_prefix,
+            &prefix_method,
+            &prefix_header,
+            &prefix_query,
+            &prefix,
+            &regex,
+        ];
+        for pair in ordered.windows(2) {
+            assert!(
+                pair[0].precedence(host) > pair[1].precedence(host),
+                "{:?} must take precedence over {:?}",
+                pair[0],
+                pair[1],
+            );
+        }
+    }
+
+    #[test]
+    fn path_matches() {
+        let prefix = PathMatch::Prefix("/foo/".to_string());
+        assert!(prefix.matches("/foo"));
+        assert!(prefix.matches("/foo/bar"));
+        assert!(!prefix.matches("/foobar"));
+        assert!(PathMatch::Prefix("/".to_string()).matches("/anything"));
+
+        let regex = PathMatch::regex("/foo/[0-9]+").unwrap();
+        assert!(regex.matches("/foo/123"));
+        assert!(!regex.matches("/foo/123/bar"));
+    }
+}
@@ -0,0 +1,31 @@
+//! Evaluates discovered policies against hypothetical requests, so that
+//! operators can determine how a proxy would handle traffic without sending
+//! any.
+
+use chrono::{offset::Utc, DateTime};
+use std::cmp::Ordering;
+
+pub mod inbound;
+pub mod outbound;
+
+/// Orders routes by creation timestamp, oldest first, as they are served to
+/// proxies. Routes with timestamps sort before routes without.
+fn by_timestamp(a: Option<&DateTime<Utc>>, b: Option<&DateTime<Utc>>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (None, None) => Ordering::Equal,
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+    }
+}
+
+/// Returns the request's host from its URI or, failing that, its `host`
+/// header, without any port.
+fn request_host<B>(req: &http::Request<B>) -> Option<String> {
+    if let Some(authority) = req.uri().authority() {
+        return Some(authority.host().to_string());
+    }
+    let host = req.headers().get(http::header::HOST)?.to_str().ok()?;
+    let authority = host.parse::<http::uri::Authority>().ok()?;
+    Some(authority.host().to_string())
+}
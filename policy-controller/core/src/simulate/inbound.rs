@@ -0,0 +1,444 @@
+use crate::{
+    inbound::{
+        AuthorizationRef, ClientAuthentication, ClientAuthorization, HttpRoute, HttpRouteRef,
+        InboundServer, ProxyProtocol, ServerRef,
+    },
+    routes::{HostMatch, HttpRouteMatch, RouteMatchPrecedence},
+};
+use ahash::AHashMap as HashMap;
+use ipnet::IpNet;
+use std::net::IpAddr;
+
+/// Describes the client side of a simulated inbound connection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Client {
+    pub addr: IpAddr,
+    pub tls: ClientTls,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientTls {
+    /// The connection is not meshed.
+    None,
+
+    /// The connection uses mesh TLS but the client has no identity.
+    Unauthenticated,
+
+    /// The connection uses mutually-authenticated mesh TLS.
+    Authenticated(String),
+}
+
+/// The outcome of evaluating an `InboundServer` against a client and,
+/// optionally, an HTTP request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decision {
+    pub server: ServerRef,
+
+    /// The route and rule selected for the request. This is only set for
+    /// HTTP requests on servers that are not opaque.
+    pub route: Option<RouteMatch>,
+    pub verdict: Verdict,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteMatch {
+    pub route: HttpRouteRef,
+
+    /// The index of the matched rule within the route.
+    pub rule: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The request is permitted by the referenced authorization.
+    Allow(AuthorizationRef),
+
+    /// No authorization permits the client.
+    Unauthorized,
+
+    /// No route matches the request, so the proxy responds with a 404.
+    NotFound,
+}
+
+/// Evaluates how a proxy would handle a connection (and, if `req` is set,
+/// an HTTP request on that connection) given the discovered server policy.
+///
+/// Authorizations that do not constrain source networks apply to the
+/// `cluster_networks`, as configured on proxies by the gRPC server.
+pub fn evaluate<B>(
+    server: &InboundServer,
+    cluster_networks: &[IpNet],
+    client: &Client,
+    req: Option<&http::Request<B>>,
+) -> Decision {
+    let req = match (&server.protocol, req) {
+        (ProxyProtocol::Opaque | ProxyProtocol::Tls, _) | (_, None) => {
+            return Decision {
+                server: server.reference.clone(),
+                route: None,
+                verdict: authorize(&server.authorizations, cluster_networks, client),
+            }
+        }
+        (_, Some(req)) => req,
+    };
+
+    let Some((rm, route)) = select_route(&server.http_routes, req) else {
+        return Decision {
+            server: server.reference.clone(),
+            route: None,
+            verdict: Verdict::NotFound,
+        };
+    };
+
+    // Server-level authorizations apply to all of the server's routes.
+    let authzs = route
+        .authorizations
+        .iter()
+        .chain(&server.authorizations)
+        .collect::<HashMap<_, _>>();
+    Decision {
+        server: server.reference.clone(),
+        route: Some(rm),
+        verdict: authorize(authzs, cluster_networks, client),
+    }
+}
+
+/// Selects the most specific rule that matches the request. Ties are won by
+/// the route with the most specific hostnames, then by the oldest route, then
+/// by route reference, and then by the earliest rule in the route, mirroring
+/// the order in which routes are served to proxies.
+pub fn select_route<'r, B>(
+    routes: &'r HashMap<HttpRouteRef, HttpRoute>,
+    req: &http::Request<B>,
+) -> Option<(RouteMatch, &'r HttpRoute)> {
+    let host = super::request_host(req);
+
+    let mut best: Option<(RouteMatchPrecedence, RouteMatch, &HttpRoute)> = None;
+    for (rref, route) in routes {
+        let Some(hp) = HostMatch::find(&route.hostnames, host.as_deref()) else {
+            continue;
+        };
+        for (idx, rule) in route.rules.iter().enumerate() {
+            let precedence = if rule.matches.is_empty() {
+                // A rule without matches matches all requests.
+                HttpRouteMatch::default().matches(hp, req)
+            } else {
+                rule.matches.iter().filter_map(|m| m.matches(hp, req)).max()
+            };
+            let Some(precedence) = precedence else {
+                continue;
+            };
+
+            let replace = match &best {
+                None => true,
+                Some((p, m, r)) => {
+                    precedence.cmp(p).then_with(|| {
+                        // Routes served earlier (and earlier rules) win ties.
+                        HostMatch::max_precedence(&route.hostnames)
+                            .cmp(&HostMatch::max_precedence(&r.hostnames))
+                            .then_with(|| {
+                                super::by_timestamp(
+                                    r.creation_timestamp.as_ref(),
+                                    route.creation_timestamp.as_ref(),
+                                )
+                            })
+                            .then_with(|| m.route.cmp(rref))
+                            .then_with(|| m.rule.cmp(&idx))
+                    }) == std::cmp::Ordering::Greater
+                }
+            };
+            if replace {
+                let rm = RouteMatch {
+                    route: rref.clone(),
+                    rule: idx,
+                };
+                best = Some((precedence, rm, route));
+            }
+        }
+    }
+
+    best.map(|(_, rm, route)| (rm, route))
+}
+
+fn authorize<'a>(
+    authzs: impl IntoIterator<Item = (&'a AuthorizationRef, &'a ClientAuthorization)>,
+    cluster_networks: &[IpNet],
+    client: &Client,
+) -> Verdict {
+    authzs
+        .into_iter()
+        .filter(|(_, authz)| permits(authz, cluster_networks, client))
+        .map(|(aref, _)| aref)
+        // Prefer a deterministic result when several authorizations apply.
+        .min_by_key(|aref| authz_key(aref))
+        .map(|aref| Verdict::Allow(aref.clone()))
+        .unwrap_or(Verdict::Unauthorized)
+}
+
+fn permits(authz: &ClientAuthorization, cluster_networks: &[IpNet], client: &Client) -> bool {
+    let in_network = if authz.networks.is_empty() {
+        cluster_networks
+            .iter()
+            .any(|net| net.contains(&client.addr))
+    } else {
+        authz.networks.iter().any(|net| net.matches(client.addr))
+    };
+    if !in_network {
+        return false;
+    }
+
+    match (&authz.authentication, &client.tls) {
+        (ClientAuthentication::Unauthenticated, _) => true,
+        (ClientAuthentication::TlsUnauthenticated, tls) => *tls != ClientTls::None,
+        (ClientAuthentication::TlsAuthenticated(ids), ClientTls::Authenticated(id)) => {
+            ids.iter().any(|m| m.matches(id))
+        }
+        (ClientAuthentication::TlsAuthenticated(_), _) => false,
+    }
+}
+
+fn authz_key(aref: &AuthorizationRef) -> (u8, &str) {
+    match aref {
+        AuthorizationRef::AuthorizationPolicy(n) => (0, n),
+        AuthorizationRef::ServerAuthorization(n) => (1, n),
+        AuthorizationRef::Default(n) => (2, n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        inbound::HttpRouteRule,
+        routes::{GroupKindName, Method, PathMatch},
+        IdentityMatch, NetworkMatch,
+    };
+    use std::time::Duration;
+
+    fn client(addr: &str, tls: ClientTls) -> Client {
+        Client {
+            addr: addr.parse().unwrap(),
+            tls,
+        }
+    }
+
+    fn request(uri: &str) -> http::Request<()> {
+        http::Request::get(uri).body(()).unwrap()
+    }
+
+    fn cluster_networks() -> Vec<IpNet> {
+        vec!["10.0.0.0/8".parse().unwrap()]
+    }
+
+    fn route(name: &'static str, path: PathMatch) -> (HttpRouteRef, HttpRoute) {
+        let rref = HttpRouteRef::Linkerd(GroupKindName {
+            group: "policy.linkerd.io".into(),
+            kind: "HTTPRoute".into(),
+            name: name.into(),
+        });
+        let route = HttpRoute {
+            rules: vec![HttpRouteRule {
+                matches: vec![HttpRouteMatch {
+                    path: Some(path),
+                    ..HttpRouteMatch::default()
+                }],
+                filters: vec![],
+            }],
+            ..HttpRoute::default()
+        };
+        (rref, route)
+    }
+
+    fn server(
+        protocol: ProxyProtocol,
+        authorizations: impl IntoIterator<Item = (AuthorizationRef, ClientAuthorization)>,
+        http_routes: impl IntoIterator<Item = (HttpRouteRef, HttpRoute)>,
+    ) -> InboundServer {
+        InboundServer {
+            reference: ServerRef::Server("srv".to_string()),
+            protocol,
+            authorizations: authorizations.into_iter().collect(),
+            http_routes: http_routes.into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn network_excepts() {
+        let authz = AuthorizationRef::AuthorizationPolicy("nets".to_string());
+        let srv = server(
+            ProxyProtocol::Opaque,
+            Some((
+                authz.clone(),
+                ClientAuthorization {
+                    networks: vec![NetworkMatch {
+                        net: "192.168.0.0/16".parse().unwrap(),
+                        except: vec!["192.168.1.0/24".parse().unwrap()],
+                    }],
+                    authentication: ClientAuthentication::Unauthenticated,
+                },
+            )),
+            None,
+        );
+
+        let allowed = client("192.168.2.1", ClientTls::None);
+        let decision = evaluate::<()>(&srv, &cluster_networks(), &allowed, None);
+        assert_eq!(decision.verdict, Verdict::Allow(authz));
+
+        let excepted = client("192.168.1.1", ClientTls::None);
+        let decision = evaluate::<()>(&srv, &cluster_networks(), &excepted, None);
+        assert_eq!(decision.verdict, Verdict::Unauthorized);
+    }
+
+    #[test]
+    fn empty_networks_use_cluster_networks() {
+        let authz = AuthorizationRef::Default("all-unauthenticated");
+        let srv = server(
+            ProxyProtocol::Opaque,
+            Some((
+                authz.clone(),
+                ClientAuthorization {
+                    networks: vec![],
+                    authentication: ClientAuthentication::Unauthenticated,
+                },
+            )),
+            None,
+        );
+
+        let inside = client("10.1.2.3", ClientTls::None);
+        let decision = evaluate::<()>(&srv, &cluster_networks(), &inside, None);
+        assert_eq!(decision.verdict, Verdict::Allow(authz));
+
+        let outside = client("192.168.1.1", ClientTls::None);
+        let decision = evaluate::<()>(&srv, &cluster_networks(), &outside, None);
+        assert_eq!(decision.verdict, Verdict::Unauthorized);
+    }
+
+    #[test]
+    fn identities() {
+        let authz = AuthorizationRef::AuthorizationPolicy("ids".to_string());
+        let srv = server(
+            ProxyProtocol::Opaque,
+            Some((
+                authz.clone(),
+                ClientAuthorization {
+                    networks: vec![NetworkMatch::from(IpNet::from(
+                        "0.0.0.0/0".parse::<ipnet::Ipv4Net>().unwrap(),
+                    ))],
+                    authentication: ClientAuthentication::TlsAuthenticated(vec![
+                        IdentityMatch::Suffix(vec!["ns".to_string(), "cluster".to_string()]),
+                    ]),
+                },
+            )),
+            None,
+        );
+
+        let meshed = client("10.1.1.1", ClientTls::Authenticated("sa.ns.cluster".into()));
+        let decision = evaluate::<()>(&srv, &cluster_networks(), &meshed, None);
+        assert_eq!(decision.verdict, Verdict::Allow(authz));
+
+        for tls in [
+            ClientTls::Authenticated("sa.other.cluster".into()),
+            ClientTls::Unauthenticated,
+            ClientTls::None,
+        ] {
+            let decision =
+                evaluate::<()>(&srv, &cluster_networks(), &client("10.1.1.1", tls), None);
+            assert_eq!(decision.verdict, Verdict::Unauthorized);
+        }
+    }
+
+    #[test]
+    fn most_specific_route() {
+        let authz = AuthorizationRef::AuthorizationPolicy("route".to_string());
+        let (api_ref, mut api) = route("api", PathMatch::Prefix("/api".to_string()));
+        api.authorizations.insert(
+            authz.clone(),
+            ClientAuthorization {
+                networks: vec![],
+                authentication: ClientAuthentication::Unauthenticated,
+            },
+        );
+        let srv = server(
+            ProxyProtocol::Detect {
+                timeout: Duration::from_secs(10),
+            },
+            None,
+            [
+                route("root", PathMatch::Prefix("/".to_string())),
+                (api_ref.clone(), api),
+            ],
+        );
+        let c = client("10.1.1.1", ClientTls::None);
+
+        let decision = evaluate(&srv, &cluster_networks(), &c, Some(&request("/api/v1")));
+        assert_eq!(
+            decision.route,
+            Some(RouteMatch {
+                route: api_ref,
+                rule: 0
+            })
+        );
+        assert_eq!(decision.verdict, Verdict::Allow(authz));
+
+        // Prefixes match whole path segments.
+        let decision = evaluate(&srv, &cluster_networks(), &c, Some(&request("/apix")));
+        assert_eq!(
+            decision.route.map(|rm| rm.route),
+            Some(route("root", PathMatch::Prefix("/".to_string())).0)
+        );
+        assert_eq!(decision.verdict, Verdict::Unauthorized);
+    }
+
+    #[test]
+    fn earlier_rule_wins_ties() {
+        let (rref, mut r) = route("web", PathMatch::Prefix("/".to_string()));
+        r.rules.push(HttpRouteRule {
+            matches: vec![
+                HttpRouteMatch {
+                    path: Some(PathMatch::Exact("/foo".to_string())),
+                    ..HttpRouteMatch::default()
+                },
+                HttpRouteMatch {
+                    path: Some(PathMatch::Prefix("/".to_string())),
+                    ..HttpRouteMatch::default()
+                },
+            ],
+            filters: vec![],
+        });
+        let srv = server(ProxyProtocol::Http1, None, Some((rref.clone(), r)));
+        let c = client("10.1.1.1", ClientTls::None);
+
+        // Equally specific matches are served in the order of their rules, so
+        // the first of several equally specific rules is selected.
+        let decision = evaluate(&srv, &cluster_networks(), &c, Some(&request("/x")));
+        assert_eq!(
+            decision.route,
+            Some(RouteMatch {
+                route: rref.clone(),
+                rule: 0
+            })
+        );
+
+        let decision = evaluate(&srv, &cluster_networks(), &c, Some(&request("/foo")));
+        assert_eq!(
+            decision.route,
+            Some(RouteMatch {
+                route: rref,
+                rule: 1
+            })
+        );
+    }
+
+    #[test]
+    fn no_route_not_found() {
+        let (rref, mut r) = route("health", PathMatch::Exact("/healthz".to_string()));
+        r.rules[0].matches[0].method = Some(Method::GET);
+        let srv = server(ProxyProtocol::Http1, None, Some((rref, r)));
+        let c = client("10.1.1.1", ClientTls::None);
+
+        let req = http::Request::post("/healthz").body(()).unwrap();
+        let decision = evaluate(&srv, &cluster_networks(), &c, Some(&req));
+        assert_eq!(decision.route, None);
+        assert_eq!(decision.verdict, Verdict::NotFound);
+    }
+}
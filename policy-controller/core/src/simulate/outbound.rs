@@ -0,0 +1,423 @@
+use crate::{
+    outbound::{
+        Backend, Filter, OutboundPolicy, OutboundRoute, OutboundRouteCollection, WeightedService,
+    },
+    routes::{GroupKindNamespaceName, HostMatch, HttpRouteMatch, RouteMatchPrecedence},
+};
+use std::time;
+
+/// The outcome of evaluating an `OutboundPolicy` against an HTTP request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decision {
+    pub route: RouteSelection,
+
+    /// Filters applied by the selected rule, in order.
+    pub filters: Vec<Filter>,
+
+    /// The backends among which requests are distributed by weight. Each
+    /// backend may apply its own filters.
+    pub backends: Vec<Backend>,
+
+    pub request_timeout: Option<time::Duration>,
+    pub backend_request_timeout: Option<time::Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RouteSelection {
+    /// The service is opaque, so requests are not routed.
+    Opaque,
+
+    /// No routes apply to the service, so the default route forwards all
+    /// requests to it.
+    Default,
+
+    /// The request matched a rule of a route.
+    Route {
+        route: GroupKindNamespaceName,
+
+        /// The index of the matched rule within the route.
+        rule: usize,
+    },
+
+    /// No route matches the request, so the proxy responds with a 404.
+    NotFound,
+}
+
+/// Evaluates how a proxy would route an HTTP request given the discovered
+/// outbound policy.
+pub fn evaluate<B>(policy: &OutboundPolicy, req: &http::Request<B>) -> Decision {
+    let default_backend = || {
+        vec![Backend::Service(WeightedService {
+            weight: 1,
+            authority: policy.authority.clone(),
+            name: policy.name.clone(),
+            namespace: policy.namespace.clone(),
+            port: policy.port,
+            filters: vec![],
+            exists: true,
+        })]
+    };
+
+    if policy.opaque {
+        return Decision {
+            route: RouteSelection::Opaque,
+            filters: vec![],
+            backends: default_backend(),
+            request_timeout: None,
+            backend_request_timeout: None,
+        };
+    }
+
+    let routes = match &policy.routes {
+        OutboundRouteCollection::Empty => {
+            return Decision {
+                route: RouteSelection::Default,
+                filters: vec![],
+                backends: default_backend(),
+                request_timeout: None,
+                backend_request_timeout: None,
+            }
+        }
+        OutboundRouteCollection::Http(routes) => routes,
+    };
+
+    let host = super::request_host(req).or_else(|| {
+        let authority = policy.authority.parse::<http::uri::Authority>().ok()?;
+        Some(authority.host().to_string())
+    });
+    let Some((route, rule)) = select_route(routes, host.as_deref(), req) else {
+        return Decision {
+            route: RouteSelection::NotFound,
+            filters: vec![],
+            backends: vec![],
+            request_timeout: None,
+            backend_request_timeout: None,
+        };
+    };
+
+    let r = &routes[&route].rules[rule];
+    // Rules without backends forward requests to the service itself.
+    let backends = if r.backends.is_empty() {
+        default_backend()
+    } else {
+        r.backends.clone()
+    };
+    Decision {
+        route: RouteSelection::Route { route, rule },
+        filters: r.filters.clone(),
+        backends,
+        request_timeout: r.request_timeout,
+        backend_request_timeout: r.backend_request_timeout,
+    }
+}
+
+/// Selects the most specific rule that matches the request. Ties are won by
+/// the route with the most specific hostnames, then by the oldest route, then
+/// by route namespace and name, and then by the earliest rule in the route,
+/// mirroring the order in which routes are served to proxies.
+fn select_route<'r, B>(
+    routes: impl IntoIterator<
+        Item = (
+            &'r GroupKindNamespaceName,
+            &'r OutboundRoute<HttpRouteMatch>,
+        ),
+    >,
+    host: Option<&str>,
+    req: &http::Request<B>,
+) -> Option<(GroupKindNamespaceName, usize)> {
+    type Best<'r> = (
+        RouteMatchPrecedence,
+        &'r GroupKindNamespaceName,
+        &'r OutboundRoute<HttpRouteMatch>,
+        usize,
+    );
+
+    let mut best: Option<Best<'r>> = None;
+    for (id, route) in routes {
+        let Some(hp) = HostMatch::find(&route.hostnames, host) else {
+            continue;
+        };
+        for (idx, rule) in route.rules.iter().enumerate() {
+            let precedence = if rule.matches.is_empty() {
+                // A rule without matches matches all requests.
+                HttpRouteMatch::default().matches(hp, req)
+            } else {
+                rule.matches.iter().filter_map(|m| m.matches(hp, req)).max()
+            };
+            let Some(precedence) = precedence else {
+                continue;
+            };
+
+            let replace = match &best {
+                None => true,
+                Some((p, bid, br, bidx)) => {
+                    precedence.cmp(p).then_with(|| {
+                        // Routes served earlier (and earlier rules) win ties.
+                        HostMatch::max_precedence(&route.hostnames)
+                            .cmp(&HostMatch::max_precedence(&br.hostnames))
+                            .then_with(|| {
+                                super::by_timestamp(
+                                    br.creation_timestamp.as_ref(),
+                                    route.creation_timestamp.as_ref(),
+                                )
+                            })
+                            .then_with(|| bid.namespace.cmp(&id.namespace))
+                            .then_with(|| bid.name.cmp(&id.name))
+                            .then_with(|| bidx.cmp(&idx))
+                    }) == std::cmp::Ordering::Greater
+                }
+            };
+            if replace {
+                best = Some((precedence, id, route, idx));
+            }
+        }
+    }
+
+    best.map(|(_, id, _, idx)| (id.clone(), idx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        outbound::OutboundRouteRule,
+        routes::{HeaderModifierFilter, HeaderName, HeaderValue, PathMatch},
+    };
+    use ahash::AHashMap as HashMap;
+    use chrono::TimeZone;
+
+    fn policy(routes: OutboundRouteCollection) -> OutboundPolicy {
+        OutboundPolicy {
+            routes,
+            authority: "web.ns.svc.cluster.local:80".to_string(),
+            name: "web".to_string(),
+            namespace: "ns".to_string(),
+            port: 80.try_into().unwrap(),
+            opaque: false,
+            accrual: None,
+        }
+    }
+
+    fn gknn(ns: &'static str, name: &'static str) -> GroupKindNamespaceName {
+        GroupKindNamespaceName {
+            group: "gateway.networking.k8s.io".into(),
+            kind: "HTTPRoute".into(),
+            namespace: ns.into(),
+            name: name.into(),
+        }
+    }
+
+    fn route(
+        secs: i64,
+        path: PathMatch,
+        backends: Vec<Backend>,
+        filters: Vec<Filter>,
+    ) -> OutboundRoute<HttpRouteMatch> {
+        OutboundRoute {
+            hostnames: vec![],
+            rules: vec![OutboundRouteRule {
+                matches: vec![HttpRouteMatch {
+                    path: Some(path),
+                    ..HttpRouteMatch::default()
+                }],
+                backends,
+                request_timeout: None,
+                backend_request_timeout: None,
+                filters,
+            }],
+            creation_timestamp: Some(chrono::Utc.timestamp_opt(secs, 0).unwrap()),
+        }
+    }
+
+    fn backend(name: &str, weight: u32) -> Backend {
+        Backend::Service(WeightedService {
+            weight,
+            authority: format!("{name}.ns.svc.cluster.local:80"),
+            name: name.to_string(),
+            namespace: "ns".to_string(),
+            port: 80.try_into().unwrap(),
+            filters: vec![],
+            exists: true,
+        })
+    }
+
+    fn request(uri: &str) -> http::Request<()> {
+        http::Request::get(uri).body(()).unwrap()
+    }
+
+    #[test]
+    fn default_route() {
+        let policy = policy(OutboundRouteCollection::Empty);
+        let decision = evaluate(&policy, &request("/"));
+        assert_eq!(decision.route, RouteSelection::Default);
+        assert_eq!(decision.backends.len(), 1);
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let filter = Filter::RequestHeaderModifier(HeaderModifierFilter {
+            add: vec![],
+            set: vec![(
+                HeaderName::from_static("x-canary"),
+                HeaderValue::from_static("true"),
+            )],
+            remove: vec![],
+        });
+        let routes = HashMap::from_iter([
+            (
+                gknn("ns", "root"),
+                route(0, PathMatch::Prefix("/".to_string()), vec![], vec![]),
+            ),
+            (
+                gknn("ns", "api"),
+                route(
+                    1,
+                    PathMatch::Prefix("/api".to_string()),
+                    vec![backend("web-v1", 90), backend("web-v2", 10)],
+                    vec![filter.clone()],
+                ),
+            ),
+        ]);
+        let policy = policy(OutboundRouteCollection::Http(routes));
+
+        let decision = evaluate(&policy, &request("/api/users"));
+        assert_eq!(
+            decision.route,
+            RouteSelection::Route {
+                route: gknn("ns", "api"),
+                rule: 0
+            }
+        );
+        assert_eq!(decision.filters, vec![filter]);
+        assert_eq!(
+            decision.backends,
+            vec![backend("web-v1", 90), backend("web-v2", 10)]
+        );
+
+        let decision = evaluate(&policy, &request("/"));
+        assert_eq!(
+            decision.route,
+            RouteSelection::Route {
+                route: gknn("ns", "root"),
+                rule: 0
+            }
+        );
+        assert_eq!(decision.backends.len(), 1);
+    }
+
+    #[test]
+    fn oldest_route_wins_ties() {
+        let routes = HashMap::from_iter([
+            (
+                gknn("consumer", "newer"),
+                route(10, PathMatch::Prefix("/".to_string()), vec![], vec![]),
+            ),
+            (
+                gknn("ns", "older"),
+                route(5, PathMatch::Prefix("/".to_string()), vec![], vec![]),
+            ),
+        ]);
+        let policy = policy(OutboundRouteCollection::Http(routes));
+
+        let decision = evaluate(&policy, &request("/"));
+        assert_eq!(
+            decision.route,
+            RouteSelection::Route {
+                route: gknn("ns", "older"),
+                rule: 0
+            }
+        );
+    }
+
+    #[test]
+    fn specific_hostnames_win_ties() {
+        let mut specific = route(10, PathMatch::Prefix("/".to_string()), vec![], vec![]);
+        specific.hostnames = vec![
+            HostMatch::Suffix {
+                reverse_labels: vec!["com".to_string(), "example".to_string()],
+            },
+            HostMatch::Exact("web.example.com".to_string()),
+        ];
+        let mut wildcard = route(5, PathMatch::Prefix("/".to_string()), vec![], vec![]);
+        wildcard.hostnames = vec![HostMatch::Suffix {
+            reverse_labels: vec!["com".to_string(), "example".to_string()],
+        }];
+        let routes = HashMap::from_iter([
+            (gknn("ns", "specific"), specific),
+            (gknn("ns", "wildcard"), wildcard),
+        ]);
+        let policy = policy(OutboundRouteCollection::Http(routes));
+
+        // Both routes match the request's hostname equally, but routes with
+        // more specific hostnames are served first, so they win ties.
+        let req = http::Request::get("http://api.example.com/")
+            .body(())
+            .unwrap();
+        let decision = evaluate(&policy, &req);
+        assert_eq!(
+            decision.route,
+            RouteSelection::Route {
+                route: gknn("ns", "specific"),
+                rule: 0
+            }
+        );
+    }
+
+    #[test]
+    fn earlier_rule_wins_ties() {
+        let mut web = route(0, PathMatch::Prefix("/".to_string()), vec![], vec![]);
+        web.rules.push(OutboundRouteRule {
+            matches: vec![
+                HttpRouteMatch {
+                    path: Some(PathMatch::Exact("/foo".to_string())),
+                    ..HttpRouteMatch::default()
+                },
+                HttpRouteMatch {
+                    path: Some(PathMatch::Prefix("/".to_string())),
+                    ..HttpRouteMatch::default()
+                },
+            ],
+            backends: vec![],
+            request_timeout: None,
+            backend_request_timeout: None,
+            filters: vec![],
+        });
+        let policy = policy(OutboundRouteCollection::Http(HashMap::from_iter([(
+            gknn("ns", "web"),
+            web,
+        )])));
+
+        // Equally specific matches are served in the order of their rules, so
+        // the first of several equally specific rules is selected.
+        let decision = evaluate(&policy, &request("/x"));
+        assert_eq!(
+            decision.route,
+            RouteSelection::Route {
+                route: gknn("ns", "web"),
+                rule: 0
+            }
+        );
+
+        let decision = evaluate(&policy, &request("/foo"));
+        assert_eq!(
+            decision.route,
+            RouteSelection::Route {
+                route: gknn("ns", "web"),
+                rule: 1
+            }
+        );
+    }
+
+    #[test]
+    fn no_match_not_found() {
+        let routes = HashMap::from_iter([(
+            gknn("ns", "exact"),
+            route(0, PathMatch::Exact("/foo".to_string()), vec![], vec![]),
+        )]);
+        let policy = policy(OutboundRouteCollection::Http(routes));
+
+        let decision = evaluate(&policy, &request("/bar"));
+        assert_eq!(decision.route, RouteSelection::NotFound);
+        assert!(decision.backends.is_empty());
+    }
+}
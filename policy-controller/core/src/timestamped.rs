@@ -0,0 +1,70 @@
+use std::{ops::Deref, time::Instant};
+
+/// A value annotated with the time at which the index update that produced it
+/// was applied.
+///
+/// The timestamp is not considered when comparing values, so that it does not
+/// defeat change detection.
+#[derive(Clone, Debug)]
+pub struct Timestamped<T> {
+    value: T,
+    updated_at: Instant,
+}
+
+// === impl Timestamped ===
+
+impl<T> Timestamped<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            updated_at: Instant::now(),
+        }
+    }
+
+    /// The time at which the value was last modified.
+    pub fn updated_at(&self) -> Instant {
+        self.updated_at
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Replaces the value, updating the timestamp.
+    pub fn set(&mut self, value: T) {
+        self.value = value;
+        self.updated_at = Instant::now();
+    }
+
+    /// Modifies the value in place, updating the timestamp if `f` returns
+    /// true to indicate that the value changed.
+    pub fn modify(&mut self, f: impl FnOnce(&mut T) -> bool) -> bool {
+        let modified = f(&mut self.value);
+        if modified {
+            self.updated_at = Instant::now();
+        }
+        modified
+    }
+}
+
+impl<T> Deref for Timestamped<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Timestamped<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: PartialEq> PartialEq<T> for Timestamped<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.value == *other
+    }
+}
+
+impl<T: Eq> Eq for Timestamped<T> {}
@@ -0,0 +1,202 @@
+use crate::workload::{Kind, Workload};
+use std::sync::Arc;
+use tonic::transport::Certificate;
+
+/// Looks up the mesh identities of workloads.
+pub trait WorkloadIdentity: Send + Sync + 'static {
+    /// Returns the mesh identity of the workload, if it is known.
+    fn workload_identity(&self, workload: &Workload) -> Option<String>;
+}
+
+/// Authorizes clients of the policy APIs.
+///
+/// When enabled, clients must present a TLS client certificate and may only
+/// discover policies on behalf of the workload whose mesh identity matches
+/// that certificate.
+#[derive(Clone)]
+pub struct ClientAuthz(Option<Arc<dyn WorkloadIdentity>>);
+
+// === impl ClientAuthz ===
+
+impl ClientAuthz {
+    /// Permits all clients.
+    pub fn disabled() -> Self {
+        Self(None)
+    }
+
+    /// Requires that clients present the identity of the workload for which
+    /// they discover policies.
+    pub fn identity(identities: impl WorkloadIdentity) -> Self {
+        Self(Some(Arc::new(identities)))
+    }
+
+    /// Checks that the request's client is permitted to discover policies for
+    /// the given workload.
+    pub(crate) fn check<T>(
+        &self,
+        req: &tonic::Request<T>,
+        workload: &Workload,
+    ) -> Result<(), tonic::Status> {
+        self.check_peer_certs(req.peer_certs().as_deref().map(Vec::as_slice), workload)
+    }
+
+    fn check_peer_certs(
+        &self,
+        certs: Option<&[Certificate]>,
+        workload: &Workload,
+    ) -> Result<(), tonic::Status> {
+        let Some(identities) = &self.0 else {
+            return Ok(());
+        };
+
+        let certs = certs
+            .filter(|certs| !certs.is_empty())
+            .ok_or_else(|| tonic::Status::unauthenticated("a client certificate is required"))?;
+        // The first certificate is the client's; any others are intermediates.
+        let names = dns_names(certs[0].get_ref()).ok_or_else(|| {
+            tonic::Status::unauthenticated("the client certificate could not be parsed")
+        })?;
+
+        let permitted = identities
+            .workload_identity(workload)
+            .map(|id| names.iter().any(|name| *name == id))
+            .unwrap_or(false);
+        if !permitted {
+            tracing::info!(
+                ?names,
+                namespace = %workload.namespace,
+                workload = %workload_name(workload),
+                "Client is not permitted to discover policies for workload",
+            );
+            return Err(tonic::Status::permission_denied(
+                "the client's identity does not match the workload",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ClientAuthz {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ClientAuthz")
+            .field(&self.0.is_some())
+            .finish()
+    }
+}
+
+fn workload_name(workload: &Workload) -> &str {
+    match &workload.kind {
+        Kind::Pod(name) | Kind::External(name) => name,
+    }
+}
+
+/// Returns the DNS subject alternative names of a DER-encoded X.509
+/// certificate, or `None` if the certificate cannot be parsed.
+///
+/// Mesh identities are DNS-like names, so other kinds of names (e.g. URIs)
+/// never match a workload's identity.
+fn dns_names(cert: &[u8]) -> Option<Vec<String>> {
+    let cert = webpki::EndEntityCert::try_from(cert).ok()?;
+    let names = cert
+        .dns_names()
+        .ok()?
+        .map(|name| <&str>::from(name).to_string())
+        .collect();
+    Some(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Identities;
+
+    impl WorkloadIdentity for Identities {
+        fn workload_identity(&self, workload: &Workload) -> Option<String> {
+            match &workload.kind {
+                Kind::Pod(name) => Some(format!(
+                    "{name}.{}.serviceaccount.identity.linkerd.cluster.local",
+                    workload.namespace
+                )),
+                Kind::External(_) => None,
+            }
+        }
+    }
+
+    fn pod(name: &str) -> Workload {
+        Workload {
+            namespace: "ns-0".to_string(),
+            kind: Kind::Pod(name.to_string()),
+        }
+    }
+
+    fn mk_cert(names: &[&str]) -> Certificate {
+        let names = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let cert = rcgen::generate_simple_self_signed(names).expect("certificate must be valid");
+        // Peer certificates are DER-encoded, despite the constructor's name.
+        Certificate::from_pem(cert.serialize_der().expect("certificate must serialize"))
+    }
+
+    #[test]
+    fn permits_matching_identity() {
+        let authz = ClientAuthz::identity(Identities);
+        let certs = [mk_cert(&[
+            "other.example.com",
+            "pod-0.ns-0.serviceaccount.identity.linkerd.cluster.local",
+        ])];
+        assert!(authz.check_peer_certs(Some(&certs), &pod("pod-0")).is_ok());
+    }
+
+    #[test]
+    fn denies_mismatched_identity() {
+        let authz = ClientAuthz::identity(Identities);
+        let certs = [mk_cert(&[
+            "pod-1.ns-0.serviceaccount.identity.linkerd.cluster.local",
+        ])];
+        let status = authz
+            .check_peer_certs(Some(&certs), &pod("pod-0"))
+            .expect_err("client must not be permitted");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        // Workloads without a known identity are never permitted.
+        let external = Workload {
+            namespace: "ns-0".to_string(),
+            kind: Kind::External("pod-1".to_string()),
+        };
+        let status = authz
+            .check_peer_certs(Some(&certs), &external)
+            .expect_err("client must not be permitted");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn requires_certificate() {
+        let authz = ClientAuthz::identity(Identities);
+        for certs in [None, Some(&[][..])] {
+            let status = authz
+                .check_peer_certs(certs, &pod("pod-0"))
+                .expect_err("client must not be permitted");
+            assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        }
+
+        let garbage = [Certificate::from_pem(b"garbage")];
+        let status = authz
+            .check_peer_certs(Some(&garbage), &pod("pod-0"))
+            .expect_err("client must not be permitted");
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        // Requests from plaintext connections have no certificates.
+        let req = tonic::Request::new(());
+        let status = authz
+            .check(&req, &pod("pod-0"))
+            .expect_err("client must not be permitted");
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn disabled_permits_all() {
+        let authz = ClientAuthz::disabled();
+        assert!(authz.check(&tonic::Request::new(()), &pod("pod-0")).is_ok());
+    }
+}
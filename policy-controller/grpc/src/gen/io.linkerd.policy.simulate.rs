@@ -0,0 +1,554 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InboundRequest {
+    /// Identifies the workload, formatted as in the inbound API's `PortSpec`.
+    #[prost(string, tag = "1")]
+    pub workload: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub port: u32,
+    #[prost(message, optional, tag = "3")]
+    pub client: ::core::option::Option<Client>,
+    /// If unset, only connection-level authorization is evaluated.
+    #[prost(message, optional, tag = "4")]
+    pub http: ::core::option::Option<HttpRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Client {
+    #[prost(message, optional, tag = "1")]
+    pub ip: ::core::option::Option<::linkerd2_proxy_api::net::IpAddress>,
+    #[prost(oneof = "client::Tls", tags = "2, 3, 4")]
+    pub tls: ::core::option::Option<client::Tls>,
+}
+/// Nested message and enum types in `Client`.
+pub mod client {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Plaintext {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Anonymous {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Tls {
+        /// Set when the connection is not meshed.
+        #[prost(message, tag = "2")]
+        Plaintext(Plaintext),
+        /// Set when the client uses mesh TLS but has no identity.
+        #[prost(message, tag = "3")]
+        Anonymous(Anonymous),
+        /// The client's mesh identity.
+        #[prost(string, tag = "4")]
+        Identity(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HttpRequest {
+    /// Defaults to GET.
+    #[prost(message, optional, tag = "1")]
+    pub method: ::core::option::Option<::linkerd2_proxy_api::http_types::HttpMethod>,
+    /// The request's `:authority` or `host`.
+    #[prost(string, tag = "2")]
+    pub authority: ::prost::alloc::string::String,
+    /// The request's path, including any query string.
+    #[prost(string, tag = "3")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub headers: ::prost::alloc::vec::Vec<http_request::Header>,
+}
+/// Nested message and enum types in `HttpRequest`.
+pub mod http_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Header {
+        #[prost(string, tag = "1")]
+        pub name: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub value: ::prost::alloc::string::String,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InboundDecision {
+    #[prost(message, optional, tag = "1")]
+    pub server: ::core::option::Option<::linkerd2_proxy_api::meta::Metadata>,
+    /// Set when the request was matched by an HTTP route.
+    #[prost(message, optional, tag = "2")]
+    pub route: ::core::option::Option<RouteMatch>,
+    #[prost(oneof = "inbound_decision::Verdict", tags = "3, 4, 5")]
+    pub verdict: ::core::option::Option<inbound_decision::Verdict>,
+}
+/// Nested message and enum types in `InboundDecision`.
+pub mod inbound_decision {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Unauthorized {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NotFound {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Verdict {
+        /// The authorization that permits the request.
+        #[prost(message, tag = "3")]
+        Allow(::linkerd2_proxy_api::meta::Metadata),
+        /// No authorization permits the client.
+        #[prost(message, tag = "4")]
+        Unauthorized(Unauthorized),
+        /// No route matches the request.
+        #[prost(message, tag = "5")]
+        NotFound(NotFound),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteMatch {
+    #[prost(message, optional, tag = "1")]
+    pub route: ::core::option::Option<::linkerd2_proxy_api::meta::Metadata>,
+    /// The index of the matched rule within the route.
+    #[prost(uint32, tag = "2")]
+    pub rule: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OutboundRequest {
+    /// The service's authority, i.e. `<name>.<namespace>.svc.<cluster-domain>:<port>`.
+    #[prost(string, tag = "1")]
+    pub authority: ::prost::alloc::string::String,
+    /// Identifies the client workload, formatted as in the outbound API's
+    /// `TrafficSpec`. Routes are resolved for the workload's namespace.
+    #[prost(string, tag = "2")]
+    pub source_workload: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub http: ::core::option::Option<HttpRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OutboundDecision {
+    #[prost(message, optional, tag = "1")]
+    pub service: ::core::option::Option<::linkerd2_proxy_api::meta::Metadata>,
+    /// Filters applied by the selected rule, in order.
+    #[prost(message, repeated, tag = "5")]
+    pub filters: ::prost::alloc::vec::Vec<
+        ::linkerd2_proxy_api::outbound::http_route::Filter,
+    >,
+    /// The backends among which requests are distributed by weight.
+    #[prost(message, repeated, tag = "6")]
+    pub backends: ::prost::alloc::vec::Vec<
+        ::linkerd2_proxy_api::outbound::http_route::WeightedRouteBackend,
+    >,
+    #[prost(message, optional, tag = "7")]
+    pub request_timeout: ::core::option::Option<::prost_types::Duration>,
+    #[prost(oneof = "outbound_decision::Route", tags = "2, 3, 4")]
+    pub route: ::core::option::Option<outbound_decision::Route>,
+}
+/// Nested message and enum types in `OutboundDecision`.
+pub mod outbound_decision {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Opaque {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NotFound {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Route {
+        /// The service is opaque, so requests are not routed.
+        #[prost(message, tag = "2")]
+        Opaque(Opaque),
+        /// The request matched a route rule. Default routes are described by a
+        /// `default` metadata.
+        #[prost(message, tag = "3")]
+        Http(super::RouteMatch),
+        /// No route matches the request.
+        #[prost(message, tag = "4")]
+        NotFound(NotFound),
+    }
+}
+/// Generated client implementations.
+pub mod policy_simulator_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// An API exposed to operators to determine how proxies would apply policy to
+    /// hypothetical traffic, without sending any.
+    ///
+    /// Responses reflect the controller's current view of the cluster, which is the
+    /// same view that is served to proxies.
+    #[derive(Debug, Clone)]
+    pub struct PolicySimulatorClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl<T> PolicySimulatorClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PolicySimulatorClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            PolicySimulatorClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Evaluates the inbound policy of a workload's port against a client and,
+        /// optionally, an HTTP request.
+        pub async fn inbound(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InboundRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InboundDecision>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/io.linkerd.policy.simulate.PolicySimulator/Inbound",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "io.linkerd.policy.simulate.PolicySimulator",
+                        "Inbound",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Evaluates the outbound policy of a service, as seen from a client
+        /// workload, against an HTTP request.
+        pub async fn outbound(
+            &mut self,
+            request: impl tonic::IntoRequest<super::OutboundRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OutboundDecision>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/io.linkerd.policy.simulate.PolicySimulator/Outbound",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "io.linkerd.policy.simulate.PolicySimulator",
+                        "Outbound",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod policy_simulator_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PolicySimulatorServer.
+    #[async_trait]
+    pub trait PolicySimulator: Send + Sync + 'static {
+        /// Evaluates the inbound policy of a workload's port against a client and,
+        /// optionally, an HTTP request.
+        async fn inbound(
+            &self,
+            request: tonic::Request<super::InboundRequest>,
+        ) -> std::result::Result<tonic::Response<super::InboundDecision>, tonic::Status>;
+        /// Evaluates the outbound policy of a service, as seen from a client
+        /// workload, against an HTTP request.
+        async fn outbound(
+            &self,
+            request: tonic::Request<super::OutboundRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OutboundDecision>,
+            tonic::Status,
+        >;
+    }
+    /// An API exposed to operators to determine how proxies would apply policy to
+    /// hypothetical traffic, without sending any.
+    ///
+    /// Responses reflect the controller's current view of the cluster, which is the
+    /// same view that is served to proxies.
+    #[derive(Debug)]
+    pub struct PolicySimulatorServer<T: PolicySimulator> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: PolicySimulator> PolicySimulatorServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PolicySimulatorServer<T>
+    where
+        T: PolicySimulator,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/io.linkerd.policy.simulate.PolicySimulator/Inbound" => {
+                    #[allow(non_camel_case_types)]
+                    struct InboundSvc<T: PolicySimulator>(pub Arc<T>);
+                    impl<
+                        T: PolicySimulator,
+                    > tonic::server::UnaryService<super::InboundRequest>
+                    for InboundSvc<T> {
+                        type Response = super::InboundDecision;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::InboundRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PolicySimulator>::inbound(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = InboundSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/io.linkerd.policy.simulate.PolicySimulator/Outbound" => {
+                    #[allow(non_camel_case_types)]
+                    struct OutboundSvc<T: PolicySimulator>(pub Arc<T>);
+                    impl<
+                        T: PolicySimulator,
+                    > tonic::server::UnaryService<super::OutboundRequest>
+                    for OutboundSvc<T> {
+                        type Response = super::OutboundDecision;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::OutboundRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PolicySimulator>::outbound(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = OutboundSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: PolicySimulator> Clone for PolicySimulatorServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: PolicySimulator> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: PolicySimulator> tonic::server::NamedService for PolicySimulatorServer<T> {
+        const NAME: &'static str = "io.linkerd.policy.simulate.PolicySimulator";
+    }
+}
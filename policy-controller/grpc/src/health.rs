@@ -0,0 +1,221 @@
+use futures::prelude::*;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+pub mod proto {
+    include!("gen/grpc.health.v1.rs");
+}
+
+use self::proto::{
+    health_check_response::ServingStatus,
+    health_server::{Health, HealthServer as Svc},
+    HealthCheckRequest, HealthCheckResponse,
+};
+
+/// Serves the standard `grpc.health.v1.Health` API.
+///
+/// All of the policy APIs are backed by the same indexes, so they share a
+/// single serving status, which is also reported for the server as a whole
+/// (i.e. the empty service name).
+#[derive(Clone, Debug)]
+pub struct HealthServer {
+    services: Arc<[&'static str]>,
+    status: watch::Receiver<ServingStatus>,
+    drain: drain::Watch,
+}
+
+/// Updates the serving status reported by a [`HealthServer`].
+#[derive(Debug)]
+pub struct HealthReporter(watch::Sender<ServingStatus>);
+
+// === impl HealthServer ===
+
+impl HealthServer {
+    /// Returns a health server for the named services, which are not serving
+    /// until the reporter marks them so.
+    pub fn new(
+        services: impl IntoIterator<Item = &'static str>,
+        drain: drain::Watch,
+    ) -> (HealthReporter, Self) {
+        let (tx, status) = watch::channel(ServingStatus::NotServing);
+        let srv = Self {
+            services: services.into_iter().collect(),
+            status,
+            drain,
+        };
+        (HealthReporter(tx), srv)
+    }
+
+    pub fn svc(self) -> Svc<Self> {
+        Svc::new(self)
+    }
+
+    fn is_known(&self, service: &str) -> bool {
+        service.is_empty() || self.services.contains(&service)
+    }
+}
+
+#[async_trait::async_trait]
+impl Health for HealthServer {
+    async fn check(
+        &self,
+        req: tonic::Request<HealthCheckRequest>,
+    ) -> Result<tonic::Response<HealthCheckResponse>, tonic::Status> {
+        let HealthCheckRequest { service } = req.into_inner();
+        if !self.is_known(&service) {
+            return Err(tonic::Status::not_found(format!(
+                "unknown service: {service}"
+            )));
+        }
+
+        let status = *self.status.borrow();
+        Ok(tonic::Response::new(response(status)))
+    }
+
+    type WatchStream = BoxWatchStream;
+
+    async fn watch(
+        &self,
+        req: tonic::Request<HealthCheckRequest>,
+    ) -> Result<tonic::Response<BoxWatchStream>, tonic::Status> {
+        let HealthCheckRequest { service } = req.into_inner();
+        let status = self.is_known(&service).then(|| self.status.clone());
+        Ok(tonic::Response::new(response_stream(
+            self.drain.clone(),
+            status,
+        )))
+    }
+}
+
+type BoxWatchStream =
+    std::pin::Pin<Box<dyn Stream<Item = Result<HealthCheckResponse, tonic::Status>> + Send + Sync>>;
+
+fn response_stream(
+    drain: drain::Watch,
+    status: Option<watch::Receiver<ServingStatus>>,
+) -> BoxWatchStream {
+    Box::pin(async_stream::try_stream! {
+        tokio::pin! {
+            let shutdown = drain.signaled();
+        }
+
+        let mut status = match status {
+            Some(status) => status,
+            None => {
+                // Services are fixed when the server is built, so an unknown
+                // service never becomes known.
+                yield response(ServingStatus::ServiceUnknown);
+                let _ = shutdown.await;
+                return;
+            }
+        };
+
+        let current = *status.borrow_and_update();
+        yield response(current);
+
+        loop {
+            tokio::select! {
+                biased;
+
+                res = status.changed() => match res {
+                    Ok(()) => {
+                        let current = *status.borrow_and_update();
+                        yield response(current);
+                    }
+                    Err(_) => return,
+                },
+
+                // If the server starts shutting down, report that it is no
+                // longer serving and close the stream so that it doesn't hold
+                // the server open.
+                _ = (&mut shutdown) => {
+                    yield response(ServingStatus::NotServing);
+                    return;
+                }
+            }
+        }
+    })
+}
+
+fn response(status: ServingStatus) -> HealthCheckResponse {
+    HealthCheckResponse {
+        status: status.into(),
+    }
+}
+
+// === impl HealthReporter ===
+
+impl HealthReporter {
+    pub fn set_serving(&self) {
+        self.set(ServingStatus::Serving);
+    }
+
+    pub fn set_not_serving(&self) {
+        self.set(ServingStatus::NotServing);
+    }
+
+    fn set(&self, status: ServingStatus) {
+        self.0.send_if_modified(|current| {
+            if *current == status {
+                return false;
+            }
+            tracing::info!(status = status.as_str_name(), "Health status changed");
+            *current = status;
+            true
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_status() {
+        let (drain_tx, drain_rx) = drain::channel();
+        let (reporter, srv) = HealthServer::new(["io.linkerd.test.Test"], drain_rx);
+
+        let check = |service: &str| {
+            let srv = srv.clone();
+            let req = tonic::Request::new(HealthCheckRequest {
+                service: service.to_string(),
+            });
+            async move { srv.check(req).await.map(|rsp| rsp.into_inner().status) }
+        };
+        assert_eq!(check("").await.unwrap(), ServingStatus::NotServing as i32);
+        assert_eq!(
+            check("unknown").await.unwrap_err().code(),
+            tonic::Code::NotFound
+        );
+
+        let mut updates = srv
+            .watch(tonic::Request::new(HealthCheckRequest {
+                service: "io.linkerd.test.Test".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let mut next = || {
+            updates
+                .next()
+                .now_or_never()
+                .flatten()
+                .map(|rsp| rsp.unwrap().status)
+        };
+        assert_eq!(next(), Some(ServingStatus::NotServing as i32));
+
+        reporter.set_serving();
+        assert_eq!(
+            check("io.linkerd.test.Test").await.unwrap(),
+            ServingStatus::Serving as i32
+        );
+        assert_eq!(next(), Some(ServingStatus::Serving as i32));
+        assert_eq!(next(), None);
+
+        // Watches report that the server is not serving when it drains.
+        drop(srv);
+        tokio::spawn(drain_tx.drain());
+        tokio::task::yield_now().await;
+        assert_eq!(next(), Some(ServingStatus::NotServing as i32));
+    }
+}
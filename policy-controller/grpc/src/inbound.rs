@@ -1,4 +1,11 @@
-use crate::{routes, workload::Workload};
+use crate::{
+    authz::ClientAuthz,
+    limits::{WatchLimits, WatchPermit},
+    metrics::{GrpcServerMetrics, WatchGuard},
+    routes,
+    updates::Updates,
+    workload::{self, Workload},
+};
 use futures::prelude::*;
 use linkerd2_proxy_api::{
     self as api,
@@ -17,7 +24,7 @@ use linkerd_policy_controller_core::{
     IdentityMatch, IpNet, NetworkMatch,
 };
 use maplit::*;
-use std::{num::NonZeroU16, str::FromStr, sync::Arc};
+use std::{num::NonZeroU16, str::FromStr, sync::Arc, time::Duration};
 use tracing::trace;
 
 #[derive(Clone, Debug)]
@@ -25,6 +32,10 @@ pub struct InboundPolicyServer<T> {
     discover: T,
     drain: drain::Watch,
     cluster_networks: Arc<[IpNet]>,
+    metrics: GrpcServerMetrics,
+    debounce: Duration,
+    authz: ClientAuthz,
+    limits: WatchLimits,
 }
 
 // === impl InboundPolicyServer ===
@@ -33,11 +44,23 @@ impl<T> InboundPolicyServer<T>
 where
     T: DiscoverInboundServer<(Workload, NonZeroU16)> + Send + Sync + 'static,
 {
-    pub fn new(discover: T, cluster_networks: Vec<IpNet>, drain: drain::Watch) -> Self {
+    pub fn new(
+        discover: T,
+        cluster_networks: Vec<IpNet>,
+        drain: drain::Watch,
+        metrics: GrpcServerMetrics,
+        debounce: Duration,
+        authz: ClientAuthz,
+        limits: WatchLimits,
+    ) -> Self {
         Self {
             discover,
             drain,
             cluster_networks: cluster_networks.into(),
+            metrics,
+            debounce,
+            authz,
+            limits,
         }
     }
 
@@ -45,20 +68,32 @@ where
         InboundServerPoliciesServer::new(self)
     }
 
+    /// Parses the requested workload port, checking that the client is
+    /// permitted to discover its policy.
     fn check_target(
         &self,
-        proto::PortSpec { workload, port }: proto::PortSpec,
+        req: &tonic::Request<proto::PortSpec>,
     ) -> Result<(Workload, NonZeroU16), tonic::Status> {
-        let workload = Workload::from_str(&workload)?;
-        // Ensure that the port is in the valid range.
-        let port = u16::try_from(port)
-            .and_then(NonZeroU16::try_from)
-            .map_err(|_| tonic::Status::invalid_argument(format!("Invalid port: {port}")))?;
-
-        Ok((workload, port))
+        let proto::PortSpec { workload, port } = req.get_ref();
+        let target = parse_target(workload, *port)?;
+        self.authz.check(req, &target.0)?;
+        Ok(target)
     }
 }
 
+pub(crate) fn parse_target(
+    workload: &str,
+    port: u32,
+) -> Result<(Workload, NonZeroU16), tonic::Status> {
+    let workload = Workload::from_str(workload)?;
+    // Ensure that the port is in the valid range.
+    let port = u16::try_from(port)
+        .and_then(NonZeroU16::try_from)
+        .map_err(|_| tonic::Status::invalid_argument(format!("Invalid port: {port}")))?;
+
+    Ok((workload, port))
+}
+
 #[async_trait::async_trait]
 impl<T> InboundServerPolicies for InboundPolicyServer<T>
 where
@@ -68,18 +103,24 @@ where
         &self,
         req: tonic::Request<proto::PortSpec>,
     ) -> Result<tonic::Response<proto::Server>, tonic::Status> {
-        let target = self.check_target(req.into_inner())?;
-
-        // Lookup the configuration for an inbound port. If the pod hasn't (yet)
-        // been indexed, return a Not Found error.
-        let s = self
-            .discover
-            .get_inbound_server(target)
-            .await
-            .map_err(|e| tonic::Status::internal(format!("lookup failed: {}", e)))?
-            .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
-
-        Ok(tonic::Response::new(to_server(&s, &self.cluster_networks)))
+        let res = async {
+            let target = self.check_target(&req)?;
+
+            // Lookup the configuration for an inbound port. If the pod hasn't (yet)
+            // been indexed, return a Not Found error.
+            let s = self
+                .discover
+                .get_inbound_server(target)
+                .await
+                .map_err(|e| tonic::Status::internal(format!("lookup failed: {}", e)))?
+                .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
+
+            let server = to_server(&s, &self.cluster_networks);
+            self.metrics.sent_message("get_port", &server);
+            Ok(tonic::Response::new(server))
+        }
+        .await;
+        self.metrics.record("get_port", res)
     }
 
     type WatchPortStream = BoxWatchStream;
@@ -88,19 +129,32 @@ where
         &self,
         req: tonic::Request<proto::PortSpec>,
     ) -> Result<tonic::Response<BoxWatchStream>, tonic::Status> {
-        let target = self.check_target(req.into_inner())?;
-        let drain = self.drain.clone();
-        let rx = self
-            .discover
-            .watch_inbound_server(target)
-            .await
-            .map_err(|e| tonic::Status::internal(format!("lookup failed: {}", e)))?
-            .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
-        Ok(tonic::Response::new(response_stream(
-            drain,
-            rx,
-            self.cluster_networks.clone(),
-        )))
+        let res = async {
+            let target = self.check_target(&req)?;
+            let permit = self.limits.acquire_for(&target.0, &self.metrics)?;
+            let namespace = target.0.namespace.clone();
+            let kind = match target.0.kind {
+                workload::Kind::Pod(_) => "pod",
+                workload::Kind::External(_) => "external_workload",
+            };
+            let drain = self.drain.clone();
+            let rx = self
+                .discover
+                .watch_inbound_server(target)
+                .await
+                .map_err(|e| tonic::Status::internal(format!("lookup failed: {}", e)))?
+                .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
+            let watch = self.metrics.watch("watch_port", &namespace, kind);
+            Ok(tonic::Response::new(response_stream(
+                drain,
+                Updates::new(rx, self.debounce),
+                self.cluster_networks.clone(),
+                watch,
+                permit,
+            )))
+        }
+        .await;
+        self.metrics.record("watch_port", res)
     }
 }
 
@@ -109,10 +163,14 @@ type BoxWatchStream =
 
 fn response_stream(
     drain: drain::Watch,
-    mut rx: InboundServerStream,
+    mut updates: Updates<InboundServerStream>,
     cluster_networks: Arc<[IpNet]>,
+    mut watch: WatchGuard,
+    permit: WatchPermit,
 ) -> BoxWatchStream {
     Box::pin(async_stream::try_stream! {
+        // Hold the permit until the stream completes.
+        let _permit = permit;
         tokio::pin! {
             let shutdown = drain.signaled();
         }
@@ -120,9 +178,16 @@ fn response_stream(
         loop {
             tokio::select! {
                 // When the port is updated with a new server, update the server watch.
-                res = rx.next() => match res {
+                res = updates.next() => match res {
                     Some(s) => {
-                        yield to_server(&s, &cluster_networks);
+                        let server = to_server(&s, &cluster_networks);
+                        if !updates.is_modified(&server) {
+                            trace!("Skipping redundant update");
+                            watch.skipped();
+                            continue;
+                        }
+                        watch.sent(s.updated_at(), &server);
+                        yield server;
                     }
                     None => return,
                 },
@@ -207,27 +272,7 @@ fn to_authz(
     }: &ClientAuthorization,
     cluster_networks: &[IpNet],
 ) -> proto::Authz {
-    let meta = Metadata {
-        kind: Some(match reference {
-            AuthorizationRef::Default(name) => metadata::Kind::Default(name.to_string()),
-            AuthorizationRef::AuthorizationPolicy(name) => {
-                metadata::Kind::Resource(api::meta::Resource {
-                    group: "policy.linkerd.io".to_string(),
-                    kind: "authorizationpolicy".to_string(),
-                    name: name.to_string(),
-                    ..Default::default()
-                })
-            }
-            AuthorizationRef::ServerAuthorization(name) => {
-                metadata::Kind::Resource(api::meta::Resource {
-                    group: "policy.linkerd.io".to_string(),
-                    kind: "serverauthorization".to_string(),
-                    name: name.clone(),
-                    ..Default::default()
-                })
-            }
-        }),
-    };
+    let meta = authz_metadata(reference);
 
     // TODO labels are deprecated, but we want to continue to support them for older proxies. This
     // can be removed in 2.13.
@@ -324,11 +369,64 @@ fn to_authz(
     }
 }
 
+pub(crate) fn authz_metadata(reference: &AuthorizationRef) -> Metadata {
+    Metadata {
+        kind: Some(match reference {
+            AuthorizationRef::Default(name) => metadata::Kind::Default(name.to_string()),
+            AuthorizationRef::AuthorizationPolicy(name) => {
+                metadata::Kind::Resource(api::meta::Resource {
+                    group: "policy.linkerd.io".to_string(),
+                    kind: "authorizationpolicy".to_string(),
+                    name: name.to_string(),
+                    ..Default::default()
+                })
+            }
+            AuthorizationRef::ServerAuthorization(name) => {
+                metadata::Kind::Resource(api::meta::Resource {
+                    group: "policy.linkerd.io".to_string(),
+                    kind: "serverauthorization".to_string(),
+                    name: name.clone(),
+                    ..Default::default()
+                })
+            }
+        }),
+    }
+}
+
+pub(crate) fn http_route_metadata(reference: &HttpRouteRef) -> Metadata {
+    Metadata {
+        kind: Some(match reference {
+            HttpRouteRef::Default(name) => metadata::Kind::Default(name.to_string()),
+            HttpRouteRef::Linkerd(gkn) => metadata::Kind::Resource(api::meta::Resource {
+                group: gkn.group.to_string(),
+                kind: gkn.kind.to_string(),
+                name: gkn.name.to_string(),
+                ..Default::default()
+            }),
+        }),
+    }
+}
+
+pub(crate) fn server_metadata(reference: &ServerRef) -> Metadata {
+    Metadata {
+        kind: Some(match reference {
+            ServerRef::Default(name) => metadata::Kind::Default(name.to_string()),
+            ServerRef::Server(name) => metadata::Kind::Resource(api::meta::Resource {
+                group: "policy.linkerd.io".to_string(),
+                kind: "server".to_string(),
+                name: name.clone(),
+                ..Default::default()
+            }),
+        }),
+    }
+}
+
 fn to_http_route_list<'r>(
     routes: impl IntoIterator<Item = (&'r HttpRouteRef, &'r HttpRoute)>,
     cluster_networks: &[IpNet],
 ) -> Vec<proto::HttpRoute> {
-    // Per the Gateway API spec:
+    // Per the Gateway API spec, routes with the most specific hostnames take
+    // precedence. Then:
     //
     // > If ties still exist across multiple Routes, matching precedence MUST be
     // > determined in order of the following criteria, continuing on ties:
@@ -342,14 +440,16 @@ fn to_http_route_list<'r>(
     // namespace.
     let mut route_list = routes.into_iter().collect::<Vec<_>>();
     route_list.sort_by(|(a_ref, a), (b_ref, b)| {
-        let by_ts = match (&a.creation_timestamp, &b.creation_timestamp) {
-            (Some(a_ts), Some(b_ts)) => a_ts.cmp(b_ts),
-            (None, None) => std::cmp::Ordering::Equal,
-            // Routes with timestamps are preferred over routes without.
-            (Some(_), None) => return std::cmp::Ordering::Less,
-            (None, Some(_)) => return std::cmp::Ordering::Greater,
-        };
-        by_ts.then_with(|| a_ref.cmp(b_ref))
+        routes::cmp_hostnames(&a.hostnames, &b.hostnames).then_with(|| {
+            let by_ts = match (&a.creation_timestamp, &b.creation_timestamp) {
+                (Some(a_ts), Some(b_ts)) => a_ts.cmp(b_ts),
+                (None, None) => std::cmp::Ordering::Equal,
+                // Routes with timestamps are preferred over routes without.
+                (Some(_), None) => return std::cmp::Ordering::Less,
+                (None, Some(_)) => return std::cmp::Ordering::Greater,
+            };
+            by_ts.then_with(|| a_ref.cmp(b_ref))
+        })
     });
 
     route_list
@@ -368,24 +468,14 @@ fn to_http_route(
     }: HttpRoute,
     cluster_networks: &[IpNet],
 ) -> proto::HttpRoute {
-    let metadata = Metadata {
-        kind: Some(match reference {
-            HttpRouteRef::Default(name) => metadata::Kind::Default(name.to_string()),
-            HttpRouteRef::Linkerd(gkn) => metadata::Kind::Resource(api::meta::Resource {
-                group: gkn.group.to_string(),
-                kind: gkn.kind.to_string(),
-                name: gkn.name.to_string(),
-                ..Default::default()
-            }),
-        }),
-    };
+    let metadata = http_route_metadata(reference);
 
     let hosts = hostnames
         .into_iter()
         .map(routes::convert_host_match)
         .collect();
 
-    let rules = rules
+    let rules = routes::sort_rules(rules, |r| &mut r.matches)
         .into_iter()
         .map(
             |HttpRouteRule { matches, filters }| proto::http_route::Rule {
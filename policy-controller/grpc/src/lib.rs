@@ -3,6 +3,13 @@
 
 mod routes;
 
+pub mod authz;
+pub mod health;
 pub mod inbound;
+pub mod limits;
+pub mod metrics;
 pub mod outbound;
+pub mod reflection;
+pub mod simulate;
+mod updates;
 pub mod workload;
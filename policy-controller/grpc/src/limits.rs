@@ -0,0 +1,174 @@
+use crate::{metrics::GrpcServerMetrics, workload::Workload};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// Limits the number of concurrent watch streams, both per client and across
+/// all clients.
+///
+/// A client is the workload on whose behalf policies are discovered, rather
+/// than the connection that requests them: proxies in a meshed control plane
+/// forward requests over pooled loopback connections, so remote addresses do
+/// not distinguish clients. When client authorization is enabled, the workload
+/// must match the client's TLS identity; otherwise, the workload is as claimed
+/// by the client, and only the global limit bounds a client that claims many
+/// workloads.
+///
+/// The limits are shared by all of the policy APIs, so a single instance
+/// should be used for every server.
+#[derive(Clone, Debug)]
+pub struct WatchLimits(Arc<Inner>);
+
+/// Holds a watch stream's place within the limits until it is dropped.
+#[derive(Debug)]
+pub(crate) struct WatchPermit {
+    limits: Arc<Inner>,
+    client: Workload,
+}
+
+/// Describes which limit prevented a watch from being started.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Exceeded {
+    Client,
+    Global,
+}
+
+#[derive(Debug)]
+struct Inner {
+    per_client: usize,
+    global: usize,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    total: usize,
+    clients: HashMap<Workload, usize>,
+}
+
+// === impl WatchLimits ===
+
+impl WatchLimits {
+    pub fn new(per_client: usize, global: usize) -> Self {
+        Self(Arc::new(Inner {
+            per_client,
+            global,
+            state: Mutex::default(),
+        }))
+    }
+
+    /// Acquires a permit for a new watch from the given client workload.
+    pub(crate) fn acquire(&self, client: &Workload) -> Result<WatchPermit, Exceeded> {
+        let mut state = self.0.state.lock().expect("watch limits lock poisoned");
+        if state.total >= self.0.global {
+            return Err(Exceeded::Global);
+        }
+        let watches = state.clients.get(client).copied().unwrap_or(0);
+        if watches >= self.0.per_client {
+            return Err(Exceeded::Client);
+        }
+        state.clients.insert(client.clone(), watches + 1);
+        state.total += 1;
+
+        Ok(WatchPermit {
+            limits: self.0.clone(),
+            client: client.clone(),
+        })
+    }
+
+    /// Acquires a permit for a new watch from the given client workload,
+    /// failing with `RESOURCE_EXHAUSTED` if a limit has been reached.
+    ///
+    /// The client must already have been authorized to discover policies for
+    /// the workload.
+    pub(crate) fn acquire_for(
+        &self,
+        client: &Workload,
+        metrics: &GrpcServerMetrics,
+    ) -> Result<WatchPermit, tonic::Status> {
+        self.acquire(client).map_err(|exceeded| {
+            tracing::info!(
+                namespace = %client.namespace,
+                workload = ?client.kind,
+                limit = exceeded.as_str(),
+                "Watch limit exceeded",
+            );
+            metrics.watch_rejected(exceeded.as_str());
+            match exceeded {
+                Exceeded::Client => {
+                    tonic::Status::resource_exhausted("too many watches for this client")
+                }
+                Exceeded::Global => tonic::Status::resource_exhausted("too many watches"),
+            }
+        })
+    }
+}
+
+// === impl WatchPermit ===
+
+impl Drop for WatchPermit {
+    fn drop(&mut self) {
+        let mut state = match self.limits.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        state.total -= 1;
+        if let Some(watches) = state.clients.get_mut(&self.client) {
+            *watches -= 1;
+            if *watches == 0 {
+                state.clients.remove(&self.client);
+            }
+        }
+    }
+}
+
+// === impl Exceeded ===
+
+impl Exceeded {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Client => "client",
+            Self::Global => "global",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workload::Kind;
+
+    fn pod(name: &str) -> Workload {
+        Workload {
+            namespace: "ns-0".to_string(),
+            kind: Kind::Pod(name.to_string()),
+        }
+    }
+
+    #[test]
+    fn limits_watches() {
+        let limits = WatchLimits::new(2, 3);
+        let client0 = &pod("pod-0");
+        let client1 = &pod("pod-1");
+        let client2 = &pod("pod-2");
+
+        let p0 = limits.acquire(client0).unwrap();
+        let p1 = limits.acquire(client0).unwrap();
+        assert_eq!(limits.acquire(client0).unwrap_err(), Exceeded::Client);
+
+        let p2 = limits.acquire(client1).unwrap();
+        assert_eq!(limits.acquire(client1).unwrap_err(), Exceeded::Global);
+        assert_eq!(limits.acquire(client2).unwrap_err(), Exceeded::Global);
+
+        // Dropping a permit releases its place.
+        drop(p0);
+        let p3 = limits.acquire(client0).unwrap();
+        assert_eq!(limits.acquire(client0).unwrap_err(), Exceeded::Global);
+
+        drop((p1, p2, p3));
+        let state = limits.0.state.lock().unwrap();
+        assert_eq!(state.total, 0);
+        assert!(state.clients.is_empty());
+    }
+}
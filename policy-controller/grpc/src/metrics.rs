@@ -0,0 +1,269 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::{exponential_buckets, Histogram},
+    },
+    registry::{Registry, Unit},
+};
+use std::time::Instant;
+use tokio::time;
+
+/// Instruments the requests and watch streams served by a gRPC API.
+#[derive(Clone, Debug)]
+pub struct GrpcServerMetrics {
+    watches: Family<WatchLabels, Gauge>,
+    watch_updates: Family<WatchLabels, Counter>,
+    watch_updates_skipped: Family<WatchLabels, Counter>,
+    watch_duration: Family<WatchLabels, Histogram, fn() -> Histogram>,
+    propagation_latency: Family<WatchLabels, Histogram, fn() -> Histogram>,
+    watches_rejected: Family<RejectedLabels, Counter>,
+    message_size: Family<MethodLabels, Histogram, fn() -> Histogram>,
+    errors: Family<ErrorLabels, Counter>,
+}
+
+/// Tracks a single watch stream, recording its duration when dropped.
+#[derive(Debug)]
+pub(crate) struct WatchGuard {
+    metrics: GrpcServerMetrics,
+    method: &'static str,
+    labels: WatchLabels,
+    start: time::Instant,
+
+    /// The first update sent on a stream reflects the state when the watch
+    /// started, so it is not used to measure propagation latency.
+    initial: bool,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct WatchLabels {
+    namespace: String,
+    kind: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RejectedLabels {
+    limit: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct MethodLabels {
+    method: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ErrorLabels {
+    method: &'static str,
+    code: String,
+}
+
+// === impl GrpcServerMetrics ===
+
+impl GrpcServerMetrics {
+    pub fn register(prom: &mut Registry) -> Self {
+        let watches = Family::default();
+        prom.register(
+            "watches",
+            "The number of active watch streams",
+            watches.clone(),
+        );
+
+        let watch_updates = Family::default();
+        prom.register(
+            "watch_updates",
+            "Count of updates sent on watch streams",
+            watch_updates.clone(),
+        );
+
+        let watch_updates_skipped = Family::default();
+        prom.register(
+            "watch_updates_skipped",
+            "Count of updates not sent on watch streams because they were identical to the last update sent",
+            watch_updates_skipped.clone(),
+        );
+
+        let watch_duration = Family::<_, _, fn() -> Histogram>::new_with_constructor(|| {
+            Histogram::new([1.0, 10.0, 60.0, 300.0, 900.0, 3600.0, 14400.0, 86400.0].into_iter())
+        });
+        prom.register_with_unit(
+            "watch_duration",
+            "Histogram of the time watch streams are open",
+            Unit::Seconds,
+            watch_duration.clone(),
+        );
+
+        let propagation_latency = Family::<_, _, fn() -> Histogram>::new_with_constructor(|| {
+            Histogram::new([0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0].into_iter())
+        });
+        prom.register_with_unit(
+            "propagation_latency",
+            "Histogram of the time from when the index applies a resource update to when the resulting update is sent on a watch stream",
+            Unit::Seconds,
+            propagation_latency.clone(),
+        );
+
+        let watches_rejected = Family::default();
+        prom.register(
+            "watches_rejected",
+            "Count of watch streams rejected because a limit on concurrent watches was reached",
+            watches_rejected.clone(),
+        );
+
+        let message_size = Family::<_, _, fn() -> Histogram>::new_with_constructor(|| {
+            // From 256B to 4MiB.
+            Histogram::new(exponential_buckets(256.0, 4.0, 8))
+        });
+        prom.register_with_unit(
+            "message_size",
+            "Histogram of the encoded size of response messages, before compression",
+            Unit::Bytes,
+            message_size.clone(),
+        );
+
+        let errors = Family::default();
+        prom.register(
+            "errors",
+            "Count of requests that failed, by gRPC status code",
+            errors.clone(),
+        );
+
+        Self {
+            watches,
+            watch_updates,
+            watch_updates_skipped,
+            watch_duration,
+            propagation_latency,
+            watches_rejected,
+            message_size,
+            errors,
+        }
+    }
+
+    pub(crate) fn watch(
+        &self,
+        method: &'static str,
+        namespace: &str,
+        kind: &'static str,
+    ) -> WatchGuard {
+        let labels = WatchLabels {
+            namespace: namespace.to_string(),
+            kind,
+        };
+        self.watches.get_or_create(&labels).inc();
+        WatchGuard {
+            metrics: self.clone(),
+            method,
+            labels,
+            start: time::Instant::now(),
+            initial: true,
+        }
+    }
+
+    /// Records the encoded size of a response message.
+    pub(crate) fn sent_message<M: prost::Message>(&self, method: &'static str, msg: &M) {
+        self.message_size
+            .get_or_create(&MethodLabels { method })
+            .observe(msg.encoded_len() as f64);
+    }
+
+    /// Records that a watch was rejected because the named limit was reached.
+    pub(crate) fn watch_rejected(&self, limit: &'static str) {
+        self.watches_rejected
+            .get_or_create(&RejectedLabels { limit })
+            .inc();
+    }
+
+    /// Records the outcome of a request, passing it through.
+    pub(crate) fn record<T>(
+        &self,
+        method: &'static str,
+        res: Result<T, tonic::Status>,
+    ) -> Result<T, tonic::Status> {
+        if let Err(status) = &res {
+            self.errors
+                .get_or_create(&ErrorLabels {
+                    method,
+                    code: format!("{:?}", status.code()),
+                })
+                .inc();
+        }
+        res
+    }
+}
+
+// === impl WatchGuard ===
+
+impl WatchGuard {
+    pub(crate) fn skipped(&self) {
+        self.metrics
+            .watch_updates_skipped
+            .get_or_create(&self.labels)
+            .inc();
+    }
+
+    /// Records that an update was sent on the stream, given the time at which
+    /// the index produced it.
+    pub(crate) fn sent<M: prost::Message>(&mut self, updated_at: Instant, msg: &M) {
+        self.metrics.watch_updates.get_or_create(&self.labels).inc();
+        self.metrics.sent_message(self.method, msg);
+        if std::mem::take(&mut self.initial) {
+            return;
+        }
+        self.metrics
+            .propagation_latency
+            .get_or_create(&self.labels)
+            .observe(updated_at.elapsed().as_secs_f64());
+    }
+}
+
+impl Drop for WatchGuard {
+    fn drop(&mut self) {
+        self.metrics.watches.get_or_create(&self.labels).dec();
+        self.metrics
+            .watch_duration
+            .get_or_create(&self.labels)
+            .observe(self.start.elapsed().as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_watches() {
+        let mut prom = Registry::default();
+        let metrics = GrpcServerMetrics::register(&mut prom);
+
+        let msg = prost_types::Duration {
+            seconds: 1,
+            nanos: 0,
+        };
+        let mut watch = metrics.watch("watch_port", "ns-0", "pod");
+        watch.sent(Instant::now(), &msg);
+        watch.sent(Instant::now(), &msg);
+        let _ = metrics.record::<()>("watch_port", Err(tonic::Status::not_found("unknown")));
+        metrics.watch_rejected("client");
+
+        let mut text = String::new();
+        prometheus_client::encoding::text::encode(&mut text, &prom).unwrap();
+        assert!(text.contains(r#"watches{namespace="ns-0",kind="pod"} 1"#));
+        assert!(text.contains(r#"watch_updates_total{namespace="ns-0",kind="pod"} 2"#));
+        assert!(text.contains(r#"errors_total{method="watch_port",code="NotFound"} 1"#));
+        assert!(text.contains(r#"watches_rejected_total{limit="client"} 1"#));
+        assert!(text.contains(r#"message_size_bytes_count{method="watch_port"} 2"#));
+        assert!(text.contains(r#"message_size_bytes_sum{method="watch_port"} 4.0"#));
+        // The initial update is not counted toward propagation latency.
+        assert!(
+            text.contains(r#"propagation_latency_seconds_count{namespace="ns-0",kind="pod"} 1"#)
+        );
+
+        drop(watch);
+        let mut text = String::new();
+        prometheus_client::encoding::text::encode(&mut text, &prom).unwrap();
+        assert!(text.contains(r#"watches{namespace="ns-0",kind="pod"} 0"#));
+        assert!(text.contains(r#"watch_duration_seconds_count{namespace="ns-0",kind="pod"} 1"#));
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{routes, workload};
+use crate::{
+    authz::ClientAuthz,
+    limits::{WatchLimits, WatchPermit},
+    metrics::{GrpcServerMetrics, WatchGuard},
+    routes,
+    updates::Updates,
+    workload,
+};
 use futures::prelude::*;
 use itertools::Itertools;
 use linkerd2_proxy_api::{
@@ -13,6 +20,7 @@ use linkerd_policy_controller_core::{
     outbound::{
         Backend, DiscoverOutboundPolicy, Filter, OutboundDiscoverTarget, OutboundPolicy,
         OutboundPolicyStream, OutboundRoute, OutboundRouteCollection, OutboundRouteRule,
+        ServicePortRef,
     },
     routes::{GroupKindNamespaceName, HttpRouteMatch},
 };
@@ -24,17 +32,33 @@ pub struct OutboundPolicyServer<T> {
     // Used to parse named addresses into <svc>.<ns>.svc.<cluster-domain>.
     cluster_domain: Arc<str>,
     drain: drain::Watch,
+    metrics: GrpcServerMetrics,
+    debounce: time::Duration,
+    authz: ClientAuthz,
+    limits: WatchLimits,
 }
 
 impl<T> OutboundPolicyServer<T>
 where
     T: DiscoverOutboundPolicy<OutboundDiscoverTarget> + Send + Sync + 'static,
 {
-    pub fn new(discover: T, cluster_domain: impl Into<Arc<str>>, drain: drain::Watch) -> Self {
+    pub fn new(
+        discover: T,
+        cluster_domain: impl Into<Arc<str>>,
+        drain: drain::Watch,
+        metrics: GrpcServerMetrics,
+        debounce: time::Duration,
+        authz: ClientAuthz,
+        limits: WatchLimits,
+    ) -> Self {
         Self {
             index: discover,
             cluster_domain: cluster_domain.into(),
             drain,
+            metrics,
+            debounce,
+            authz,
+            limits,
         }
     }
 
@@ -42,6 +66,19 @@ where
         OutboundPoliciesServer::new(self)
     }
 
+    /// Resolves the requested target, checking that the client is permitted to
+    /// discover policies on behalf of the source workload.
+    /// Returns the authorized source workload and the requested target.
+    fn check_lookup(
+        &self,
+        req: tonic::Request<outbound::TrafficSpec>,
+    ) -> Result<(workload::Workload, OutboundDiscoverTarget), tonic::Status> {
+        let source = workload::Workload::from_str(&req.get_ref().source_workload)?;
+        self.authz.check(&req, &source)?;
+        let target = self.lookup(req.into_inner())?;
+        Ok((source, target))
+    }
+
     fn lookup(&self, spec: outbound::TrafficSpec) -> Result<OutboundDiscoverTarget, tonic::Status> {
         let target = spec
             .target
@@ -50,13 +87,11 @@ where
         let target = match target {
             outbound::traffic_spec::Target::Addr(target) => target,
             outbound::traffic_spec::Target::Authority(auth) => {
-                return self.lookup_authority(&auth).map(
-                    |(service_namespace, service_name, service_port)| OutboundDiscoverTarget {
-                        service_name,
-                        service_namespace,
-                        service_port,
-                        source_namespace,
-                    },
+                return resolve_authority(
+                    &self.index,
+                    &self.cluster_domain,
+                    &auth,
+                    source_namespace,
                 )
             }
         };
@@ -80,48 +115,127 @@ where
             .lookup_ip(addr, port, source_namespace)
             .ok_or_else(|| tonic::Status::not_found("No such service"))
     }
+}
 
-    fn lookup_authority(
-        &self,
-        authority: &str,
-    ) -> Result<(String, String, NonZeroU16), tonic::Status> {
-        let auth = authority
-            .parse::<http::uri::Authority>()
-            .map_err(|_| tonic::Status::invalid_argument("invalid authority"))?;
-
-        let mut host = auth.host();
-        if host.is_empty() {
-            return Err(tonic::Status::invalid_argument(
-                "authority must have a host",
-            ));
-        }
+/// A Service authority, as parsed from a request.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ServiceAuthority {
+    pub(crate) name: String,
 
-        host = host
-            .trim_end_matches('.')
-            .trim_end_matches(&*self.cluster_domain);
-
-        let mut parts = host.split('.');
-        let invalid = {
-            let domain = &self.cluster_domain;
-            move || {
-                tonic::Status::not_found(format!(
-                    "authority must be of the form <name>.<namespace>.svc.{domain}",
-                ))
-            }
-        };
-        let name = parts.next().ok_or_else(invalid)?;
-        let namespace = parts.next().ok_or_else(invalid)?;
-        if parts.next() != Some("svc") {
-            return Err(invalid());
-        };
+    /// The Service's namespace, if the authority specifies one. Otherwise, the
+    /// Service is in the client's namespace.
+    pub(crate) namespace: Option<String>,
 
-        let port = auth
-            .port_u16()
-            .and_then(|p| NonZeroU16::try_from(p).ok())
-            .unwrap_or_else(|| 80.try_into().unwrap());
+    /// Indicates that the authority is of the fully-qualified form
+    /// `<name>.<namespace>.svc.<cluster-domain>`.
+    pub(crate) qualified: bool,
+
+    pub(crate) port: ServicePortRef,
+}
+
+/// Resolves a Service authority to a discovery target, relative to the source
+/// workload's namespace.
+///
+/// Fully-qualified names with numeric ports are resolved without consulting
+/// the index, as they cannot refer to anything but a Service. Short names and
+/// named ports are only resolved for known Services.
+pub(crate) fn resolve_authority<T>(
+    discover: &T,
+    cluster_domain: &str,
+    authority: &str,
+    source_namespace: String,
+) -> Result<OutboundDiscoverTarget, tonic::Status>
+where
+    T: DiscoverOutboundPolicy<OutboundDiscoverTarget>,
+{
+    let ServiceAuthority {
+        name,
+        namespace,
+        qualified,
+        port,
+    } = parse_authority(cluster_domain, authority)?;
+    let service_namespace = namespace.unwrap_or_else(|| source_namespace.clone());
+
+    let service_port = match port {
+        ServicePortRef::Number(port) if qualified => port,
+        port => discover
+            .lookup_service_port(&service_namespace, &name, &port)
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("unknown service port: {authority}"))
+            })?,
+    };
 
-        Ok((namespace.to_string(), name.to_string(), port))
+    Ok(OutboundDiscoverTarget {
+        service_name: name,
+        service_namespace,
+        service_port,
+        source_namespace,
+    })
+}
+
+/// Parses a Service authority of the form `<name>[.<namespace>[.svc[.<cluster-domain>]]]`,
+/// with an optional numeric or named port. The port defaults to 80.
+pub(crate) fn parse_authority(
+    cluster_domain: &str,
+    authority: &str,
+) -> Result<ServiceAuthority, tonic::Status> {
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, port),
+        None => (authority, ""),
+    };
+
+    let host = host.trim_end_matches('.');
+    if host.is_empty() {
+        return Err(tonic::Status::invalid_argument(
+            "authority must have a host",
+        ));
     }
+    let is_label =
+        |l: &str| !l.is_empty() && l.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-');
+    if !host.split('.').all(is_label) {
+        return Err(tonic::Status::invalid_argument("invalid authority"));
+    }
+
+    let port = if port.is_empty() {
+        ServicePortRef::Number(NonZeroU16::new(80).unwrap())
+    } else if let Ok(port) = port.parse::<u16>() {
+        NonZeroU16::new(port)
+            .map(ServicePortRef::Number)
+            .ok_or_else(|| tonic::Status::invalid_argument("port cannot be zero"))?
+    } else if is_label(port) {
+        ServicePortRef::Name(port.to_string())
+    } else {
+        return Err(tonic::Status::invalid_argument("invalid authority port"));
+    };
+
+    let (host, svc, qualified) = match host
+        .strip_suffix(cluster_domain)
+        .and_then(|h| h.strip_suffix(".svc."))
+    {
+        Some(host) => (host, true, true),
+        None => match host.strip_suffix(".svc") {
+            Some(host) => (host, true, false),
+            None => (host, false, false),
+        },
+    };
+
+    let mut labels = host.split('.');
+    let (name, namespace) = match (labels.next(), labels.next(), labels.next()) {
+        (Some(name), Some(ns), None) => (name, Some(ns)),
+        (Some(name), None, None) if !svc => (name, None),
+        _ => {
+            return Err(tonic::Status::not_found(format!(
+                "authority must be of the form <name>[.<namespace>[.svc[.{cluster_domain}]]]",
+            )))
+        }
+    };
+
+    Ok(ServiceAuthority {
+        name: name.to_string(),
+        namespace: namespace.map(ToString::to_string),
+        qualified,
+        port,
+    })
 }
 
 #[async_trait::async_trait]
@@ -133,21 +247,27 @@ where
         &self,
         req: tonic::Request<outbound::TrafficSpec>,
     ) -> Result<tonic::Response<outbound::OutboundPolicy>, tonic::Status> {
-        let service = self.lookup(req.into_inner())?;
-
-        let policy = self
-            .index
-            .get_outbound_policy(service)
-            .await
-            .map_err(|error| {
-                tonic::Status::internal(format!("failed to get outbound policy: {error}"))
-            })?;
-
-        if let Some(policy) = policy {
-            Ok(tonic::Response::new(to_service(policy)))
-        } else {
-            Err(tonic::Status::not_found("No such policy"))
+        let res = async {
+            let (_, service) = self.check_lookup(req)?;
+
+            let policy = self
+                .index
+                .get_outbound_policy(service)
+                .await
+                .map_err(|error| {
+                    tonic::Status::internal(format!("failed to get outbound policy: {error}"))
+                })?;
+
+            if let Some(policy) = policy {
+                let policy = to_service(policy);
+                self.metrics.sent_message("get", &policy);
+                Ok(tonic::Response::new(policy))
+            } else {
+                Err(tonic::Status::not_found("No such policy"))
+            }
         }
+        .await;
+        self.metrics.record("get", res)
     }
 
     type WatchStream = BoxWatchStream;
@@ -156,16 +276,28 @@ where
         &self,
         req: tonic::Request<outbound::TrafficSpec>,
     ) -> Result<tonic::Response<BoxWatchStream>, tonic::Status> {
-        let service = self.lookup(req.into_inner())?;
-        let drain = self.drain.clone();
-
-        let rx = self
-            .index
-            .watch_outbound_policy(service)
-            .await
-            .map_err(|e| tonic::Status::internal(format!("lookup failed: {e}")))?
-            .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
-        Ok(tonic::Response::new(response_stream(drain, rx)))
+        let res = async {
+            let (source, service) = self.check_lookup(req)?;
+            let permit = self.limits.acquire_for(&source, &self.metrics)?;
+            let namespace = service.service_namespace.clone();
+            let drain = self.drain.clone();
+
+            let rx = self
+                .index
+                .watch_outbound_policy(service)
+                .await
+                .map_err(|e| tonic::Status::internal(format!("lookup failed: {e}")))?
+                .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
+            let watch = self.metrics.watch("watch", &namespace, "service");
+            Ok(tonic::Response::new(response_stream(
+                drain,
+                Updates::new(rx, self.debounce),
+                watch,
+                permit,
+            )))
+        }
+        .await;
+        self.metrics.record("watch", res)
     }
 }
 
@@ -173,8 +305,15 @@ type BoxWatchStream = std::pin::Pin<
     Box<dyn Stream<Item = Result<outbound::OutboundPolicy, tonic::Status>> + Send + Sync>,
 >;
 
-fn response_stream(drain: drain::Watch, mut rx: OutboundPolicyStream) -> BoxWatchStream {
+fn response_stream(
+    drain: drain::Watch,
+    mut updates: Updates<OutboundPolicyStream>,
+    mut watch: WatchGuard,
+    permit: WatchPermit,
+) -> BoxWatchStream {
     Box::pin(async_stream::try_stream! {
+        // Hold the permit until the stream completes.
+        let _permit = permit;
         tokio::pin! {
             let shutdown = drain.signaled();
         }
@@ -182,9 +321,17 @@ fn response_stream(drain: drain::Watch, mut rx: OutboundPolicyStream) -> BoxWatc
         loop {
             tokio::select! {
                 // When the port is updated with a new server, update the server watch.
-                res = rx.next() => match res {
+                res = updates.next() => match res {
                     Some(policy) => {
-                        yield to_service(policy);
+                        let updated_at = policy.updated_at();
+                        let policy = to_service(policy.into_inner());
+                        if !updates.is_modified(&policy) {
+                            tracing::trace!("Skipping redundant update");
+                            watch.skipped();
+                            continue;
+                        }
+                        watch.sent(updated_at, &policy);
+                        yield policy;
                     }
                     None => return,
                 },
@@ -201,6 +348,7 @@ fn response_stream(drain: drain::Watch, mut rx: OutboundPolicyStream) -> BoxWatc
 
 fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
     let backend = default_backend(&outbound);
+    let metadata = service_metadata(&outbound);
 
     let kind = if outbound.opaque {
         outbound::proxy_protocol::Kind::Opaque(outbound::proxy_protocol::Opaque {
@@ -251,7 +399,7 @@ fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
             OutboundRouteCollection::Http(routes) => {
                 let routes = routes
                     .into_iter()
-                    .sorted_by(timestamp_then_name)
+                    .sorted_by(hostname_then_timestamp_then_name)
                     .map(|(gknn, route)| convert_outbound_http_route(gknn, route, backend.clone()))
                     .collect::<Vec<_>>();
 
@@ -277,23 +425,36 @@ fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
         }
     };
 
-    let metadata = Metadata {
+    outbound::OutboundPolicy {
+        metadata: Some(metadata),
+        protocol: Some(outbound::ProxyProtocol { kind: Some(kind) }),
+    }
+}
+
+pub(crate) fn service_metadata(outbound: &OutboundPolicy) -> Metadata {
+    Metadata {
         kind: Some(metadata::Kind::Resource(api::meta::Resource {
             group: "core".to_string(),
             kind: "Service".to_string(),
-            namespace: outbound.namespace,
-            name: outbound.name,
+            namespace: outbound.namespace.clone(),
+            name: outbound.name.clone(),
             port: u16::from(outbound.port).into(),
             ..Default::default()
         })),
-    };
-
-    outbound::OutboundPolicy {
-        metadata: Some(metadata),
-        protocol: Some(outbound::ProxyProtocol { kind: Some(kind) }),
     }
 }
 
+/// Orders routes by the Gateway API's precedence rules: routes with the most
+/// specific hostnames first, then the oldest routes, and then routes in
+/// alphabetical order by `{namespace}/{name}`.
+fn hostname_then_timestamp_then_name<MatchType>(
+    left: &(GroupKindNamespaceName, OutboundRoute<MatchType>),
+    right: &(GroupKindNamespaceName, OutboundRoute<MatchType>),
+) -> std::cmp::Ordering {
+    routes::cmp_hostnames(&left.1.hostnames, &right.1.hostnames)
+        .then_with(|| timestamp_then_name(left, right))
+}
+
 fn timestamp_then_name<MatchType>(
     (left_id, left_route): &(GroupKindNamespaceName, OutboundRoute<MatchType>),
     (right_id, right_route): &(GroupKindNamespaceName, OutboundRoute<MatchType>),
@@ -309,7 +470,9 @@ fn timestamp_then_name<MatchType>(
         (None, Some(_)) => return std::cmp::Ordering::Greater,
     };
 
-    by_ts.then_with(|| left_id.name.cmp(&right_id.name))
+    by_ts
+        .then_with(|| left_id.namespace.cmp(&right_id.namespace))
+        .then_with(|| left_id.name.cmp(&right_id.name))
 }
 
 fn convert_outbound_http_route(
@@ -336,7 +499,7 @@ fn convert_outbound_http_route(
         .map(routes::convert_host_match)
         .collect();
 
-    let rules = rules
+    let rules = routes::sort_rules(rules, |r| &mut r.matches)
         .into_iter()
         .map(
             |OutboundRouteRule {
@@ -388,7 +551,7 @@ fn convert_outbound_http_route(
     }
 }
 
-fn convert_http_backend(
+pub(crate) fn convert_http_backend(
     request_timeout: Option<prost_types::Duration>,
     backend: Backend,
 ) -> outbound::http_route::WeightedRouteBackend {
@@ -607,7 +770,10 @@ fn default_queue_config() -> outbound::Queue {
     }
 }
 
-fn convert_duration(name: &'static str, duration: time::Duration) -> Option<prost_types::Duration> {
+pub(crate) fn convert_duration(
+    name: &'static str,
+    duration: time::Duration,
+) -> Option<prost_types::Duration> {
     duration
         .try_into()
         .map_err(|error| {
@@ -616,7 +782,7 @@ fn convert_duration(name: &'static str, duration: time::Duration) -> Option<pros
         .ok()
 }
 
-fn convert_to_http_filter(filter: Filter) -> outbound::http_route::Filter {
+pub(crate) fn convert_to_http_filter(filter: Filter) -> outbound::http_route::Filter {
     use outbound::http_route::filter::Kind;
 
     outbound::http_route::Filter {
@@ -634,3 +800,76 @@ fn convert_to_http_filter(filter: Filter) -> outbound::http_route::Filter {
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authority(
+        name: &str,
+        namespace: Option<&str>,
+        qualified: bool,
+        port: ServicePortRef,
+    ) -> ServiceAuthority {
+        ServiceAuthority {
+            name: name.to_string(),
+            namespace: namespace.map(ToString::to_string),
+            qualified,
+            port,
+        }
+    }
+
+    fn port(port: u16) -> ServicePortRef {
+        ServicePortRef::Number(NonZeroU16::new(port).unwrap())
+    }
+
+    #[test]
+    fn parses_authorities() {
+        for (input, expected) in [
+            (
+                "web.ns.svc.cluster.local:8080",
+                authority("web", Some("ns"), true, port(8080)),
+            ),
+            (
+                "web.ns.svc.cluster.local.",
+                authority("web", Some("ns"), true, port(80)),
+            ),
+            (
+                "web.ns.svc:8080",
+                authority("web", Some("ns"), false, port(8080)),
+            ),
+            ("web.ns", authority("web", Some("ns"), false, port(80))),
+            (
+                "web:http",
+                authority("web", None, false, ServicePortRef::Name("http".to_string())),
+            ),
+        ] {
+            assert_eq!(
+                parse_authority("cluster.local", input).expect(input),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_authorities() {
+        for (input, code) in [
+            ("", tonic::Code::InvalidArgument),
+            (":8080", tonic::Code::InvalidArgument),
+            ("web..ns", tonic::Code::InvalidArgument),
+            ("web.ns:0", tonic::Code::InvalidArgument),
+            ("web.ns:h/ttp", tonic::Code::InvalidArgument),
+            ("web.svc", tonic::Code::NotFound),
+            ("web.ns.other.svc.cluster.local", tonic::Code::NotFound),
+        ] {
+            assert_eq!(
+                parse_authority("cluster.local", input)
+                    .expect_err(input)
+                    .code(),
+                code,
+                "{input}"
+            );
+        }
+    }
+}
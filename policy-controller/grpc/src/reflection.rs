@@ -0,0 +1,355 @@
+use futures::prelude::*;
+use prost::Message;
+use prost_types::{DescriptorProto, FileDescriptorProto, FileDescriptorSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tonic::server::NamedService;
+
+pub mod proto {
+    include!("gen/grpc.reflection.v1alpha.rs");
+}
+
+use self::proto::{
+    server_reflection_request::MessageRequest,
+    server_reflection_response::MessageResponse,
+    server_reflection_server::{ServerReflection, ServerReflectionServer as Svc},
+    ErrorResponse, ExtensionNumberResponse, FileDescriptorResponse, ListServiceResponse,
+    ServerReflectionRequest, ServerReflectionResponse, ServiceResponse,
+};
+
+/// Describes the policy, simulation, health, and reflection APIs, along with
+/// their dependencies. This is generated with the bindings in `gen`.
+const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("gen/file_descriptor_set.bin");
+
+/// Serves the standard `grpc.reflection.v1alpha.ServerReflection` API, so that
+/// tools like `grpcurl` can describe the controller's APIs without their
+/// definitions.
+#[derive(Clone, Debug)]
+pub struct ReflectionServer {
+    index: Arc<Index>,
+    drain: drain::Watch,
+}
+
+#[derive(Debug)]
+struct Index {
+    /// The services listed by the server, including the reflection service.
+    services: Vec<&'static str>,
+
+    files: HashMap<String, FileDescriptorProto>,
+
+    /// The name of the file that declares each fully-qualified symbol.
+    symbols: HashMap<String, String>,
+}
+
+// === impl ReflectionServer ===
+
+impl ReflectionServer {
+    /// Returns a reflection server that lists the named services.
+    ///
+    /// # Panics
+    ///
+    /// If the generated file descriptor set cannot be decoded.
+    pub fn new(services: impl IntoIterator<Item = &'static str>, drain: drain::Watch) -> Self {
+        let files = FileDescriptorSet::decode(FILE_DESCRIPTOR_SET)
+            .expect("generated file descriptor set must be valid")
+            .file;
+        let services = services
+            .into_iter()
+            .chain(Some(<Svc<Self> as NamedService>::NAME))
+            .collect();
+        Self {
+            index: Arc::new(Index::new(services, files)),
+            drain,
+        }
+    }
+
+    pub fn svc(self) -> Svc<Self> {
+        Svc::new(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl ServerReflection for ReflectionServer {
+    type ServerReflectionInfoStream = BoxInfoStream;
+
+    async fn server_reflection_info(
+        &self,
+        req: tonic::Request<tonic::Streaming<ServerReflectionRequest>>,
+    ) -> Result<tonic::Response<BoxInfoStream>, tonic::Status> {
+        let index = self.index.clone();
+        let drain = self.drain.clone();
+        let mut requests = req.into_inner();
+        Ok(tonic::Response::new(Box::pin(async_stream::try_stream! {
+            tokio::pin! {
+                let shutdown = drain.signaled();
+            }
+
+            loop {
+                let req = tokio::select! {
+                    biased;
+
+                    // Each request is answered independently, so the stream is
+                    // closed when the server starts draining rather than
+                    // holding the server open.
+                    _ = (&mut shutdown) => return,
+
+                    req = requests.message() => req,
+                };
+                match req? {
+                    Some(req) => yield index.respond(req),
+                    None => return,
+                }
+            }
+        })))
+    }
+}
+
+type BoxInfoStream =
+    std::pin::Pin<Box<dyn Stream<Item = Result<ServerReflectionResponse, tonic::Status>> + Send>>;
+
+// === impl Index ===
+
+impl Index {
+    fn new(services: Vec<&'static str>, files: Vec<FileDescriptorProto>) -> Self {
+        let mut symbols = HashMap::new();
+        for file in &files {
+            let package = file.package();
+            for svc in &file.service {
+                let svc_name = qualify(package, svc.name());
+                for method in &svc.method {
+                    symbols.insert(qualify(&svc_name, method.name()), file.name().to_string());
+                }
+                symbols.insert(svc_name, file.name().to_string());
+            }
+            for msg in &file.message_type {
+                index_message(&mut symbols, file.name(), package, msg);
+            }
+            for enm in &file.enum_type {
+                symbols.insert(qualify(package, enm.name()), file.name().to_string());
+            }
+        }
+
+        Self {
+            services,
+            files: files
+                .into_iter()
+                .map(|file| (file.name().to_string(), file))
+                .collect(),
+            symbols,
+        }
+    }
+
+    fn respond(&self, req: ServerReflectionRequest) -> ServerReflectionResponse {
+        let rsp = match &req.message_request {
+            Some(MessageRequest::FileByFilename(name)) => self
+                .file_descriptors(name)
+                .unwrap_or_else(|| not_found(format!("file not found: {name}"))),
+
+            Some(MessageRequest::FileContainingSymbol(symbol)) => self
+                .symbols
+                .get(symbol)
+                .and_then(|name| self.file_descriptors(name))
+                .unwrap_or_else(|| not_found(format!("symbol not found: {symbol}"))),
+
+            // None of the described types are extended.
+            Some(MessageRequest::FileContainingExtension(ext)) => not_found(format!(
+                "extension not found: {}({})",
+                ext.containing_type, ext.extension_number
+            )),
+            Some(MessageRequest::AllExtensionNumbersOfType(ty)) => {
+                if self.symbols.contains_key(ty) {
+                    MessageResponse::AllExtensionNumbersResponse(ExtensionNumberResponse {
+                        base_type_name: ty.clone(),
+                        extension_number: vec![],
+                    })
+                } else {
+                    not_found(format!("type not found: {ty}"))
+                }
+            }
+
+            Some(MessageRequest::ListServices(_)) => {
+                MessageResponse::ListServicesResponse(ListServiceResponse {
+                    service: self
+                        .services
+                        .iter()
+                        .map(|name| ServiceResponse {
+                            name: name.to_string(),
+                        })
+                        .collect(),
+                })
+            }
+
+            None => error(tonic::Code::InvalidArgument, "a request must be set".into()),
+        };
+
+        ServerReflectionResponse {
+            valid_host: req.host.clone(),
+            original_request: Some(req),
+            message_response: Some(rsp),
+        }
+    }
+
+    /// Returns the encoded descriptors of the named file and all of the files
+    /// it transitively imports, since clients need them to resolve its types.
+    fn file_descriptors(&self, name: &str) -> Option<MessageResponse> {
+        let mut seen = HashSet::new();
+        let mut pending = vec![name];
+        let mut file_descriptor_proto = vec![];
+        while let Some(name) = pending.pop() {
+            if !seen.insert(name) {
+                continue;
+            }
+            let file = self.files.get(name)?;
+            file_descriptor_proto.push(file.encode_to_vec());
+            pending.extend(file.dependency.iter().map(String::as_str));
+        }
+        Some(MessageResponse::FileDescriptorResponse(
+            FileDescriptorResponse {
+                file_descriptor_proto,
+            },
+        ))
+    }
+}
+
+fn index_message(
+    symbols: &mut HashMap<String, String>,
+    file: &str,
+    scope: &str,
+    msg: &DescriptorProto,
+) {
+    let name = qualify(scope, msg.name());
+    for nested in &msg.nested_type {
+        index_message(symbols, file, &name, nested);
+    }
+    for enm in &msg.enum_type {
+        symbols.insert(qualify(&name, enm.name()), file.to_string());
+    }
+    symbols.insert(name, file.to_string());
+}
+
+fn qualify(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        return name.to_string();
+    }
+    format!("{scope}.{name}")
+}
+
+fn not_found(message: String) -> MessageResponse {
+    error(tonic::Code::NotFound, message)
+}
+
+fn error(code: tonic::Code, error_message: String) -> MessageResponse {
+    MessageResponse::ErrorResponse(ErrorResponse {
+        error_code: code as i32,
+        error_message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVICES: [&str; 4] = [
+        "grpc.health.v1.Health",
+        "io.linkerd.policy.simulate.PolicySimulator",
+        "io.linkerd.proxy.inbound.InboundServerPolicies",
+        "io.linkerd.proxy.outbound.OutboundPolicies",
+    ];
+
+    fn request(req: MessageRequest) -> Option<MessageResponse> {
+        let (_drain_tx, drain_rx) = drain::channel();
+        let srv = ReflectionServer::new(SERVICES, drain_rx);
+        srv.index
+            .respond(ServerReflectionRequest {
+                host: String::new(),
+                message_request: Some(req),
+            })
+            .message_response
+    }
+
+    #[test]
+    fn lists_services() {
+        let Some(MessageResponse::ListServicesResponse(rsp)) =
+            request(MessageRequest::ListServices(String::new()))
+        else {
+            panic!("services must be listed");
+        };
+        let names = rsp.service.into_iter().map(|s| s.name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            SERVICES
+                .into_iter()
+                .chain(Some("grpc.reflection.v1alpha.ServerReflection"))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn describes_services() {
+        for svc in SERVICES
+            .into_iter()
+            .chain(Some("grpc.reflection.v1alpha.ServerReflection"))
+        {
+            let Some(MessageResponse::FileDescriptorResponse(rsp)) =
+                request(MessageRequest::FileContainingSymbol(svc.to_string()))
+            else {
+                panic!("{svc} must be described");
+            };
+            let files = rsp
+                .file_descriptor_proto
+                .iter()
+                .map(|buf| FileDescriptorProto::decode(&**buf).unwrap())
+                .collect::<Vec<_>>();
+
+            let (package, name) = svc.rsplit_once('.').unwrap();
+            assert_eq!(files[0].package(), package);
+            assert!(files[0].service.iter().any(|s| s.name() == name));
+
+            // Every import is included so that clients can resolve all types.
+            for dep in files.iter().flat_map(|f| &f.dependency) {
+                assert!(
+                    files.iter().any(|f| f.name() == dep),
+                    "{svc} must include {dep}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn describes_types() {
+        for symbol in [
+            "io.linkerd.proxy.inbound.Server",
+            "io.linkerd.proxy.outbound.ProxyProtocol.Http1",
+            "grpc.health.v1.HealthCheckResponse.ServingStatus",
+            "grpc.health.v1.Health.Watch",
+            "google.protobuf.Duration",
+        ] {
+            assert!(
+                matches!(
+                    request(MessageRequest::FileContainingSymbol(symbol.to_string())),
+                    Some(MessageResponse::FileDescriptorResponse(_))
+                ),
+                "{symbol} must be described"
+            );
+        }
+    }
+
+    #[test]
+    fn reports_unknown_symbols() {
+        let Some(MessageResponse::ErrorResponse(rsp)) = request(
+            MessageRequest::FileContainingSymbol("io.linkerd.Unknown".to_string()),
+        ) else {
+            panic!("an error must be returned");
+        };
+        assert_eq!(rsp.error_code, tonic::Code::NotFound as i32);
+
+        let Some(MessageResponse::ErrorResponse(rsp)) =
+            request(MessageRequest::FileByFilename("unknown.proto".to_string()))
+        else {
+            panic!("an error must be returned");
+        };
+        assert_eq!(rsp.error_code, tonic::Code::NotFound as i32);
+    }
+}
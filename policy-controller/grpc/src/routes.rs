@@ -1,8 +1,49 @@
 use linkerd2_proxy_api::{http_route as proto, http_types};
 use linkerd_policy_controller_core::routes::{
-    FailureInjectorFilter, HeaderMatch, HeaderModifierFilter, HostMatch, HttpRouteMatch, PathMatch,
-    PathModifier, QueryParamMatch, RequestRedirectFilter,
+    FailureInjectorFilter, HeaderMatch, HeaderModifierFilter, HostMatch, HostMatchPrecedence,
+    HttpRouteMatch, PathMatch, PathModifier, QueryParamMatch, RequestRedirectFilter,
 };
+use std::cmp::{Ordering, Reverse};
+
+/// Orders routes so that those with the most specific hostnames are listed
+/// first.
+pub(crate) fn cmp_hostnames(a: &[HostMatch], b: &[HostMatch]) -> Ordering {
+    HostMatch::max_precedence(b).cmp(&HostMatch::max_precedence(a))
+}
+
+/// Orders a route's rules from most to least specific according to the
+/// Gateway API's precedence rules: path, method, header count, and then query
+/// parameter count.
+///
+/// A rule's matches may have different precedences, so each match is served
+/// as its own rule (with the same filters and backends). Sorting is stable,
+/// so equally specific matches retain the order in which their rules (and
+/// then the matches within a rule) are specified, and the earliest rule still
+/// wins ties. As a result, served rules do not correspond one-to-one with the
+/// route's rules; the simulator reports the index of the rule as specified.
+pub(crate) fn sort_rules<R: Clone>(
+    rules: Vec<R>,
+    matches: impl Fn(&mut R) -> &mut Vec<HttpRouteMatch>,
+) -> Vec<R> {
+    let mut sorted = Vec::with_capacity(rules.len());
+    for mut rule in rules {
+        let ms = std::mem::take(matches(&mut rule));
+        if ms.is_empty() {
+            // A rule without matches matches all requests.
+            let precedence = HttpRouteMatch::default().precedence(HostMatchPrecedence::Any);
+            sorted.push((Reverse(precedence), rule));
+            continue;
+        }
+        for m in ms {
+            let precedence = m.precedence(HostMatchPrecedence::Any);
+            let mut rule = rule.clone();
+            *matches(&mut rule) = vec![m];
+            sorted.push((Reverse(precedence), rule));
+        }
+    }
+    sorted.sort_by_key(|(precedence, _)| *precedence);
+    sorted.into_iter().map(|(_, rule)| rule).collect()
+}
 
 pub(crate) fn convert_host_match(h: HostMatch) -> proto::HostMatch {
     proto::HostMatch {
@@ -166,3 +207,40 @@ pub(crate) mod http {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_rules_by_precedence() {
+        let prefix = |p: &str| HttpRouteMatch {
+            path: Some(PathMatch::Prefix(p.to_string())),
+            ..HttpRouteMatch::default()
+        };
+        let exact = HttpRouteMatch {
+            path: Some(PathMatch::Exact("/foo".to_string())),
+            ..HttpRouteMatch::default()
+        };
+
+        let rules = vec![
+            ("root", vec![prefix("/")]),
+            ("all", vec![]),
+            ("foo", vec![prefix("/"), exact.clone()]),
+            ("foobar", vec![prefix("/foo/bar")]),
+        ];
+        let sorted = sort_rules(rules, |(_, ms)| ms);
+        assert_eq!(
+            sorted,
+            vec![
+                ("foo", vec![exact]),
+                ("foobar", vec![prefix("/foo/bar")]),
+                // Equally specific matches retain the order of their rules, so
+                // the first rule still wins ties.
+                ("root", vec![prefix("/")]),
+                ("all", vec![]),
+                ("foo", vec![prefix("/")]),
+            ],
+        );
+    }
+}
@@ -0,0 +1,500 @@
+use crate::{inbound, outbound, workload::Workload};
+use linkerd2_proxy_api::meta::{metadata, Metadata};
+use linkerd_policy_controller_core::{
+    inbound::DiscoverInboundServer,
+    outbound::{DiscoverOutboundPolicy, OutboundDiscoverTarget},
+    simulate::{
+        inbound::{self as sim_inbound, ClientTls, Verdict},
+        outbound::{self as sim_outbound, RouteSelection},
+    },
+    IpNet,
+};
+use std::{net::IpAddr, num::NonZeroU16, str::FromStr, sync::Arc};
+
+pub mod proto {
+    include!("gen/io.linkerd.policy.simulate.rs");
+}
+
+use self::proto::policy_simulator_server::{PolicySimulator, PolicySimulatorServer};
+
+/// Serves policy simulations from the same discovery indexes that serve
+/// proxies.
+#[derive(Clone, Debug)]
+pub struct PolicySimulationServer<I, O> {
+    inbound: I,
+    outbound: O,
+    cluster_networks: Arc<[IpNet]>,
+    cluster_domain: Arc<str>,
+}
+
+// === impl PolicySimulationServer ===
+
+impl<I, O> PolicySimulationServer<I, O>
+where
+    I: DiscoverInboundServer<(Workload, NonZeroU16)> + Send + Sync + 'static,
+    O: DiscoverOutboundPolicy<OutboundDiscoverTarget> + Send + Sync + 'static,
+{
+    pub fn new(
+        inbound: I,
+        outbound: O,
+        cluster_networks: Vec<IpNet>,
+        cluster_domain: impl Into<Arc<str>>,
+    ) -> Self {
+        Self {
+            inbound,
+            outbound,
+            cluster_networks: cluster_networks.into(),
+            cluster_domain: cluster_domain.into(),
+        }
+    }
+
+    pub fn svc(self) -> PolicySimulatorServer<Self> {
+        PolicySimulatorServer::new(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<I, O> PolicySimulator for PolicySimulationServer<I, O>
+where
+    I: DiscoverInboundServer<(Workload, NonZeroU16)> + Send + Sync + 'static,
+    O: DiscoverOutboundPolicy<OutboundDiscoverTarget> + Send + Sync + 'static,
+{
+    async fn inbound(
+        &self,
+        req: tonic::Request<proto::InboundRequest>,
+    ) -> Result<tonic::Response<proto::InboundDecision>, tonic::Status> {
+        let proto::InboundRequest {
+            workload,
+            port,
+            client,
+            http,
+        } = req.into_inner();
+        let target = inbound::parse_target(&workload, port)?;
+        let client = to_client(client)?;
+        let http = http.map(to_http_request).transpose()?;
+
+        let server = self
+            .inbound
+            .get_inbound_server(target)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("lookup failed: {}", e)))?
+            .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
+
+        let decision =
+            sim_inbound::evaluate(&server, &self.cluster_networks, &client, http.as_ref());
+        tracing::debug!(?decision);
+
+        Ok(tonic::Response::new(to_inbound_decision(decision)))
+    }
+
+    async fn outbound(
+        &self,
+        req: tonic::Request<proto::OutboundRequest>,
+    ) -> Result<tonic::Response<proto::OutboundDecision>, tonic::Status> {
+        let proto::OutboundRequest {
+            authority,
+            source_workload,
+            http,
+        } = req.into_inner();
+        let source_namespace = Workload::from_str(&source_workload)?.namespace;
+        let target = outbound::resolve_authority(
+            &self.outbound,
+            &self.cluster_domain,
+            &authority,
+            source_namespace,
+        )?;
+        let http = to_http_request(http.unwrap_or_default())?;
+
+        let policy = self
+            .outbound
+            .get_outbound_policy(target)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("lookup failed: {}", e)))?
+            .ok_or_else(|| tonic::Status::not_found("No such policy"))?;
+
+        let decision = sim_outbound::evaluate(&policy, &http);
+        tracing::debug!(?decision);
+
+        Ok(tonic::Response::new(proto::OutboundDecision {
+            service: Some(outbound::service_metadata(&policy)),
+            ..to_outbound_decision(decision)
+        }))
+    }
+}
+
+fn to_client(client: Option<proto::Client>) -> Result<sim_inbound::Client, tonic::Status> {
+    let proto::Client { ip, tls } =
+        client.ok_or_else(|| tonic::Status::invalid_argument("missing client"))?;
+    let addr = ip
+        .and_then(|ip| IpAddr::try_from(ip).ok())
+        .ok_or_else(|| tonic::Status::invalid_argument("missing or invalid client IP"))?;
+    let tls = match tls {
+        None | Some(proto::client::Tls::Plaintext(_)) => ClientTls::None,
+        Some(proto::client::Tls::Anonymous(_)) => ClientTls::Unauthenticated,
+        Some(proto::client::Tls::Identity(id)) => ClientTls::Authenticated(id),
+    };
+    Ok(sim_inbound::Client { addr, tls })
+}
+
+fn to_http_request(
+    proto::HttpRequest {
+        method,
+        authority,
+        path,
+        headers,
+    }: proto::HttpRequest,
+) -> Result<http::Request<()>, tonic::Status> {
+    let method = match method {
+        Some(m) => http::Method::try_from(m)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid method: {e}")))?,
+        None => http::Method::GET,
+    };
+    let path = if path.is_empty() {
+        "/".to_string()
+    } else {
+        path
+    };
+
+    let mut req = http::Request::builder().method(method).uri(path);
+    if !authority.is_empty() {
+        req = req.header(http::header::HOST, authority);
+    }
+    for proto::http_request::Header { name, value } in headers {
+        req = req.header(name, value);
+    }
+    req.body(())
+        .map_err(|e| tonic::Status::invalid_argument(format!("invalid request: {e}")))
+}
+
+fn to_inbound_decision(
+    sim_inbound::Decision {
+        server,
+        route,
+        verdict,
+    }: sim_inbound::Decision,
+) -> proto::InboundDecision {
+    let route = route.map(|rm| proto::RouteMatch {
+        route: Some(inbound::http_route_metadata(&rm.route)),
+        rule: rm.rule as u32,
+    });
+    let verdict = match verdict {
+        Verdict::Allow(authz) => {
+            proto::inbound_decision::Verdict::Allow(inbound::authz_metadata(&authz))
+        }
+        Verdict::Unauthorized => {
+            proto::inbound_decision::Verdict::Unauthorized(proto::inbound_decision::Unauthorized {})
+        }
+        Verdict::NotFound => {
+            proto::inbound_decision::Verdict::NotFound(proto::inbound_decision::NotFound {})
+        }
+    };
+    proto::InboundDecision {
+        server: Some(inbound::server_metadata(&server)),
+        route,
+        verdict: Some(verdict),
+    }
+}
+
+fn to_outbound_decision(
+    sim_outbound::Decision {
+        route,
+        filters,
+        backends,
+        request_timeout,
+        backend_request_timeout,
+    }: sim_outbound::Decision,
+) -> proto::OutboundDecision {
+    use proto::outbound_decision::Route;
+
+    let route = match route {
+        RouteSelection::Opaque => Route::Opaque(proto::outbound_decision::Opaque {}),
+        RouteSelection::Default => Route::Http(proto::RouteMatch {
+            route: Some(Metadata {
+                kind: Some(metadata::Kind::Default("http".to_string())),
+            }),
+            rule: 0,
+        }),
+        RouteSelection::Route { route, rule } => Route::Http(proto::RouteMatch {
+            route: Some(Metadata {
+                kind: Some(metadata::Kind::Resource(
+                    linkerd2_proxy_api::meta::Resource {
+                        group: route.group.to_string(),
+                        kind: route.kind.to_string(),
+                        namespace: route.namespace.to_string(),
+                        name: route.name.to_string(),
+                        ..Default::default()
+                    },
+                )),
+            }),
+            rule: rule as u32,
+        }),
+        RouteSelection::NotFound => Route::NotFound(proto::outbound_decision::NotFound {}),
+    };
+
+    let backend_request_timeout = backend_request_timeout
+        .and_then(|d| outbound::convert_duration("backend request_timeout", d));
+    proto::OutboundDecision {
+        service: None,
+        route: Some(route),
+        filters: filters
+            .into_iter()
+            .map(outbound::convert_to_http_filter)
+            .collect(),
+        backends: backends
+            .into_iter()
+            .map(|b| outbound::convert_http_backend(backend_request_timeout.clone(), b))
+            .collect(),
+        request_timeout: request_timeout
+            .and_then(|d| outbound::convert_duration("request timeout", d)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd2_proxy_api::{http_types, net};
+    use linkerd_policy_controller_core::{
+        inbound::{AuthorizationRef, HttpRouteRef, ServerRef},
+        routes::{GroupKindName, GroupKindNamespaceName},
+    };
+
+    fn client(tls: Option<proto::client::Tls>) -> proto::Client {
+        proto::Client {
+            ip: Some(IpAddr::from([10, 0, 0, 1]).into()),
+            tls,
+        }
+    }
+
+    #[test]
+    fn converts_clients() {
+        assert_eq!(
+            to_client(None).unwrap_err().message(),
+            "missing client",
+            "a client is required"
+        );
+        for ip in [None, Some(net::IpAddress { ip: None })] {
+            let status = to_client(Some(proto::Client { ip, tls: None })).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+            assert_eq!(status.message(), "missing or invalid client IP");
+        }
+
+        for (tls, expected) in [
+            (None, ClientTls::None),
+            (
+                Some(proto::client::Tls::Plaintext(proto::client::Plaintext {})),
+                ClientTls::None,
+            ),
+            (
+                Some(proto::client::Tls::Anonymous(proto::client::Anonymous {})),
+                ClientTls::Unauthenticated,
+            ),
+            (
+                Some(proto::client::Tls::Identity(
+                    "foo.ns.serviceaccount.identity.linkerd.cluster.local".to_string(),
+                )),
+                ClientTls::Authenticated(
+                    "foo.ns.serviceaccount.identity.linkerd.cluster.local".to_string(),
+                ),
+            ),
+        ] {
+            assert_eq!(
+                to_client(Some(client(tls))).unwrap(),
+                sim_inbound::Client {
+                    addr: IpAddr::from([10, 0, 0, 1]),
+                    tls: expected,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn converts_http_requests() {
+        let req = to_http_request(proto::HttpRequest::default()).unwrap();
+        assert_eq!(req.method(), http::Method::GET);
+        assert_eq!(req.uri(), "/");
+        assert!(req.headers().is_empty());
+
+        let req = to_http_request(proto::HttpRequest {
+            method: Some(http::Method::POST.into()),
+            authority: "web.ns.svc.cluster.local:8080".to_string(),
+            path: "/foo?bar=baz".to_string(),
+            headers: vec![proto::http_request::Header {
+                name: "x-foo".to_string(),
+                value: "bar".to_string(),
+            }],
+        })
+        .unwrap();
+        assert_eq!(req.method(), http::Method::POST);
+        assert_eq!(req.uri(), "/foo?bar=baz");
+        assert_eq!(
+            req.headers()[http::header::HOST],
+            "web.ns.svc.cluster.local:8080"
+        );
+        assert_eq!(req.headers()["x-foo"], "bar");
+
+        for method in [
+            http_types::HttpMethod { r#type: None },
+            http_types::HttpMethod {
+                r#type: Some(http_types::http_method::Type::Unregistered(
+                    "NOT A METHOD".to_string(),
+                )),
+            },
+        ] {
+            let status = to_http_request(proto::HttpRequest {
+                method: Some(method),
+                ..Default::default()
+            })
+            .unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+            assert!(
+                status.message().starts_with("invalid method"),
+                "{}",
+                status.message()
+            );
+        }
+
+        let status = to_http_request(proto::HttpRequest {
+            path: "not a path".to_string(),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(
+            status.message().starts_with("invalid request"),
+            "{}",
+            status.message()
+        );
+    }
+
+    #[test]
+    fn converts_inbound_decisions() {
+        let server = ServerRef::Server("web".to_string());
+        let route = HttpRouteRef::Linkerd(GroupKindName {
+            group: "policy.linkerd.io".into(),
+            kind: "HTTPRoute".into(),
+            name: "web".into(),
+        });
+        let authz = AuthorizationRef::AuthorizationPolicy("web".to_string());
+
+        let decision = to_inbound_decision(sim_inbound::Decision {
+            server: server.clone(),
+            route: Some(sim_inbound::RouteMatch {
+                route: route.clone(),
+                rule: 1,
+            }),
+            verdict: Verdict::Allow(authz.clone()),
+        });
+        assert_eq!(
+            decision,
+            proto::InboundDecision {
+                server: Some(inbound::server_metadata(&server)),
+                route: Some(proto::RouteMatch {
+                    route: Some(inbound::http_route_metadata(&route)),
+                    rule: 1,
+                }),
+                verdict: Some(proto::inbound_decision::Verdict::Allow(
+                    inbound::authz_metadata(&authz)
+                )),
+            }
+        );
+
+        let decision = to_inbound_decision(sim_inbound::Decision {
+            server: server.clone(),
+            route: Some(sim_inbound::RouteMatch { route, rule: 0 }),
+            verdict: Verdict::Unauthorized,
+        });
+        assert_eq!(
+            decision.verdict,
+            Some(proto::inbound_decision::Verdict::Unauthorized(
+                proto::inbound_decision::Unauthorized {}
+            ))
+        );
+
+        let decision = to_inbound_decision(sim_inbound::Decision {
+            server,
+            route: None,
+            verdict: Verdict::NotFound,
+        });
+        assert_eq!(decision.route, None);
+        assert_eq!(
+            decision.verdict,
+            Some(proto::inbound_decision::Verdict::NotFound(
+                proto::inbound_decision::NotFound {}
+            ))
+        );
+    }
+
+    #[test]
+    fn converts_outbound_decisions() {
+        use proto::outbound_decision::Route;
+
+        let decision = |route| {
+            to_outbound_decision(sim_outbound::Decision {
+                route,
+                filters: vec![],
+                backends: vec![],
+                request_timeout: None,
+                backend_request_timeout: None,
+            })
+        };
+
+        assert_eq!(
+            decision(RouteSelection::Opaque).route,
+            Some(Route::Opaque(proto::outbound_decision::Opaque {}))
+        );
+        assert_eq!(
+            decision(RouteSelection::Default).route,
+            Some(Route::Http(proto::RouteMatch {
+                route: Some(Metadata {
+                    kind: Some(metadata::Kind::Default("http".to_string())),
+                }),
+                rule: 0,
+            }))
+        );
+        assert_eq!(
+            decision(RouteSelection::Route {
+                route: GroupKindNamespaceName {
+                    group: "gateway.networking.k8s.io".into(),
+                    kind: "HTTPRoute".into(),
+                    namespace: "ns".into(),
+                    name: "web".into(),
+                },
+                rule: 2,
+            })
+            .route,
+            Some(Route::Http(proto::RouteMatch {
+                route: Some(Metadata {
+                    kind: Some(metadata::Kind::Resource(
+                        linkerd2_proxy_api::meta::Resource {
+                            group: "gateway.networking.k8s.io".to_string(),
+                            kind: "HTTPRoute".to_string(),
+                            namespace: "ns".to_string(),
+                            name: "web".to_string(),
+                            ..Default::default()
+                        }
+                    )),
+                }),
+                rule: 2,
+            }))
+        );
+        assert_eq!(
+            decision(RouteSelection::NotFound).route,
+            Some(Route::NotFound(proto::outbound_decision::NotFound {}))
+        );
+
+        let decision = to_outbound_decision(sim_outbound::Decision {
+            route: RouteSelection::Default,
+            filters: vec![],
+            backends: vec![],
+            request_timeout: Some(std::time::Duration::from_secs(5)),
+            backend_request_timeout: None,
+        });
+        assert_eq!(
+            decision.request_timeout,
+            Some(prost_types::Duration {
+                seconds: 5,
+                nanos: 0,
+            })
+        );
+    }
+}
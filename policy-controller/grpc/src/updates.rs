@@ -0,0 +1,106 @@
+use futures::prelude::*;
+use tokio::time::{self, Duration, Instant};
+
+/// Reads the updates to be sent on a watch stream, coalescing bursts of
+/// updates and skipping messages that are identical to the last one sent.
+///
+/// Changes to a single resource may cause many updates to be published in
+/// quick succession (e.g. as each pod in a rollout is indexed). The first
+/// update is returned immediately, so that clients are not delayed on
+/// startup; afterwards, updates received within `debounce` of one another are
+/// coalesced so that only the latest is sent.
+#[derive(Debug)]
+pub(crate) struct Updates<S> {
+    rx: stream::Fuse<S>,
+    debounce: Duration,
+    initial: bool,
+    last_sent: Option<Vec<u8>>,
+}
+
+// === impl Updates ===
+
+impl<S: Stream + Unpin> Updates<S> {
+    pub(crate) fn new(rx: S, debounce: Duration) -> Self {
+        Self {
+            rx: rx.fuse(),
+            debounce,
+            initial: true,
+            last_sent: None,
+        }
+    }
+
+    /// Waits for the next update, returning `None` when the stream ends.
+    pub(crate) async fn next(&mut self) -> Option<S::Item> {
+        let mut update = self.rx.next().await?;
+        if std::mem::take(&mut self.initial) || self.debounce.is_zero() {
+            return Some(update);
+        }
+
+        let deadline = Instant::now() + self.debounce;
+        while let Ok(Some(next)) = time::timeout_at(deadline, self.rx.next()).await {
+            update = next;
+        }
+        Some(update)
+    }
+
+    /// Returns true if the message differs from the last message sent on the
+    /// stream, recording it as sent.
+    pub(crate) fn is_modified<M: prost::Message>(&mut self, msg: &M) -> bool {
+        let encoded = msg.encode_to_vec();
+        if self.last_sent.as_ref() == Some(&encoded) {
+            return false;
+        }
+        self.last_sent = Some(encoded);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    #[tokio::test(start_paused = true)]
+    async fn coalesces_updates() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut updates = Updates::new(UnboundedReceiverStream::new(rx), Duration::from_secs(1));
+
+        // The initial update is not delayed.
+        tx.send(0).unwrap();
+        tx.send(1).unwrap();
+        let start = Instant::now();
+        assert_eq!(updates.next().await, Some(0));
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Subsequent updates within the window are coalesced.
+        let send = tokio::spawn(async move {
+            time::sleep(Duration::from_millis(500)).await;
+            tx.send(2).unwrap();
+            time::sleep(Duration::from_secs(1)).await;
+            tx.send(3).unwrap();
+        });
+        assert_eq!(updates.next().await, Some(2));
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+        assert_eq!(updates.next().await, Some(3));
+        send.await.unwrap();
+        assert_eq!(updates.next().await, None);
+    }
+
+    #[test]
+    fn skips_identical_messages() {
+        let mut updates = Updates::new(stream::empty::<()>(), Duration::ZERO);
+        assert!(updates.is_modified(&prost_types::Duration {
+            seconds: 1,
+            nanos: 0
+        }));
+        assert!(!updates.is_modified(&prost_types::Duration {
+            seconds: 1,
+            nanos: 0
+        }));
+        assert!(updates.is_modified(&prost_types::Duration {
+            seconds: 2,
+            nanos: 0
+        }));
+    }
+}
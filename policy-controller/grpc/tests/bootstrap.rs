@@ -0,0 +1,139 @@
+//! Generates the protobuf bindings in `src/gen` from the definitions in
+//! `proto`, updating the checked-in bindings and failing if they were stale.
+//! The file descriptors served by the reflection API are generated alongside
+//! them.
+//!
+//! Run `cargo test -p linkerd-policy-controller-grpc --test bootstrap` after
+//! changing a definition and commit the updated bindings.
+
+use std::path::{Path, PathBuf};
+
+/// Packages imported by the simulation API or described by the reflection API,
+/// whose types are provided by the `linkerd2-proxy-api` crate.
+const PROXY_API_PACKAGES: [&str; 8] = [
+    "destination",
+    "grpc_route",
+    "http_route",
+    "http_types",
+    "inbound",
+    "meta",
+    "net",
+    "outbound",
+];
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let gen_dir = root.join("src").join("gen");
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("gen");
+    if out_dir.exists() {
+        std::fs::remove_dir_all(&out_dir).expect("stale bindings must be removable");
+    }
+    std::fs::create_dir_all(&out_dir).expect("output directory must be creatable");
+
+    std::env::set_var(
+        "PROTOC",
+        protoc_bin_vendored::protoc_bin_path().expect("protoc must be available"),
+    );
+    let mut config = tonic_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .emit_rerun_if_changed(false);
+    for pkg in PROXY_API_PACKAGES {
+        config = config.extern_path(
+            format!(".io.linkerd.proxy.{pkg}"),
+            format!("::linkerd2_proxy_api::{pkg}"),
+        );
+    }
+    let proxy_api_protos = proxy_api_protos();
+    config
+        .out_dir(&out_dir)
+        .file_descriptor_set_path(out_dir.join("file_descriptor_set.bin"))
+        .compile(
+            &[
+                root.join("proto").join("health.proto"),
+                root.join("proto").join("reflection.proto"),
+                root.join("proto").join("simulate.proto"),
+                // The policy APIs are only compiled so that they are described
+                // by the file descriptor set.
+                proxy_api_protos.join("inbound.proto"),
+                proxy_api_protos.join("outbound.proto"),
+            ],
+            &[root.join("proto"), proxy_api_protos.clone()],
+        )
+        .expect("protobuf definitions must compile");
+
+    let checked_in = read_dir(&gen_dir);
+    // Service stubs are emitted for imported packages even though their types
+    // are provided by `linkerd2-proxy-api`, so these files are ignored.
+    let generated = read_dir(&out_dir)
+        .into_iter()
+        .filter(|(name, _)| {
+            !PROXY_API_PACKAGES
+                .iter()
+                .any(|pkg| *name == format!("io.linkerd.proxy.{pkg}.rs"))
+        })
+        .collect::<Vec<_>>();
+    for (name, contents) in &generated {
+        if checked_in.iter().find(|(n, _)| n == name).map(|(_, c)| c) != Some(contents) {
+            std::fs::write(gen_dir.join(name), contents).expect("bindings must be writable");
+            panic!("src/gen/{name} was out of date; commit the regenerated bindings");
+        }
+    }
+    for (name, _) in &checked_in {
+        assert!(
+            generated.iter().any(|(n, _)| n == name),
+            "src/gen/{name} is not generated by any definition",
+        );
+    }
+}
+
+/// Locates the definitions shipped with the `linkerd2-proxy-api` crate.
+fn proxy_api_protos() -> PathBuf {
+    // Only the host's dependencies are resolved, so that packages for other
+    // platforms need not be downloaded.
+    let rustc = std::process::Command::new("rustc")
+        .arg("-vV")
+        .output()
+        .expect("rustc must run");
+    let rustc = String::from_utf8(rustc.stdout).expect("rustc output must be UTF-8");
+    let host = rustc
+        .lines()
+        .find_map(|l| l.strip_prefix("host: "))
+        .expect("rustc must report its host");
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = std::process::Command::new(cargo)
+        .args(["metadata", "--format-version=1", "--filter-platform", host])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("cargo metadata must run");
+    assert!(output.status.success(), "cargo metadata failed");
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("cargo metadata must be JSON");
+    let manifest = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|pkg| pkg["name"] == "linkerd2-proxy-api")
+        .and_then(|pkg| pkg["manifest_path"].as_str())
+        .expect("linkerd2-proxy-api must be a dependency");
+    Path::new(manifest)
+        .parent()
+        .expect("manifest must have a parent directory")
+        .join("proto")
+}
+
+fn read_dir(dir: &Path) -> Vec<(String, Vec<u8>)> {
+    let mut files = std::fs::read_dir(dir)
+        .expect("bindings directory must exist")
+        .map(|entry| {
+            let path = entry.expect("bindings directory must be readable").path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let contents = std::fs::read(&path).expect("bindings must be readable");
+            (name, contents)
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
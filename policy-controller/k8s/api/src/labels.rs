@@ -90,6 +90,47 @@ impl Selector {
     }
 }
 
+/// Formats the selector as a Kubernetes label selector query, as used by
+/// list and watch requests.
+///
+/// `In` and `NotIn` expressions without values cannot be expressed in a query,
+/// so they are omitted and the query may select a superset of the resources
+/// this selector matches. Listed resources should be filtered with
+/// [`Selector::matches`].
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sep = "";
+        for (k, v) in self.match_labels.iter().flatten() {
+            write!(f, "{sep}{k}={v}")?;
+            sep = ",";
+        }
+        for Expression {
+            key,
+            operator,
+            values,
+        } in self.match_expressions.iter().flatten()
+        {
+            let values = values
+                .iter()
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(",");
+            if values.is_empty() && matches!(operator, Operator::In | Operator::NotIn) {
+                continue;
+            }
+            match operator {
+                Operator::In => write!(f, "{sep}{key} in ({values})")?,
+                Operator::NotIn => write!(f, "{sep}{key} notin ({values})")?,
+                Operator::Exists => write!(f, "{sep}{key}")?,
+                Operator::DoesNotExist => write!(f, "{sep}!{key}")?,
+            }
+            sep = ",";
+        }
+        Ok(())
+    }
+}
+
 impl std::iter::FromIterator<(String, String)> for Selector {
     fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
         Self::from_map(iter.into_iter().collect())
@@ -270,4 +311,65 @@ mod tests {
             assert_eq!(selector.matches(labels), *matches, "{}", msg);
         }
     }
+
+    #[test]
+    fn test_display() {
+        for (selector, expected) in [
+            (Selector::default(), ""),
+            (
+                Selector::from_iter(vec![("foo", "bar"), ("bah", "baz")]),
+                "bah=baz,foo=bar",
+            ),
+            (
+                Selector::new(
+                    Map::from([("foo".to_string(), "bar".to_string())]),
+                    vec![
+                        Expression {
+                            key: "env".into(),
+                            operator: Operator::In,
+                            values: Some(["prod".to_string(), "dev".to_string()].into()),
+                        },
+                        Expression {
+                            key: "tier".into(),
+                            operator: Operator::NotIn,
+                            values: Some(["db".to_string()].into()),
+                        },
+                        Expression {
+                            key: "app".into(),
+                            operator: Operator::Exists,
+                            values: None,
+                        },
+                        Expression {
+                            key: "canary".into(),
+                            operator: Operator::DoesNotExist,
+                            values: None,
+                        },
+                    ],
+                ),
+                "foo=bar,env in (dev,prod),tier notin (db),app,!canary",
+            ),
+            (
+                Selector::from_iter(vec![
+                    Expression {
+                        key: "env".into(),
+                        operator: Operator::In,
+                        values: Some(BTreeSet::new()),
+                    },
+                    Expression {
+                        key: "tier".into(),
+                        operator: Operator::NotIn,
+                        values: None,
+                    },
+                    Expression {
+                        key: "app".into(),
+                        operator: Operator::Exists,
+                        values: None,
+                    },
+                ]),
+                "app",
+            ),
+        ] {
+            assert_eq!(selector.to_string(), expected);
+        }
+    }
 }
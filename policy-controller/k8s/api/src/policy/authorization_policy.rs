@@ -1,4 +1,4 @@
-use super::{LocalTargetRef, NamespacedTargetRef};
+use super::{super::Condition, LocalTargetRef, NamespacedTargetRef};
 
 #[derive(
     Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
@@ -7,6 +7,7 @@ use super::{LocalTargetRef, NamespacedTargetRef};
     group = "policy.linkerd.io",
     version = "v1alpha1",
     kind = "AuthorizationPolicy",
+    status = "AuthorizationPolicyStatus",
     namespaced
 )]
 #[serde(rename_all = "camelCase")]
@@ -14,3 +15,11 @@ pub struct AuthorizationPolicySpec {
     pub target_ref: LocalTargetRef,
     pub required_authentication_refs: Vec<NamespacedTargetRef>,
 }
+
+/// Describes whether an `AuthorizationPolicy`'s references resolve.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct AuthorizationPolicyStatus {
+    pub conditions: Vec<Condition>,
+}
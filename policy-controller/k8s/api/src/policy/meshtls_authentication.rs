@@ -1,4 +1,4 @@
-use super::NamespacedTargetRef;
+use super::{super::Condition, NamespacedTargetRef};
 
 #[derive(
     Clone,
@@ -15,6 +15,9 @@ use super::NamespacedTargetRef;
     group = "policy.linkerd.io",
     version = "v1alpha1",
     kind = "MeshTLSAuthentication",
+    status = "MeshTLSAuthenticationStatus",
+    printcolumn = r#"{"name":"Policies", "type":"integer", "jsonPath":".status.authorizationPolicyCount"}"#,
+    printcolumn = r#"{"name":"Age", "type":"date", "jsonPath":".metadata.creationTimestamp"}"#,
     namespaced
 )]
 #[serde(rename_all = "camelCase")]
@@ -22,3 +25,19 @@ pub struct MeshTLSAuthenticationSpec {
     pub identities: Option<Vec<String>>,
     pub identity_refs: Option<Vec<NamespacedTargetRef>>,
 }
+
+/// Describes the AuthorizationPolicies that require it and whether its identity
+/// references resolve.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MeshTLSAuthenticationStatus {
+    /// The AuthorizationPolicies, as `namespace/name`, that require this
+    /// authentication.
+    #[serde(default)]
+    pub authorization_policies: Vec<String>,
+    #[serde(default)]
+    pub authorization_policy_count: u32,
+    pub conditions: Vec<Condition>,
+}
@@ -1,3 +1,4 @@
+use super::super::Condition;
 pub use super::Network;
 
 #[derive(
@@ -15,9 +16,27 @@ pub use super::Network;
     group = "policy.linkerd.io",
     version = "v1alpha1",
     kind = "NetworkAuthentication",
+    status = "NetworkAuthenticationStatus",
+    printcolumn = r#"{"name":"Policies", "type":"integer", "jsonPath":".status.authorizationPolicyCount"}"#,
+    printcolumn = r#"{"name":"Age", "type":"date", "jsonPath":".metadata.creationTimestamp"}"#,
     namespaced
 )]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkAuthenticationSpec {
     pub networks: Vec<Network>,
 }
+
+/// Describes the AuthorizationPolicies that require it.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkAuthenticationStatus {
+    /// The AuthorizationPolicies, as `namespace/name`, that require this
+    /// authentication.
+    #[serde(default)]
+    pub authorization_policies: Vec<String>,
+    #[serde(default)]
+    pub authorization_policy_count: u32,
+    pub conditions: Vec<Condition>,
+}
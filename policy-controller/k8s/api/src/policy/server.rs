@@ -1,41 +1,98 @@
-use super::super::labels;
+use super::super::{labels, Condition};
 use kube::CustomResource;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
-use std::{fmt, num::NonZeroU16};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt,
+    num::{NonZeroU16, ParseIntError},
+    str::FromStr,
+};
 
 /// Describes a server interface exposed by a set of pods.
 #[derive(Clone, Debug, PartialEq, Eq, CustomResource, Deserialize, Serialize, JsonSchema)]
 #[kube(
     group = "policy.linkerd.io",
-    version = "v1beta2",
+    version = "v1beta3",
     kind = "Server",
+    status = "ServerStatus",
     namespaced
 )]
 #[serde(rename_all = "camelCase")]
 pub struct ServerSpec {
     #[serde(flatten)]
     pub selector: Selector,
-    pub port: Port,
+
+    /// A single port. Exactly one of `port` or `ports` must be set.
+    ///
+    /// When the server selects workloads through a Service, ports refer to
+    /// the Service's ports rather than to the workloads' ports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<Port>,
+
+    /// A list of ports, each of which may also be a range of port numbers
+    /// (e.g. `9000-9010`). Servers with the opaque proxy protocol must set
+    /// `port` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<Port>>,
+
     pub proxy_protocol: Option<ProxyProtocol>,
 }
 
+/// Describes how a `Server` applies to the workloads in its namespace.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct ServerStatus {
+    pub conditions: Vec<Condition>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 pub enum Selector {
     #[serde(rename = "podSelector")]
     Pod(labels::Selector),
     #[serde(rename = "externalWorkloadSelector")]
     ExternalWorkload(labels::Selector),
+    #[serde(rename = "serviceRef")]
+    Service(ServiceRef),
+}
+
+/// References a Service in the same namespace. The server selects the
+/// workloads selected by the Service, on the ports targeted by the Service's
+/// ports. Servers with the opaque proxy protocol must select workloads
+/// directly instead.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct ServiceRef {
+    pub name: String,
 }
 
-/// References a pod spec's port by name or number.
+/// References a pod spec's port by name or number, or a range of port
+/// numbers.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum Port {
     Number(NonZeroU16),
+    Range(PortRange),
     Name(String),
 }
 
+/// An inclusive range of port numbers, written as `<first>-<last>` (e.g.
+/// `9000-9010`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PortRange {
+    first: NonZeroU16,
+    last: NonZeroU16,
+}
+
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+pub enum PortRangeError {
+    #[error("expected a range of port numbers, e.g. '9000-9010'")]
+    Syntax,
+
+    #[error("invalid port number: {0}")]
+    Port(#[from] ParseIntError),
+
+    #[error("the first port must not be greater than the last")]
+    Reversed,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 pub enum ProxyProtocol {
     #[serde(rename = "unknown")]
@@ -52,11 +109,173 @@ pub enum ProxyProtocol {
     Tls,
 }
 
+// === impl ServerSpec ===
+
+impl ServerSpec {
+    /// Iterates over the ports referenced by either `port` or `ports`.
+    pub fn port_refs(&self) -> impl Iterator<Item = &Port> {
+        self.port.iter().chain(self.ports.iter().flatten())
+    }
+}
+
+// === impl Port ===
+
 impl fmt::Display for Port {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Port::Number(n) => fmt::Display::fmt(n, f),
+            Port::Range(r) => fmt::Display::fmt(r, f),
             Port::Name(n) => fmt::Display::fmt(n, f),
         }
     }
 }
+
+// === impl PortRange ===
+
+impl PortRange {
+    pub fn first(&self) -> NonZeroU16 {
+        self.first
+    }
+
+    pub fn last(&self) -> NonZeroU16 {
+        self.last
+    }
+
+    pub fn contains(&self, port: NonZeroU16) -> bool {
+        self.first <= port && port <= self.last
+    }
+}
+
+impl FromStr for PortRange {
+    type Err = PortRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, last) = s.split_once('-').ok_or(PortRangeError::Syntax)?;
+        let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_number(first) || !is_number(last) {
+            return Err(PortRangeError::Syntax);
+        }
+        let first = first.parse::<NonZeroU16>()?;
+        let last = last.parse::<NonZeroU16>()?;
+        if first > last {
+            return Err(PortRangeError::Reversed);
+        }
+        Ok(Self { first, last })
+    }
+}
+
+impl fmt::Display for PortRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.first, self.last)
+    }
+}
+
+impl Serialize for PortRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PortRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PortRange;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a range of port numbers, e.g. '9000-9010'")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                value.parse().map_err(de::Error::custom)
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl JsonSchema for PortRange {
+    fn schema_name() -> String {
+        "PortRange".to_owned()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some("^[0-9]+-[0-9]+$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ports() {
+        let parse = |s: &str| serde_json::from_str::<Port>(s).unwrap();
+        assert_eq!(parse("8080"), Port::Number(8080.try_into().unwrap()));
+        assert_eq!(
+            parse(r#""admin-http""#),
+            Port::Name("admin-http".to_string())
+        );
+
+        let Port::Range(range) = parse(r#""9000-9002""#) else {
+            panic!("a range must be parsed");
+        };
+        assert_eq!((range.first().get(), range.last().get()), (9000, 9002));
+        assert_eq!(
+            serde_json::to_string(&Port::Range(range)).unwrap(),
+            r#""9000-9002""#
+        );
+
+        // Invalid ranges are not ranges, though they are not valid port names
+        // either, since port names must contain a letter.
+        assert_eq!(parse(r#""9002-9000""#), Port::Name("9002-9000".to_string()));
+    }
+
+    #[test]
+    fn parses_port_ranges() {
+        assert_eq!(
+            "1-65535"
+                .parse::<PortRange>()
+                .map(|r| (r.first().get(), r.last().get())),
+            Ok((1, 65535))
+        );
+        let range = "80-80".parse::<PortRange>().unwrap();
+        assert!(range.contains(80.try_into().unwrap()));
+        assert!(!range.contains(79.try_into().unwrap()));
+        assert!(!range.contains(81.try_into().unwrap()));
+        assert_eq!("80".parse::<PortRange>(), Err(PortRangeError::Syntax));
+        assert_eq!("80-".parse::<PortRange>(), Err(PortRangeError::Syntax));
+        assert_eq!("+80-90".parse::<PortRange>(), Err(PortRangeError::Syntax));
+        assert_eq!("http-90".parse::<PortRange>(), Err(PortRangeError::Syntax));
+        assert!(matches!(
+            "0-90".parse::<PortRange>(),
+            Err(PortRangeError::Port(_))
+        ));
+        assert!(matches!(
+            "80-65536".parse::<PortRange>(),
+            Err(PortRangeError::Port(_))
+        ));
+        assert_eq!("90-80".parse::<PortRange>(), Err(PortRangeError::Reversed));
+    }
+}
@@ -0,0 +1,81 @@
+//! Surfaces problems found while indexing resources as Kubernetes `Warning`
+//! events on the offending objects.
+//!
+//! Indexes are updated synchronously, so warnings are queued onto a bounded
+//! channel and published by a separate task. Warnings are dropped when the
+//! channel is full, since they are also logged.
+
+use linkerd_policy_controller_k8s_api::{api::core::v1::ObjectReference, Resource};
+use tokio::sync::mpsc;
+
+/// A handle used by indexes to emit warnings about objects.
+///
+/// The default handle discards all warnings.
+#[derive(Clone, Debug, Default)]
+pub struct Events {
+    tx: Option<mpsc::Sender<Warning>>,
+}
+
+/// A problem with an object that should be surfaced to its owners.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warning {
+    pub object: ObjectReference,
+
+    /// A short, CamelCase description of the problem, e.g. `InvalidAnnotation`.
+    pub reason: &'static str,
+    pub note: String,
+}
+
+pub mod reasons {
+    pub const INVALID_ANNOTATION: &str = "InvalidAnnotation";
+    pub const INVALID_PROBE_PATH: &str = "InvalidProbePath";
+    pub const INVALID_RESOURCE: &str = "InvalidResource";
+    pub const PORT_NOT_FOUND: &str = "PortNotFound";
+}
+
+// === impl Events ===
+
+impl Events {
+    pub fn channel(capacity: usize) -> (Self, mpsc::Receiver<Warning>) {
+        let (tx, rx) = mpsc::channel(capacity);
+        (Self { tx: Some(tx) }, rx)
+    }
+
+    /// Emits a warning about `resource`.
+    pub fn warn<K>(&self, resource: &K, reason: &'static str, note: impl ToString)
+    where
+        K: Resource<DynamicType = ()>,
+    {
+        self.warn_ref(resource.object_ref(&()), reason, note)
+    }
+
+    /// Emits a warning about the referenced object.
+    pub fn warn_ref(&self, object: ObjectReference, reason: &'static str, note: impl ToString) {
+        let Some(tx) = self.tx.as_ref() else {
+            return;
+        };
+        let warning = Warning {
+            object,
+            reason,
+            note: note.to_string(),
+        };
+        if let Err(error) = tx.try_send(warning) {
+            tracing::debug!(%error, "Dropping warning event");
+        }
+    }
+}
+
+/// Builds a reference to a namespaced object that is not at hand, e.g. one
+/// that has already been consumed by an index.
+pub fn object_ref<K>(namespace: &str, name: &str) -> ObjectReference
+where
+    K: Resource<DynamicType = ()>,
+{
+    ObjectReference {
+        api_version: Some(K::api_version(&()).to_string()),
+        kind: Some(K::kind(&()).to_string()),
+        namespace: Some(namespace.to_string()),
+        name: Some(name.to_string()),
+        ..Default::default()
+    }
+}
@@ -5,9 +5,10 @@ mod meshtls_authentication;
 mod network_authentication;
 mod server;
 pub mod server_authorization;
+mod service;
 mod workload;
 
-pub use index::{metrics, Index, SharedIndex};
+pub use index::{metrics, AuthorizationPolicySummary, Index, ServerSummary, SharedIndex};
 
 #[cfg(test)]
 mod tests;
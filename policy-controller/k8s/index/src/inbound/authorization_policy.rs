@@ -16,6 +16,8 @@ pub(crate) struct Spec {
 pub(crate) enum Target {
     HttpRoute(GroupKindName),
     Server(String),
+    /// Targets the servers that select workloads through a Service.
+    Service(String),
     Namespace,
 }
 
@@ -64,6 +66,7 @@ fn target(t: LocalTargetRef) -> Result<Target> {
     match t {
         t if t.targets_kind::<k8s::policy::Server>() => Ok(Target::Server(t.name)),
         t if t.targets_kind::<k8s::Namespace>() => Ok(Target::Namespace),
+        t if t.targets_kind::<k8s::Service>() => Ok(Target::Service(t.name)),
         t if t.targets_kind::<k8s::policy::HttpRoute>()
             || t.targets_kind::<k8s_gateway_api::HttpRoute>() =>
         {
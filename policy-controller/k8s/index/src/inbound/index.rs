@@ -7,10 +7,14 @@
 //! kubernetes resources.
 
 use super::{
-    authorization_policy, http_route::RouteBinding, meshtls_authentication, network_authentication,
-    server, server_authorization, workload,
+    authorization_policy,
+    http_route::RouteBinding,
+    meshtls_authentication, network_authentication,
+    server::{self, ServerPorts},
+    server_authorization, service, workload,
 };
 use crate::{
+    events::{self, reasons, Events},
     ports::{PortHasher, PortMap, PortSet},
     routes::{ExplicitGKN, ImpliedGKN},
     ClusterInfo, DefaultPolicy,
@@ -23,19 +27,19 @@ use linkerd_policy_controller_core::{
         HttpRouteRule, InboundServer, ProxyProtocol, ServerRef,
     },
     routes::{GroupKindName, HttpRouteMatch, Method, PathMatch},
-    IdentityMatch, Ipv4Net, Ipv6Net, NetworkMatch,
+    IdentityMatch, Ipv4Net, Ipv6Net, NetworkMatch, Timestamped,
 };
 use linkerd_policy_controller_k8s_api::{
-    self as k8s, gateway as k8s_gateway_api, policy::server::Port, policy::server::Selector,
-    ResourceExt,
+    self as k8s, gateway as k8s_gateway_api, policy::server::Selector, ResourceExt,
 };
 use parking_lot::RwLock;
 use std::{
+    borrow::Cow,
     collections::{hash_map::Entry, BTreeSet},
     num::NonZeroU16,
     sync::Arc,
 };
-use tokio::sync::watch;
+use tokio::sync::{watch, Notify};
 use tracing::info_span;
 
 pub mod metrics;
@@ -50,6 +54,18 @@ pub struct Index {
     cluster_info: Arc<ClusterInfo>,
     namespaces: NamespaceIndex,
     authentications: AuthenticationNsIndex,
+    events: Events,
+
+    /// Namespaces in which the policy of workloads or servers may have changed
+    /// since they were last taken with `take_changed_namespaces`.
+    changed_namespaces: HashSet<String>,
+
+    /// Servers, by namespace and name, whose selected workloads may have
+    /// changed since they were last taken with `take_changed_servers`. Workload
+    /// changes only affect the servers that select them, so they do not change
+    /// the whole namespace.
+    changed_servers: HashSet<(String, String)>,
+    changed: Arc<Notify>,
 }
 
 /// Holds all `Pod`, `Server`, and `ServerAuthorization` indices by-namespace.
@@ -91,6 +107,9 @@ struct PodIndex {
 struct Pod {
     meta: workload::Meta,
 
+    /// The mesh identity of the pod's service account.
+    identity: String,
+
     /// The pod's named container ports. Used by `Server` port selectors.
     ///
     /// A pod may have multiple ports with the same name. E.g., each container
@@ -121,7 +140,7 @@ struct WorkloadPortServer {
     name: Option<String>,
 
     /// A sender used to broadcast workload port server updates.
-    watch: watch::Sender<InboundServer>,
+    watch: watch::Sender<Timestamped<InboundServer>>,
 }
 
 /// Holds all external workload data for a single namespace
@@ -140,6 +159,9 @@ struct ExternalWorkloadIndex {
 struct ExternalWorkload {
     meta: workload::Meta,
 
+    /// The workload's mesh identity, as configured in its spec.
+    identity: String,
+
     // The workload's named container ports. Used by `Server` port selectors.
     //
     // A workload will not have multiple ports with the same name, e.g. two
@@ -161,6 +183,16 @@ struct PolicyIndex {
 
     authorization_policies: HashMap<String, authorization_policy::Spec>,
     http_routes: HashMap<GroupKindName, RouteBinding>,
+
+    /// Services that may be referenced by servers.
+    services: HashMap<String, service::Service>,
+}
+
+/// The kinds of workloads that servers may select.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum WorkloadKind {
+    Pod,
+    External,
 }
 
 #[derive(Debug, Default)]
@@ -169,6 +201,44 @@ struct AuthenticationIndex {
     network: HashMap<String, network_authentication::Spec>,
 }
 
+/// Describes how a `Server` applies to the workloads in its namespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServerSummary {
+    /// The number of pods selected by the server.
+    pub pods: usize,
+
+    /// The number of external workloads selected by the server.
+    pub external_workloads: usize,
+
+    /// The number of selected workloads that do not declare the server's
+    /// named port.
+    pub unresolved_port: usize,
+
+    /// Other servers that serve a workload port selected by this server.
+    pub conflicts: BTreeSet<String>,
+
+    /// The number of authorizations that apply to the server.
+    pub authorizations: usize,
+}
+
+/// Describes whether an `AuthorizationPolicy`'s references resolve.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuthorizationPolicySummary {
+    /// The policy's target, if it is not indexed.
+    pub missing_target: Option<String>,
+
+    /// The required authentications that are not indexed.
+    pub missing_authentications: Vec<String>,
+
+    /// The namespace and name of each required `ServiceAccount`. Service
+    /// accounts are not indexed, so callers must check that these exist.
+    pub service_accounts: Vec<(String, String)>,
+
+    /// Describes why the policy cannot be applied even though its
+    /// references resolve.
+    pub error: Option<String>,
+}
+
 struct NsUpdate<K, T> {
     added: Vec<(K, T)>,
     removed: HashSet<K>,
@@ -177,7 +247,7 @@ struct NsUpdate<K, T> {
 // === impl Index ===
 
 impl Index {
-    pub fn shared(cluster_info: impl Into<Arc<ClusterInfo>>) -> SharedIndex {
+    pub fn shared(cluster_info: impl Into<Arc<ClusterInfo>>, events: Events) -> SharedIndex {
         let cluster_info = cluster_info.into();
         Arc::new(RwLock::new(Self {
             cluster_info: cluster_info.clone(),
@@ -186,19 +256,60 @@ impl Index {
                 by_ns: HashMap::default(),
             },
             authentications: AuthenticationNsIndex::default(),
+            events,
+            changed_namespaces: HashSet::default(),
+            changed_servers: HashSet::default(),
+            changed: Arc::new(Notify::new()),
         }))
     }
 
+    /// Returns a handle that is notified when a namespace changes, so that
+    /// dependents (e.g. server statuses) can be updated.
+    pub fn changed(&self) -> Arc<Notify> {
+        self.changed.clone()
+    }
+
+    /// Takes the namespaces that have changed since this was last called.
+    pub fn take_changed_namespaces(&mut self) -> HashSet<String> {
+        std::mem::take(&mut self.changed_namespaces)
+    }
+
+    /// Takes the servers, by namespace and name, whose selected workloads have
+    /// changed since this was last called.
+    pub fn take_changed_servers(&mut self) -> HashSet<(String, String)> {
+        std::mem::take(&mut self.changed_servers)
+    }
+
+    fn namespace_changed(&mut self, namespace: &str) {
+        if !self.changed_namespaces.contains(namespace) {
+            self.changed_namespaces.insert(namespace.to_string());
+        }
+        self.changed.notify_one();
+    }
+
+    fn servers_changed(&mut self, namespace: &str, servers: Vec<String>) {
+        if servers.is_empty() {
+            return;
+        }
+        self.changed_servers.extend(
+            servers
+                .into_iter()
+                .map(|name| (namespace.to_string(), name)),
+        );
+        self.changed.notify_one();
+    }
+
     /// Obtains a pod:port's server receiver.
     ///
     /// An error is returned if the pod is not found. If the port is not found,
-    /// a default is server is created.
+    /// a default is server is created, unless a server selects the port by a
+    /// port range.
     pub fn pod_server_rx(
         &mut self,
         namespace: &str,
         pod: &str,
         port: NonZeroU16,
-    ) -> Result<watch::Receiver<InboundServer>> {
+    ) -> Result<watch::Receiver<Timestamped<InboundServer>>> {
         let ns = self
             .namespaces
             .by_ns
@@ -209,22 +320,30 @@ impl Index {
             .by_name
             .get_mut(pod)
             .ok_or_else(|| anyhow::anyhow!("pod {}.{} not found", pod, namespace))?;
-        Ok(pod
+        let known = pod.port_servers.contains_key(&port);
+        let rx = pod
             .port_server_or_default(port, &self.cluster_info)
             .watch
-            .subscribe())
+            .subscribe();
+        // Ports in ranges are only selected once they are known, so a newly
+        // discovered port may belong to a server.
+        if !known && ns.policy.has_port_ranges() {
+            pod.reindex_servers(&ns.policy, &self.authentications);
+        }
+        Ok(rx)
     }
 
     /// Obtains an external_workload:port's server receiver.
     ///
     /// An error is returned if the external workload is not found. If the port
-    /// is not found, a default server is created.
+    /// is not found, a default server is created, unless a server selects the
+    /// port by a port range.
     pub fn external_workload_server_rx(
         &mut self,
         namespace: &str,
         workload: &str,
         port: NonZeroU16,
-    ) -> Result<watch::Receiver<InboundServer>> {
+    ) -> Result<watch::Receiver<Timestamped<InboundServer>>> {
         let ns = self
             .namespaces
             .by_ns
@@ -237,15 +356,213 @@ impl Index {
                 .ok_or_else(|| {
                     anyhow::anyhow!("external workload {}.{} not found", workload, namespace)
                 })?;
-        Ok(external_workload
+        let known = external_workload.port_servers.contains_key(&port);
+        let rx = external_workload
             .port_server_or_default(port, &self.cluster_info)
             .watch
-            .subscribe())
+            .subscribe();
+        // Ports in ranges are only selected once they are known, so a newly
+        // discovered port may belong to a server.
+        if !known && ns.policy.has_port_ranges() {
+            external_workload.reindex_servers(&ns.policy, &self.authentications);
+        }
+        Ok(rx)
+    }
+
+    /// Returns the mesh identity of a pod, if it is indexed.
+    pub fn pod_identity(&self, namespace: &str, pod: &str) -> Option<&str> {
+        let pod = self
+            .namespaces
+            .by_ns
+            .get(namespace)?
+            .pods
+            .by_name
+            .get(pod)?;
+        Some(&pod.identity)
+    }
+
+    /// Returns the mesh identity of an external workload, if it is indexed.
+    pub fn external_workload_identity(&self, namespace: &str, workload: &str) -> Option<&str> {
+        let workload = self
+            .namespaces
+            .by_ns
+            .get(namespace)?
+            .external_workloads
+            .by_name
+            .get(workload)?;
+        Some(&workload.identity)
+    }
+
+    /// Summarizes how a `Server` applies to the workloads in its namespace.
+    ///
+    /// Returns `None` if the server is not indexed.
+    pub fn server_summary(&self, namespace: &str, name: &str) -> Option<ServerSummary> {
+        let ns = self.namespaces.by_ns.get(namespace)?;
+        let server = ns.policy.servers.get(name)?;
+
+        let mut summary = ServerSummary {
+            authorizations: ns
+                .policy
+                .client_authzs(name, server, &self.authentications)
+                .len(),
+            ..Default::default()
+        };
+        for pod in ns.pods.by_name.values() {
+            let Some(ports) = ns
+                .policy
+                .server_ports(server, WorkloadKind::Pod, &pod.meta.labels)
+            else {
+                continue;
+            };
+            summary.pods += 1;
+            if ports
+                .names
+                .iter()
+                .any(|name| !pod.port_names.contains_key(name))
+            {
+                summary.unresolved_port += 1;
+            }
+            for port in pod.select_ports(&ports) {
+                if let Some(other) = pod.port_servers.get(&port).and_then(|ps| ps.conflict(name)) {
+                    summary.conflicts.insert(other.to_string());
+                }
+            }
+        }
+        for workload in ns.external_workloads.by_name.values() {
+            let Some(ports) =
+                ns.policy
+                    .server_ports(server, WorkloadKind::External, &workload.meta.labels)
+            else {
+                continue;
+            };
+            summary.external_workloads += 1;
+            if ports
+                .names
+                .iter()
+                .any(|name| !workload.port_names.contains_key(name))
+            {
+                summary.unresolved_port += 1;
+            }
+            for port in workload.select_ports(&ports) {
+                if let Some(other) = workload
+                    .port_servers
+                    .get(&port)
+                    .and_then(|ps| ps.conflict(name))
+                {
+                    summary.conflicts.insert(other.to_string());
+                }
+            }
+        }
+
+        Some(summary)
+    }
+
+    /// Summarizes whether an `AuthorizationPolicy`'s target and required
+    /// authentications are indexed.
+    ///
+    /// Returns `None` if the policy is not indexed.
+    pub fn authorization_policy_summary(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Option<AuthorizationPolicySummary> {
+        use authorization_policy::{AuthenticationTarget, Target};
+
+        let ns = self.namespaces.by_ns.get(namespace)?;
+        let spec = ns.policy.authorization_policies.get(name)?;
+
+        let missing_target = match &spec.target {
+            Target::Server(name) if !ns.policy.servers.contains_key(name) => {
+                Some(format!("Server {name}"))
+            }
+            Target::Service(name) if !ns.policy.services.contains_key(name) => {
+                Some(format!("Service {name}"))
+            }
+            Target::HttpRoute(gkn)
+                if !ns
+                    .policy
+                    .http_routes
+                    .keys()
+                    .any(|route| route.eq_ignore_ascii_case(gkn)) =>
+            {
+                Some(format!("{} {}", gkn.kind, gkn.name))
+            }
+            _ => None,
+        };
+
+        let mut summary = AuthorizationPolicySummary {
+            missing_target,
+            ..Default::default()
+        };
+        for authn in &spec.authentications {
+            match authn {
+                AuthenticationTarget::MeshTLS {
+                    namespace: authn_ns,
+                    name,
+                } => {
+                    let authn_ns = authn_ns.as_deref().unwrap_or(namespace);
+                    let found = self
+                        .authentications
+                        .by_ns
+                        .get(authn_ns)
+                        .map_or(false, |ns| ns.meshtls.contains_key(name));
+                    if !found {
+                        summary
+                            .missing_authentications
+                            .push(format!("MeshTLSAuthentication {authn_ns}/{name}"));
+                    }
+                }
+                AuthenticationTarget::Network {
+                    namespace: authn_ns,
+                    name,
+                } => {
+                    let authn_ns = authn_ns.as_deref().unwrap_or(namespace);
+                    let found = self
+                        .authentications
+                        .by_ns
+                        .get(authn_ns)
+                        .map_or(false, |ns| ns.network.contains_key(name));
+                    if !found {
+                        summary
+                            .missing_authentications
+                            .push(format!("NetworkAuthentication {authn_ns}/{name}"));
+                    }
+                }
+                AuthenticationTarget::ServiceAccount {
+                    namespace: sa_ns,
+                    name,
+                } => {
+                    let sa_ns = sa_ns.as_deref().unwrap_or(namespace);
+                    summary
+                        .service_accounts
+                        .push((sa_ns.to_string(), name.clone()));
+                }
+            }
+        }
+
+        // Missing authentications are already reported, so only report other
+        // errors (e.g. multiple identity sources).
+        if summary.missing_authentications.is_empty() {
+            summary.error = ns
+                .policy
+                .policy_client_authz(spec, &self.authentications)
+                .err()
+                .map(|error| error.to_string());
+        }
+
+        Some(summary)
     }
 
     fn ns_with_reindex(&mut self, namespace: String, f: impl FnOnce(&mut Namespace) -> bool) {
+        let mut changed = false;
         self.namespaces
-            .get_with_reindex(namespace, &self.authentications, f)
+            .get_with_reindex(namespace.clone(), &self.authentications, |ns| {
+                changed = f(ns);
+                changed
+            });
+        if changed {
+            self.namespace_changed(&namespace);
+        }
     }
 
     fn ns_or_default_with_reindex(
@@ -253,15 +570,29 @@ impl Index {
         namespace: String,
         f: impl FnOnce(&mut Namespace) -> bool,
     ) {
-        self.namespaces
-            .get_or_default_with_reindex(namespace, &self.authentications, f)
+        let mut changed = false;
+        self.namespaces.get_or_default_with_reindex(
+            namespace.clone(),
+            &self.authentications,
+            |ns| {
+                changed = f(ns);
+                changed
+            },
+        );
+        if changed {
+            self.namespace_changed(&namespace);
+        }
     }
 
     fn reindex_all(&mut self) {
         tracing::debug!("Reindexing all namespaces");
-        for ns in self.namespaces.by_ns.values_mut() {
+        for (name, ns) in self.namespaces.by_ns.iter_mut() {
             ns.reindex(&self.authentications);
+            if !self.changed_namespaces.contains(name) {
+                self.changed_namespaces.insert(name.clone());
+            }
         }
+        self.changed.notify_one();
     }
 
     fn apply_route<R>(&mut self, route: R)
@@ -279,6 +610,11 @@ impl Index {
             Ok(binding) => binding,
             Err(error) => {
                 tracing::info!(%ns, %name, %error, "Ignoring HTTPRoute");
+                self.events.warn_ref(
+                    events::object_ref::<R>(&ns, &name),
+                    reasons::INVALID_RESOURCE,
+                    error,
+                );
                 return;
             }
         };
@@ -306,6 +642,11 @@ impl Index {
                 Ok(binding) => binding,
                 Err(error) => {
                     tracing::info!(ns = %namespace, %name, %error, "Ignoring HTTPRoute");
+                    self.events.warn_ref(
+                        events::object_ref::<R>(&namespace, &name),
+                        reasons::INVALID_RESOURCE,
+                        error,
+                    );
                     continue;
                 }
             };
@@ -378,31 +719,71 @@ impl kubert::index::IndexNamespacedResource<k8s::Pod> for Index {
             .map(workload::pod_http_probes)
             .unwrap_or_default();
 
+        for (reason, note) in workload::metadata_warnings(&pod.metadata)
+            .into_iter()
+            .chain(
+                pod.spec
+                    .as_ref()
+                    .map(workload::pod_probe_warnings)
+                    .unwrap_or_default(),
+            )
+        {
+            self.events.warn(&pod, reason, note);
+        }
+
+        let identity = self.cluster_info.service_account_identity(
+            &namespace,
+            pod.spec
+                .as_ref()
+                .and_then(|spec| spec.service_account_name.as_deref())
+                .unwrap_or("default"),
+        );
         let meta = workload::Meta::from_metadata(pod.metadata);
 
         // Add or update the pod. If the pod was not already present in the
         // index with the same metadata, index it against the policy resources,
         // updating its watches.
-        let ns = self.namespaces.get_or_default(namespace);
-        match ns.pods.update(name, meta, port_names, probes) {
-            Ok(None) => {}
-            Ok(Some(pod)) => pod.reindex_servers(&ns.policy, &self.authentications),
+        let ns = self.namespaces.get_or_default(namespace.clone());
+        let prior_labels = ns
+            .pods
+            .by_name
+            .get(&name)
+            .map(|pod| pod.meta.labels.clone());
+        let servers = match ns.pods.update(name, meta, identity, port_names, probes) {
+            Ok(None) => return,
+            Ok(Some(pod)) => {
+                pod.reindex_servers(&ns.policy, &self.authentications);
+                // Only the servers that selected the pod, or that now select
+                // it, are affected by the update.
+                let labels = prior_labels.iter().chain(Some(&pod.meta.labels));
+                ns.policy.servers_selecting(WorkloadKind::Pod, labels)
+            }
             Err(error) => {
                 tracing::error!(%error, "Illegal pod update");
+                return;
             }
-        }
+        };
+        self.servers_changed(&namespace, servers);
     }
 
     fn delete(&mut self, ns: String, name: String) {
         tracing::debug!(%ns, %name, "delete");
-        if let Entry::Occupied(mut ns) = self.namespaces.by_ns.entry(ns) {
+        if let Entry::Occupied(mut entry) = self.namespaces.by_ns.entry(ns.clone()) {
             // Once the pod is removed, there's nothing else to update. Any open
             // watches will complete.  No other parts of the index need to be
             // updated.
-            if ns.get_mut().pods.by_name.remove(&name).is_some() && ns.get().is_empty() {
-                tracing::debug!(namespace = ns.key(), "Removing empty namespace index");
-                ns.remove();
+            let Some(pod) = entry.get_mut().pods.by_name.remove(&name) else {
+                return;
+            };
+            let servers = entry
+                .get()
+                .policy
+                .servers_selecting(WorkloadKind::Pod, Some(&pod.meta.labels));
+            if entry.get().is_empty() {
+                tracing::debug!(namespace = entry.key(), "Removing empty namespace index");
+                entry.remove();
             }
+            self.servers_changed(&ns, servers);
         }
     }
 
@@ -420,40 +801,61 @@ impl kubert::index::IndexNamespacedResource<k8s::external_workload::ExternalWork
         // Note: external workloads do not have any probe paths to synthesise
         // default policies for.
         let port_names = workload::external_tcp_ports_by_name(&ext_workload.spec);
+        for (reason, note) in workload::metadata_warnings(&ext_workload.metadata) {
+            self.events.warn(&ext_workload, reason, note);
+        }
+        let identity = ext_workload.spec.mesh_tls.identity;
         let meta = workload::Meta::from_metadata(ext_workload.metadata);
 
         // Add or update the workload.
         //
         // If the resource is present in the index, but its metadata has
         // changed, then it means the watches need to get an update.
-        let ns = self.namespaces.get_or_default(ns);
-        match ns.external_workloads.update(name, meta, port_names) {
+        let namespace = self.namespaces.get_or_default(ns.clone());
+        let prior_labels = namespace
+            .external_workloads
+            .by_name
+            .get(&name)
+            .map(|workload| workload.meta.labels.clone());
+        let servers = match namespace
+            .external_workloads
+            .update(name, meta, identity, port_names)
+        {
             // No update
-            Ok(None) => {}
+            Ok(None) => return,
             // Update, so re-index
-            Ok(Some(workload)) => workload.reindex_servers(&ns.policy, &self.authentications),
+            Ok(Some(workload)) => {
+                workload.reindex_servers(&namespace.policy, &self.authentications);
+                let labels = prior_labels.iter().chain(Some(&workload.meta.labels));
+                namespace
+                    .policy
+                    .servers_selecting(WorkloadKind::External, labels)
+            }
             Err(error) => {
                 tracing::error!(%error, "Illegal external workload update");
+                return;
             }
-        }
+        };
+        self.servers_changed(&ns, servers);
     }
 
     fn delete(&mut self, ns: String, name: String) {
         tracing::debug!(%ns, %name, "delete");
-        if let Entry::Occupied(mut ns) = self.namespaces.by_ns.entry(ns) {
+        if let Entry::Occupied(mut entry) = self.namespaces.by_ns.entry(ns.clone()) {
             // Once the external workload is removed, there's nothing else to
             // update. Any open watches will complete. No other parts of the
             // index need to be updated.
-            if ns
-                .get_mut()
-                .external_workloads
-                .by_name
-                .remove(&name)
-                .is_some()
-                && ns.get().is_empty()
-            {
-                ns.remove();
+            let Some(workload) = entry.get_mut().external_workloads.by_name.remove(&name) else {
+                return;
+            };
+            let servers = entry
+                .get()
+                .policy
+                .servers_selecting(WorkloadKind::External, Some(&workload.meta.labels));
+            if entry.get().is_empty() {
+                entry.remove();
             }
+            self.servers_changed(&ns, servers);
         }
     }
 
@@ -467,8 +869,28 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::Server> for Index {
         let name = srv.name_unchecked();
         let _span = info_span!("apply", %ns, %name).entered();
 
+        let object = k8s::Resource::object_ref(&srv, &());
         let server = server::Server::from_resource(srv, &self.cluster_info);
-        self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_server(name, server))
+        let port_names = server.ports.names.join(", ");
+        self.ns_or_default_with_reindex(ns.clone(), |ns| {
+            ns.policy.update_server(name.clone(), server)
+        });
+
+        // Warn when the server's named ports cannot be resolved on some of the
+        // workloads it selects, since they do not apply to them.
+        if let Some(summary) = self.server_summary(&ns, &name) {
+            if summary.unresolved_port > 0 {
+                self.events.warn_ref(
+                    object,
+                    reasons::PORT_NOT_FOUND,
+                    format!(
+                        "port {port_names} is not declared by {} of {} selected workloads",
+                        summary.unresolved_port,
+                        summary.pods + summary.external_workloads
+                    ),
+                );
+            }
+        }
     }
 
     fn delete(&mut self, ns: String, name: String) {
@@ -526,6 +948,29 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::Server> for Index {
     }
 }
 
+impl kubert::index::IndexNamespacedResource<k8s::Service> for Index {
+    fn apply(&mut self, svc: k8s::Service) {
+        let ns = svc.namespace().expect("service must be namespaced");
+        let name = svc.name_unchecked();
+        let _span = info_span!("apply", %ns, %name).entered();
+
+        let svc = service::Service::from_resource(&svc);
+        self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_service(name, Some(svc)));
+    }
+
+    fn delete(&mut self, ns: String, name: String) {
+        let _span = info_span!("delete", %ns, %name).entered();
+        // Empty namespaces are removed, even if no servers reference the
+        // Service.
+        self.ns_with_reindex(ns, |ns| {
+            ns.policy.update_service(name, None) || ns.is_empty()
+        })
+    }
+
+    // Since apply only reindexes the servers that reference a Service, there's
+    // no need to handle resets specially.
+}
+
 impl kubert::index::IndexNamespacedResource<k8s::policy::ServerAuthorization> for Index {
     fn apply(&mut self, saz: k8s::policy::ServerAuthorization) {
         let ns = saz.namespace().unwrap();
@@ -536,7 +981,14 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::ServerAuthorization> fo
             Ok(meta) => self.ns_or_default_with_reindex(ns, move |ns| {
                 ns.policy.update_server_authz(name, meta)
             }),
-            Err(error) => tracing::error!(%error, "Illegal server authorization update"),
+            Err(error) => {
+                tracing::error!(%error, "Illegal server authorization update");
+                self.events.warn_ref(
+                    events::object_ref::<k8s::policy::ServerAuthorization>(&ns, &name),
+                    reasons::INVALID_RESOURCE,
+                    error,
+                );
+            }
         }
     }
 
@@ -570,7 +1022,12 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::ServerAuthorization> fo
                     .added
                     .push((name, saz)),
                 Err(error) => {
-                    tracing::error!(ns = %namespace, %name, %error, "Illegal server authorization update")
+                    tracing::error!(ns = %namespace, %name, %error, "Illegal server authorization update");
+                    self.events.warn_ref(
+                        events::object_ref::<k8s::policy::ServerAuthorization>(&namespace, &name),
+                        reasons::INVALID_RESOURCE,
+                        error,
+                    );
                 }
             }
         }
@@ -617,6 +1074,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid authorization policy");
+                self.events.warn_ref(
+                    events::object_ref::<k8s::policy::AuthorizationPolicy>(&ns, &name),
+                    reasons::INVALID_RESOURCE,
+                    error,
+                );
                 return;
             }
         };
@@ -656,7 +1118,12 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
                     .added
                     .push((name, spec)),
                 Err(error) => {
-                    tracing::error!(ns = %namespace, %name, %error, "Illegal server authorization update")
+                    tracing::error!(ns = %namespace, %name, %error, "Illegal server authorization update");
+                    self.events.warn_ref(
+                        events::object_ref::<k8s::policy::AuthorizationPolicy>(&namespace, &name),
+                        reasons::INVALID_RESOURCE,
+                        error,
+                    );
                 }
             }
         }
@@ -706,6 +1173,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication>
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid MeshTLSAuthentication");
+                self.events.warn_ref(
+                    events::object_ref::<k8s::policy::MeshTLSAuthentication>(&ns, &name),
+                    reasons::INVALID_RESOURCE,
+                    error,
+                );
                 return;
             }
         };
@@ -751,6 +1223,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication>
                 Ok(spec) => spec,
                 Err(error) => {
                     tracing::warn!(ns = %namespace, %name, %error, "Invalid MeshTLSAuthentication");
+                    self.events.warn_ref(
+                        events::object_ref::<k8s::policy::MeshTLSAuthentication>(&namespace, &name),
+                        reasons::INVALID_RESOURCE,
+                        error,
+                    );
                     continue;
                 }
             };
@@ -783,6 +1260,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid NetworkAuthentication");
+                self.events.warn_ref(
+                    events::object_ref::<k8s::policy::NetworkAuthentication>(&ns, &name),
+                    reasons::INVALID_RESOURCE,
+                    error,
+                );
                 return;
             }
         };
@@ -826,6 +1308,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
                 Ok(spec) => spec,
                 Err(error) => {
                     tracing::warn!(ns = %namespace, %name, %error, "Invalid NetworkAuthentication");
+                    self.events.warn_ref(
+                        events::object_ref::<k8s::policy::NetworkAuthentication>(&namespace, &name),
+                        reasons::INVALID_RESOURCE,
+                        error,
+                    );
                     return;
                 }
             };
@@ -953,6 +1440,7 @@ impl Namespace {
                 server_authorizations: HashMap::default(),
                 authorization_policies: HashMap::default(),
                 http_routes: HashMap::default(),
+                services: HashMap::default(),
             },
         }
     }
@@ -982,12 +1470,14 @@ impl PodIndex {
         &mut self,
         name: String,
         meta: workload::Meta,
+        identity: String,
         port_names: HashMap<String, PortSet>,
         probes: PortMap<BTreeSet<String>>,
     ) -> Result<Option<&mut Pod>> {
         let pod = match self.by_name.entry(name.clone()) {
             Entry::Vacant(entry) => entry.insert(Pod {
                 meta,
+                identity,
                 port_names,
                 port_servers: PortMap::default(),
                 probes,
@@ -1045,36 +1535,34 @@ impl Pod {
         );
 
         for (srvname, server) in policy.servers.iter() {
-            if let Selector::Pod(pod_selector) = &server.selector {
-                if pod_selector.matches(&self.meta.labels) {
-                    for port in self.select_ports(&server.port_ref).into_iter() {
-                        // If the port is already matched to a server, then log a warning and skip
-                        // updating it so it doesn't flap between servers.
-                        if let Some(prior) = matched_ports.get(&port) {
-                            tracing::warn!(
-                                port = %port,
-                                server = %prior,
-                                conflict = %srvname,
-                                "Port already matched by another server; skipping"
-                            );
-                            continue;
-                        }
-
-                        let s = policy.inbound_server(
-                            srvname.clone(),
-                            server,
-                            authentications,
-                            self.probes
-                                .get(&port)
-                                .into_iter()
-                                .flatten()
-                                .map(|p| p.as_str()),
+            if let Some(ports) = policy.server_ports(server, WorkloadKind::Pod, &self.meta.labels) {
+                for port in self.select_ports(&ports).into_iter() {
+                    // If the port is already matched to a server, then log a warning and skip
+                    // updating it so it doesn't flap between servers.
+                    if let Some(prior) = matched_ports.get(&port) {
+                        tracing::warn!(
+                            port = %port,
+                            server = %prior,
+                            conflict = %srvname,
+                            "Port already matched by another server; skipping"
                         );
-                        self.update_server(port, srvname, s);
-
-                        matched_ports.insert(port, srvname.clone());
-                        unmatched_ports.remove(&port);
+                        continue;
                     }
+
+                    let s = policy.inbound_server(
+                        srvname.clone(),
+                        server,
+                        authentications,
+                        self.probes
+                            .get(&port)
+                            .into_iter()
+                            .flatten()
+                            .map(|p| p.as_str()),
+                    );
+                    self.update_server(port, srvname, s);
+
+                    matched_ports.insert(port, srvname.clone());
+                    unmatched_ports.remove(&port);
                 }
             }
         }
@@ -1093,7 +1581,7 @@ impl Pod {
         match self.port_servers.entry(port) {
             Entry::Vacant(entry) => {
                 tracing::trace!(port = %port, server = %name, "Creating server");
-                let (watch, _) = watch::channel(server);
+                let (watch, _) = watch::channel(Timestamped::new(server));
                 entry.insert(WorkloadPortServer {
                     name: Some(name.to_string()),
                     watch,
@@ -1121,7 +1609,7 @@ impl Pod {
                         ps.name = Some(name.to_string());
                     }
 
-                    *current = server;
+                    current.set(server);
                     true
                 });
             }
@@ -1145,7 +1633,7 @@ impl Pod {
         match self.port_servers.entry(port) {
             Entry::Vacant(entry) => {
                 tracing::debug!(%port, server = %config.default_policy, "Creating default server");
-                let (watch, _) = watch::channel(server);
+                let (watch, _) = watch::channel(Timestamped::new(server));
                 entry.insert(WorkloadPortServer { name: None, watch });
             }
 
@@ -1160,27 +1648,38 @@ impl Pod {
 
                     tracing::debug!(%port, server = %config.default_policy, "Setting default server");
                     ps.name = None;
-                    *current = server;
+                    current.set(server);
                     true
                 });
             }
         }
     }
 
-    /// Enumerates ports.
+    /// Enumerates the ports selected by a server.
     ///
-    /// A named port may refer to an arbitrary number of port numbers.
-    fn select_ports(&mut self, port_ref: &Port) -> Vec<NonZeroU16> {
-        match port_ref {
-            Port::Number(p) => Some(*p).into_iter().collect(),
-            Port::Name(name) => self
-                .port_names
-                .get(name)
-                .into_iter()
-                .flatten()
-                .cloned()
-                .collect(),
-        }
+    /// A named port may refer to an arbitrary number of port numbers. Port
+    /// ranges only select the ports that are already known on the pod (i.e.
+    /// because they have been discovered), so that a wide range does not
+    /// create a server for each of its ports.
+    fn select_ports(&self, ports: &ServerPorts) -> Vec<NonZeroU16> {
+        let named = ports
+            .names
+            .iter()
+            .filter_map(|name| self.port_names.get(name))
+            .flatten();
+        let ranged = self
+            .port_servers
+            .keys()
+            .filter(|port| ports.ranges.iter().any(|r| r.contains(**port)));
+        ports
+            .numbers
+            .iter()
+            .chain(named)
+            .chain(ranged)
+            .copied()
+            .collect::<PortSet>()
+            .into_iter()
+            .collect()
     }
 
     fn port_server_or_default(
@@ -1191,22 +1690,33 @@ impl Pod {
         match self.port_servers.entry(port) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
-                let (watch, _) = watch::channel(PolicyIndex::default_inbound_server(
-                    port,
-                    &self.meta.settings,
-                    self.probes
-                        .get(&port)
-                        .into_iter()
-                        .flatten()
-                        .map(|p| p.as_str()),
-                    config,
-                ));
+                let (watch, _) =
+                    watch::channel(Timestamped::new(PolicyIndex::default_inbound_server(
+                        port,
+                        &self.meta.settings,
+                        self.probes
+                            .get(&port)
+                            .into_iter()
+                            .flatten()
+                            .map(|p| p.as_str()),
+                        config,
+                    )));
                 entry.insert(WorkloadPortServer { name: None, watch })
             }
         }
     }
 }
 
+// === impl WorkloadPortServer ===
+
+impl WorkloadPortServer {
+    /// Returns the name of the server that serves this port, if it is not the
+    /// named server.
+    fn conflict(&self, server: &str) -> Option<&str> {
+        self.name.as_deref().filter(|name| *name != server)
+    }
+}
+
 // === impl ExternalWorkloadIndex ===
 
 impl ExternalWorkloadIndex {
@@ -1227,17 +1737,23 @@ impl ExternalWorkloadIndex {
         &mut self,
         name: String,
         meta: workload::Meta,
+        identity: String,
         port_names: HashMap<String, NonZeroU16>,
     ) -> Result<Option<&mut ExternalWorkload>> {
         let workload = match self.by_name.entry(name.clone()) {
             Entry::Vacant(entry) => entry.insert(ExternalWorkload {
                 meta,
+                identity,
                 port_names,
                 port_servers: PortMap::default(),
             }),
             Entry::Occupied(entry) => {
                 let workload = entry.into_mut();
 
+                // The identity is not used to compute policy, so changing it
+                // does not require the workload to be reindexed.
+                workload.identity = identity;
+
                 if workload.meta == meta && workload.port_names == port_names {
                     tracing::debug!(external_workload = %name, "No changes");
                     return Ok(None);
@@ -1294,21 +1810,12 @@ impl ExternalWorkload {
         );
 
         for (srvname, server) in policy.servers.iter() {
-            if let Selector::ExternalWorkload(selector) = &server.selector {
-                if selector.matches(&self.meta.labels) {
-                    // Each server selects exactly one port on an
-                    // external workload
-                    //
-                    // Note: an external workload has only one set of ports. A
-                    // pod has a union, each container declares its own set.
-                    let port = if let Some(srv_port) = self.selects_port(&server.port_ref) {
-                        srv_port
-                    } else {
-                        // If server references a named port, and our workload
-                        // contains no such port, then skip this server.
-                        continue;
-                    };
-
+            if let Some(ports) =
+                policy.server_ports(server, WorkloadKind::External, &self.meta.labels)
+            {
+                // Named ports that the workload does not declare are
+                // skipped.
+                for port in self.select_ports(&ports) {
                     if let Some(prior) = matched_ports.get(&port) {
                         // If a different server has already matched this
                         tracing::warn!(
@@ -1348,7 +1855,7 @@ impl ExternalWorkload {
         match self.port_servers.entry(port) {
             Entry::Vacant(entry) => {
                 tracing::trace!(port = %port, server = %name, "Creating server");
-                let (watch, _) = watch::channel(server);
+                let (watch, _) = watch::channel(Timestamped::new(server));
                 entry.insert(WorkloadPortServer {
                     name: Some(name.to_string()),
                     watch,
@@ -1377,7 +1884,7 @@ impl ExternalWorkload {
                         ps.name = Some(name.to_string());
                     }
 
-                    *current = server;
+                    current.set(server);
                     true
                 });
             }
@@ -1398,7 +1905,7 @@ impl ExternalWorkload {
         match self.port_servers.entry(port) {
             Entry::Vacant(entry) => {
                 tracing::debug!(%port, server = %config.default_policy, "Creating default server");
-                let (watch, _) = watch::channel(server);
+                let (watch, _) = watch::channel(Timestamped::new(server));
                 entry.insert(WorkloadPortServer { name: None, watch });
             }
 
@@ -1415,24 +1922,37 @@ impl ExternalWorkload {
 
                     tracing::debug!(%port, server = %config.default_policy, "Setting default server");
                     ps.name = None;
-                    *current = server;
+                    current.set(server);
                     true
                 });
             }
         }
     }
 
-    /// Returns an optional port when a server references a known external
-    /// workload port.
+    /// Enumerates the ports selected by a server.
     ///
     /// Unlike a pod, an external workload has only one set of ports. Names
     /// within the set are unique, and as a result, only one port will ever
-    /// match a given name.
-    fn selects_port(&mut self, port_ref: &Port) -> Option<NonZeroU16> {
-        match port_ref {
-            Port::Number(p) => Some(*p),
-            Port::Name(name) => self.port_names.get(name).cloned(),
-        }
+    /// match a given name. As with pods, port ranges only select the ports
+    /// that are already known on the workload.
+    fn select_ports(&self, ports: &ServerPorts) -> Vec<NonZeroU16> {
+        let named = ports
+            .names
+            .iter()
+            .filter_map(|name| self.port_names.get(name));
+        let ranged = self
+            .port_servers
+            .keys()
+            .filter(|port| ports.ranges.iter().any(|r| r.contains(**port)));
+        ports
+            .numbers
+            .iter()
+            .chain(named)
+            .chain(ranged)
+            .copied()
+            .collect::<PortSet>()
+            .into_iter()
+            .collect()
     }
 
     fn port_server_or_default(
@@ -1443,12 +1963,13 @@ impl ExternalWorkload {
         match self.port_servers.entry(port) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
-                let (watch, _) = watch::channel(PolicyIndex::default_inbound_server(
-                    port,
-                    &self.meta.settings,
-                    Vec::new().into_iter(),
-                    config,
-                ));
+                let (watch, _) =
+                    watch::channel(Timestamped::new(PolicyIndex::default_inbound_server(
+                        port,
+                        &self.meta.settings,
+                        Vec::new().into_iter(),
+                        config,
+                    )));
                 entry.insert(WorkloadPortServer { name: None, watch })
             }
         }
@@ -1464,6 +1985,87 @@ impl PolicyIndex {
             && self.server_authorizations.is_empty()
             && self.authorization_policies.is_empty()
             && self.http_routes.is_empty()
+            && self.services.is_empty()
+    }
+
+    /// Returns true if any server selects ports by a port range.
+    fn has_port_ranges(&self) -> bool {
+        self.servers
+            .values()
+            .any(|srv| !srv.ports.ranges.is_empty())
+    }
+
+    /// Returns the workload ports that a server selects on a workload of the
+    /// given kind, or `None` if the server does not select the workload.
+    ///
+    /// Servers that reference a Service select the workloads selected by the
+    /// Service, on the ports targeted by the Service's ports.
+    fn server_ports<'s>(
+        &'s self,
+        server: &'s server::Server,
+        kind: WorkloadKind,
+        labels: &k8s::Labels,
+    ) -> Option<Cow<'s, ServerPorts>> {
+        match (&server.selector, kind) {
+            (Selector::Pod(selector), WorkloadKind::Pod)
+            | (Selector::ExternalWorkload(selector), WorkloadKind::External) => selector
+                .matches(labels)
+                .then_some(Cow::Borrowed(&server.ports)),
+            (Selector::Service(svc), _) => {
+                let svc = self.services.get(&svc.name)?;
+                svc.selects(labels)
+                    .then(|| Cow::Owned(svc.target_ports(&server.ports)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the names of the servers that select a workload of the given
+    /// kind with any of the given labels.
+    fn servers_selecting<'l>(
+        &self,
+        kind: WorkloadKind,
+        labels: impl IntoIterator<Item = &'l k8s::Labels>,
+    ) -> Vec<String> {
+        let labels = labels.into_iter().collect::<Vec<_>>();
+        self.servers
+            .iter()
+            .filter(|(_, srv)| {
+                labels
+                    .iter()
+                    .any(|labels| self.server_ports(srv, kind, labels).is_some())
+            })
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Updates a Service, returning true if any servers select workloads
+    /// through it and must be reindexed, or if any authorization policies
+    /// target it and their status may have changed.
+    fn update_service(&mut self, name: String, svc: Option<service::Service>) -> bool {
+        let changed = match svc {
+            Some(svc) => match self.services.entry(name.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(svc);
+                    true
+                }
+                Entry::Occupied(entry) => {
+                    let prior = entry.into_mut();
+                    if *prior == svc {
+                        false
+                    } else {
+                        *prior = svc;
+                        true
+                    }
+                }
+            },
+            None => self.services.remove(&name).is_some(),
+        };
+        changed
+            && (self.servers.values().any(|srv| srv.selects_service(&name))
+                || self.authorization_policies.values().any(|ap| {
+                    matches!(&ap.target, authorization_policy::Target::Service(svc) if *svc == name)
+                }))
     }
 
     fn update_server(&mut self, name: String, server: server::Server) -> bool {
@@ -1607,6 +2209,18 @@ impl PolicyIndex {
                         continue;
                     }
                 }
+                authorization_policy::Target::Service(svc) => {
+                    if !server.selects_service(svc) {
+                        tracing::trace!(
+                            ns = %self.namespace,
+                            authorizationpolicy = %name,
+                            server = %server_name,
+                            service = %svc,
+                            "AuthorizationPolicy does not target server's Service",
+                        );
+                        continue;
+                    }
+                }
                 authorization_policy::Target::Namespace => {}
                 authorization_policy::Target::HttpRoute(_) => {
                     // Policies which target HttpRoutes will be attached to
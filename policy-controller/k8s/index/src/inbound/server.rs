@@ -1,27 +1,77 @@
-use crate::ClusterInfo;
+use crate::{ports::PortSet, ClusterInfo};
 use linkerd_policy_controller_core::inbound::ProxyProtocol;
 use linkerd_policy_controller_k8s_api::{
-    self as k8s, policy::server::Port, policy::server::Selector,
+    self as k8s,
+    policy::server::{Port, PortRange, Selector},
 };
+use std::num::NonZeroU16;
 
 /// The parts of a `Server` resource that can change.
 #[derive(Debug, PartialEq)]
 pub(crate) struct Server {
     pub labels: k8s::Labels,
     pub selector: Selector,
-    pub port_ref: Port,
+    pub ports: ServerPorts,
     pub protocol: ProxyProtocol,
 }
 
+/// The workload ports selected by a `Server`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct ServerPorts {
+    pub numbers: PortSet,
+
+    /// Ranges of port numbers. Ranges are not expanded, since they may span
+    /// many ports; instead, they are matched against the ports known on each
+    /// selected workload.
+    pub ranges: Vec<PortRange>,
+
+    /// Named ports, which are resolved against each selected workload.
+    pub names: Vec<String>,
+}
+
 impl Server {
     pub(crate) fn from_resource(srv: k8s::policy::Server, cluster: &ClusterInfo) -> Self {
         Self {
             labels: srv.metadata.labels.into(),
+            ports: ServerPorts::from_spec(&srv.spec),
             selector: srv.spec.selector,
-            port_ref: srv.spec.port,
             protocol: proxy_protocol(srv.spec.proxy_protocol, cluster),
         }
     }
+
+    /// Returns true if the server selects workloads through the named
+    /// Service.
+    pub(crate) fn selects_service(&self, name: &str) -> bool {
+        matches!(&self.selector, Selector::Service(svc) if svc.name == name)
+    }
+}
+
+// === impl ServerPorts ===
+
+impl ServerPorts {
+    fn from_spec(spec: &k8s::policy::ServerSpec) -> Self {
+        let mut ports = Self::default();
+        for port in spec.port_refs() {
+            match port {
+                Port::Number(port) => {
+                    ports.numbers.insert(*port);
+                }
+                Port::Range(range) => ports.ranges.push(*range),
+                Port::Name(name) => {
+                    if !ports.names.contains(name) {
+                        ports.names.push(name.clone());
+                    }
+                }
+            }
+        }
+        ports
+    }
+
+    /// Returns true if the port number is selected, either explicitly or by
+    /// a range.
+    pub(crate) fn contains(&self, port: NonZeroU16) -> bool {
+        self.numbers.contains(&port) || self.ranges.iter().any(|r| r.contains(port))
+    }
 }
 
 fn proxy_protocol(
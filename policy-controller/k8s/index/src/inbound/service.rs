@@ -0,0 +1,91 @@
+use super::server::ServerPorts;
+use linkerd_policy_controller_k8s_api::{self as k8s, labels, policy::server::Port, IntOrString};
+use std::num::NonZeroU16;
+
+/// The parts of a `Service` resource that determine the workloads and ports
+/// selected by servers that reference it.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Service {
+    /// The Service's pod selector. Services without a selector do not select
+    /// any workloads.
+    pub selector: Option<labels::Selector>,
+    pub ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct ServicePort {
+    pub port: NonZeroU16,
+    pub name: Option<String>,
+    pub target: Port,
+}
+
+// === impl Service ===
+
+impl Service {
+    pub(crate) fn from_resource(svc: &k8s::Service) -> Self {
+        let spec = svc.spec.as_ref();
+        let selector = spec
+            .and_then(|spec| spec.selector.as_ref())
+            .filter(|selector| !selector.is_empty())
+            .map(|selector| selector.clone().into_iter().collect());
+        let ports = spec
+            .and_then(|spec| spec.ports.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|p| p.protocol.as_deref().map_or(true, |p| p == "TCP"))
+            .filter_map(|p| {
+                let port = NonZeroU16::try_from(u16::try_from(p.port).ok()?).ok()?;
+                let target = match &p.target_port {
+                    None => Port::Number(port),
+                    Some(IntOrString::Int(n)) => {
+                        Port::Number(NonZeroU16::try_from(u16::try_from(*n).ok()?).ok()?)
+                    }
+                    Some(IntOrString::String(name)) => match name.parse() {
+                        Ok(n) => Port::Number(n),
+                        Err(_) => Port::Name(name.clone()),
+                    },
+                };
+                Some(ServicePort {
+                    port,
+                    name: p.name.clone(),
+                    target,
+                })
+            })
+            .collect();
+        Self { selector, ports }
+    }
+
+    pub(crate) fn selects(&self, labels: &k8s::Labels) -> bool {
+        self.selector
+            .as_ref()
+            .map_or(false, |selector| selector.matches(labels))
+    }
+
+    /// Maps the Service ports selected by a server to the workload ports that
+    /// they target.
+    pub(crate) fn target_ports(&self, ports: &ServerPorts) -> ServerPorts {
+        let mut targets = ServerPorts::default();
+        for sp in self.ports.iter() {
+            let selected = ports.contains(sp.port)
+                || sp
+                    .name
+                    .as_ref()
+                    .map_or(false, |name| ports.names.contains(name));
+            if !selected {
+                continue;
+            }
+            match &sp.target {
+                Port::Number(port) => {
+                    targets.numbers.insert(*port);
+                }
+                Port::Range(range) => targets.ranges.push(*range),
+                Port::Name(name) => {
+                    if !targets.names.contains(name) {
+                        targets.names.push(name.clone());
+                    }
+                }
+            }
+        }
+        targets
+    }
+}
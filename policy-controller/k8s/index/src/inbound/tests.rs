@@ -2,6 +2,9 @@ mod annotation;
 mod authorization_policy;
 mod http_routes;
 mod server_authorization;
+mod server_ports;
+mod server_summary;
+mod service;
 
 use crate::{
     defaults::DefaultPolicy,
@@ -36,6 +39,28 @@ fn pod_must_exist_for_lookup() {
         .expect_err("pod-0.ns-0 must not exist");
 }
 
+#[test]
+fn pod_identity() {
+    let test = TestConfig::default();
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.spec.as_mut().unwrap().service_account_name = Some("sa-0".to_string());
+    test.index.write().apply(pod);
+    test.index
+        .write()
+        .apply(mk_pod("ns-0", "pod-1", Some(("container-0", None))));
+
+    let index = test.index.read();
+    assert_eq!(
+        index.pod_identity("ns-0", "pod-0"),
+        Some("sa-0.ns-0.serviceaccount.identity.linkerd.cluster.example.com")
+    );
+    assert_eq!(
+        index.pod_identity("ns-0", "pod-1"),
+        Some("default.ns-0.serviceaccount.identity.linkerd.cluster.example.com")
+    );
+    assert_eq!(index.pod_identity("ns-0", "pod-2"), None);
+}
+
 struct TestConfig {
     index: SharedIndex,
     detect_timeout: time::Duration,
@@ -117,10 +142,12 @@ fn mk_server(
             ..Default::default()
         },
         spec: k8s::policy::ServerSpec {
-            port,
+            port: Some(port),
+            ports: None,
             selector: k8s::policy::server::Selector::Pod(pod_labels.into_iter().collect()),
             proxy_protocol,
         },
+        status: None,
     }
 }
 
@@ -209,7 +236,7 @@ impl TestConfig {
             default_opaque_ports: Default::default(),
             probe_networks,
         };
-        let index = Index::shared(cluster.clone());
+        let index = Index::shared(cluster.clone(), Default::default());
         Self {
             index,
             cluster,
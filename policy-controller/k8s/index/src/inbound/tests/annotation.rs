@@ -128,3 +128,23 @@ fn authenticated_annotated() {
         assert_eq!(*rx.borrow(), config);
     }
 }
+
+/// Tests that an invalid workload annotation is surfaced as a warning on the
+/// pod.
+#[test]
+fn invalid_annotation_warns() {
+    let test = TestConfig::default();
+    let (events, mut warnings) = crate::Events::channel(10);
+    let index = Index::shared(test.cluster.clone(), events);
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.annotations_mut()
+        .insert("config.linkerd.io/opaque-ports".into(), "8080,bogus".into());
+    index.write().apply(pod);
+
+    let warning = warnings.try_recv().expect("warning must be emitted");
+    assert_eq!(warning.object.kind.as_deref(), Some("Pod"));
+    assert_eq!(warning.object.name.as_deref(), Some("pod-0"));
+    assert_eq!(warning.reason, crate::events::reasons::INVALID_ANNOTATION);
+    assert!(warnings.try_recv().is_err(), "only one warning expected");
+}
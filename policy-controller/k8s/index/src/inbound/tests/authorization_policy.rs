@@ -315,6 +315,7 @@ fn authorization_policy_prevents_index_deletion() {
                 },
             ],
         },
+        status: None,
     };
     test.index.write().apply(authz_policy.clone());
     let route = mk_http_route("ns-0", "route-foo", "srv-8080");
@@ -417,6 +418,7 @@ fn mk_authorization_policy(
             },
             required_authentication_refs: authns.into_iter().collect(),
         },
+        status: None,
     }
 }
 
@@ -446,6 +448,7 @@ fn mk_meshtls_authentication(
                 Some(identity_refs)
             },
         },
+        status: None,
     }
 }
 
@@ -463,6 +466,7 @@ fn mk_network_authentication(
         spec: k8s::policy::NetworkAuthenticationSpec {
             networks: networks.into_iter().collect(),
         },
+        status: None,
     }
 }
 
@@ -296,5 +296,6 @@ fn mk_authorization_policy(
             },
             required_authentication_refs: authns.into_iter().collect(),
         },
+        status: None,
     }
 }
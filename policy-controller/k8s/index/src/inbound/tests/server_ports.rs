@@ -0,0 +1,125 @@
+use super::*;
+
+/// Tests that a server selects each of its listed ports, expanding port
+/// ranges and resolving port names.
+#[test]
+fn links_server_port_list_and_range() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod(
+        "ns-0",
+        "pod-0",
+        Some((
+            "container-0",
+            Some(ContainerPort {
+                name: Some("admin-http".to_string()),
+                container_port: 8080,
+                ..ContainerPort::default()
+            }),
+        )),
+    );
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut srv = mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Name("admin-http".to_string()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    );
+    srv.spec.port = None;
+    srv.spec.ports = Some(vec![
+        Port::Name("admin-http".to_string()),
+        Port::Range("9000-9002".parse().unwrap()),
+    ]);
+    test.index.write().apply(srv);
+
+    for port in [8080, 9000, 9001, 9002] {
+        let rx = test
+            .index
+            .write()
+            .pod_server_rx("ns-0", "pod-0", port.try_into().unwrap())
+            .expect("pod-0.ns-0 should exist");
+        assert_eq!(
+            rx.borrow().reference,
+            ServerRef::Server("srv-0".to_string()),
+            "port {port}",
+        );
+        assert_eq!(rx.borrow().protocol, ProxyProtocol::Http1, "port {port}");
+    }
+
+    let rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 9003.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx.borrow(), test.default_server());
+}
+
+/// Tests that port ranges select ports as they are discovered, and that
+/// discovered ports are updated as servers change.
+#[test]
+fn resolves_port_ranges_on_discovery() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    // A port is discovered before any server selects it.
+    let rx_8080 = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx_8080.borrow(), test.default_server());
+
+    let mut srv = mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Number(1.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    );
+    srv.spec.port = None;
+    srv.spec.ports = Some(vec![Port::Range("1-65535".parse().unwrap())]);
+    test.index.write().apply(srv);
+
+    // The known port is selected by the range...
+    assert_eq!(
+        rx_8080.borrow().reference,
+        ServerRef::Server("srv-0".to_string())
+    );
+
+    // ...as are ports that are discovered later.
+    let rx_9090 = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 9090.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(
+        rx_9090.borrow().reference,
+        ServerRef::Server("srv-0".to_string())
+    );
+
+    // When the range no longer includes the ports, they use the default
+    // policy.
+    let mut srv = mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Number(1.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    );
+    srv.spec.port = None;
+    srv.spec.ports = Some(vec![Port::Range("1-1024".parse().unwrap())]);
+    test.index.write().apply(srv);
+    assert_eq!(*rx_8080.borrow(), test.default_server());
+    assert_eq!(*rx_9090.borrow(), test.default_server());
+}
@@ -0,0 +1,142 @@
+use super::*;
+use crate::inbound::ServerSummary;
+use ahash::AHashSet as HashSet;
+
+/// Tests that a server's summary counts the pods it selects and the pods on
+/// which its named port does not resolve.
+#[test]
+fn summarizes_selected_pods() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod(
+        "ns-0",
+        "pod-0",
+        Some((
+            "container-0",
+            Some(ContainerPort {
+                name: Some("admin-http".to_string()),
+                container_port: 8080,
+                ..ContainerPort::default()
+            }),
+        )),
+    );
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut pod = mk_pod("ns-0", "pod-1", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    assert_eq!(test.index.read().server_summary("ns-0", "srv-0"), None);
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Name("admin-http".to_string()),
+        None,
+        Some(("app", "app-0")),
+        None,
+    ));
+    assert_eq!(
+        test.index.read().server_summary("ns-0", "srv-0"),
+        Some(ServerSummary {
+            pods: 2,
+            unresolved_port: 1,
+            ..Default::default()
+        }),
+    );
+}
+
+/// Tests that servers selecting the same pod port report each other as
+/// conflicts.
+#[test]
+fn summarizes_conflicting_servers() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    for name in ["srv-0", "srv-1"] {
+        test.index.write().apply(mk_server(
+            "ns-0",
+            name,
+            Port::Number(8080.try_into().unwrap()),
+            None,
+            Some(("app", "app-0")),
+            None,
+        ));
+    }
+
+    // Exactly one of the servers serves the port, so the other reports it as
+    // a conflict.
+    let srv0 = test.index.read().server_summary("ns-0", "srv-0").unwrap();
+    let srv1 = test.index.read().server_summary("ns-0", "srv-1").unwrap();
+    assert_eq!(srv0.pods, 1);
+    assert_eq!(srv1.pods, 1);
+    let conflicts = srv0
+        .conflicts
+        .into_iter()
+        .chain(srv1.conflicts)
+        .collect::<Vec<_>>();
+    assert_eq!(conflicts.len(), 1);
+}
+
+/// Tests that workload changes are only reported for the servers that select
+/// the workload, before or after the change.
+#[test]
+fn reports_servers_selecting_changed_pods() {
+    let test = TestConfig::default();
+
+    for (name, app) in [("srv-0", "app-0"), ("srv-1", "app-1")] {
+        test.index.write().apply(mk_server(
+            "ns-0",
+            name,
+            Port::Number(8080.try_into().unwrap()),
+            None,
+            Some(("app", app)),
+            None,
+        ));
+    }
+    test.index.write().take_changed_namespaces();
+
+    let changed = |names: &[&str]| {
+        names
+            .iter()
+            .map(|name| ("ns-0".to_string(), name.to_string()))
+            .collect::<HashSet<_>>()
+    };
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod.clone());
+    assert_eq!(
+        test.index.write().take_changed_servers(),
+        changed(&["srv-0"])
+    );
+
+    pod.labels_mut()
+        .insert("app".to_string(), "app-1".to_string());
+    test.index.write().apply(pod);
+    assert_eq!(
+        test.index.write().take_changed_servers(),
+        changed(&["srv-0", "srv-1"])
+    );
+
+    <Index as IndexNamespacedResource<k8s::Pod>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "pod-0".to_string(),
+    );
+    assert_eq!(
+        test.index.write().take_changed_servers(),
+        changed(&["srv-1"])
+    );
+
+    // Workload changes do not affect the rest of the namespace.
+    assert!(test.index.write().take_changed_namespaces().is_empty());
+}
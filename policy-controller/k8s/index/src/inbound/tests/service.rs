@@ -0,0 +1,270 @@
+use super::*;
+use k8s::api::core::v1::{ServicePort, ServiceSpec};
+use k8s::IntOrString;
+
+/// Tests that a server that references a Service selects the Service's
+/// workloads on its target ports, and that it is updated as the Service
+/// changes.
+#[test]
+fn links_server_through_service() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod(
+        "ns-0",
+        "pod-0",
+        Some((
+            "container-0",
+            Some(ContainerPort {
+                name: Some("admin-http".to_string()),
+                container_port: 8080,
+                ..ContainerPort::default()
+            }),
+        )),
+    );
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut srv = mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Name("http".to_string()),
+        None,
+        None,
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    );
+    srv.spec.selector = k8s::policy::server::Selector::Service(k8s::policy::server::ServiceRef {
+        name: "svc-0".to_string(),
+    });
+    test.index.write().apply(srv);
+
+    // The server does not apply until the Service is known.
+    let mut rx_8080 = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx_8080.borrow_and_update(), test.default_server());
+
+    test.index.write().apply(mk_service(
+        "svc-0",
+        Some(("app", "app-0")),
+        IntOrString::String("admin-http".to_string()),
+    ));
+    assert!(rx_8080.has_changed().unwrap());
+    assert_eq!(
+        rx_8080.borrow_and_update().reference,
+        ServerRef::Server("srv-0".to_string()),
+    );
+
+    // Changing the Service's target port moves the server to the new port.
+    let mut rx_9090 = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 9090.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx_9090.borrow_and_update(), test.default_server());
+    test.index.write().apply(mk_service(
+        "svc-0",
+        Some(("app", "app-0")),
+        IntOrString::Int(9090),
+    ));
+    assert_eq!(*rx_8080.borrow_and_update(), test.default_server());
+    assert_eq!(
+        rx_9090.borrow_and_update().reference,
+        ServerRef::Server("srv-0".to_string()),
+    );
+
+    // Changing the Service's selector deselects the pod.
+    test.index.write().apply(mk_service(
+        "svc-0",
+        Some(("app", "app-1")),
+        IntOrString::Int(9090),
+    ));
+    assert_eq!(*rx_9090.borrow_and_update(), test.default_server());
+
+    test.index.write().apply(mk_service(
+        "svc-0",
+        Some(("app", "app-0")),
+        IntOrString::Int(9090),
+    ));
+    assert_eq!(
+        rx_9090.borrow_and_update().reference,
+        ServerRef::Server("srv-0".to_string()),
+    );
+    <Index as IndexNamespacedResource<k8s::Service>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "svc-0".to_string(),
+    );
+    assert_eq!(*rx_9090.borrow_and_update(), test.default_server());
+}
+
+/// Tests that an AuthorizationPolicy that targets a Service applies to the
+/// servers that reference the Service.
+#[test]
+fn links_authorization_policy_with_service_target() {
+    let test = TestConfig::default();
+
+    let mut srv = mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Number(80.try_into().unwrap()),
+        None,
+        None,
+        None,
+    );
+    srv.spec.selector = k8s::policy::server::Selector::Service(k8s::policy::server::ServiceRef {
+        name: "svc-0".to_string(),
+    });
+    test.index.write().apply(srv);
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-1",
+        Port::Number(80.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        None,
+    ));
+
+    test.index.write().apply(k8s::policy::AuthorizationPolicy {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("authz-0".to_string()),
+            ..Default::default()
+        },
+        spec: k8s::policy::AuthorizationPolicySpec {
+            target_ref: LocalTargetRef {
+                group: None,
+                kind: "Service".to_string(),
+                name: "svc-0".to_string(),
+            },
+            required_authentication_refs: vec![NamespacedTargetRef {
+                group: None,
+                kind: "ServiceAccount".to_string(),
+                name: "sa-0".to_string(),
+                namespace: None,
+            }],
+        },
+        status: None,
+    });
+
+    let index = test.index.read();
+    assert_eq!(
+        index
+            .authorization_policy_summary("ns-0", "authz-0")
+            .unwrap()
+            .missing_target,
+        Some("Service svc-0".to_string()),
+    );
+    assert_eq!(
+        index
+            .server_summary("ns-0", "srv-0")
+            .unwrap()
+            .authorizations,
+        1
+    );
+    assert_eq!(
+        index
+            .server_summary("ns-0", "srv-1")
+            .unwrap()
+            .authorizations,
+        0
+    );
+}
+
+/// Tests that changes to a Service are reported for the AuthorizationPolicies
+/// that target it, even if no server references the Service.
+#[test]
+fn service_changes_update_authorization_policies() {
+    let test = TestConfig::default();
+
+    test.index.write().apply(k8s::policy::AuthorizationPolicy {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("authz-0".to_string()),
+            ..Default::default()
+        },
+        spec: k8s::policy::AuthorizationPolicySpec {
+            target_ref: LocalTargetRef {
+                group: None,
+                kind: "Service".to_string(),
+                name: "svc-0".to_string(),
+            },
+            required_authentication_refs: vec![NamespacedTargetRef {
+                group: None,
+                kind: "ServiceAccount".to_string(),
+                name: "sa-0".to_string(),
+                namespace: None,
+            }],
+        },
+        status: None,
+    });
+    test.index.write().take_changed_namespaces();
+
+    // Services that no policy targets do not change the namespace.
+    test.index.write().apply(mk_service(
+        "svc-1",
+        Some(("app", "app-0")),
+        IntOrString::Int(8080),
+    ));
+    assert!(test.index.write().take_changed_namespaces().is_empty());
+
+    test.index.write().apply(mk_service(
+        "svc-0",
+        Some(("app", "app-0")),
+        IntOrString::Int(8080),
+    ));
+    assert_eq!(
+        test.index.write().take_changed_namespaces(),
+        Some("ns-0".to_string()).into_iter().collect()
+    );
+    assert_eq!(
+        test.index
+            .read()
+            .authorization_policy_summary("ns-0", "authz-0")
+            .unwrap()
+            .missing_target,
+        None,
+    );
+
+    <Index as IndexNamespacedResource<k8s::Service>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "svc-0".to_string(),
+    );
+    assert_eq!(
+        test.index.write().take_changed_namespaces(),
+        Some("ns-0".to_string()).into_iter().collect()
+    );
+}
+
+fn mk_service(
+    name: impl ToString,
+    selector: impl IntoIterator<Item = (&'static str, &'static str)>,
+    target_port: IntOrString,
+) -> k8s::Service {
+    k8s::Service {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: Some(ServiceSpec {
+            selector: Some(
+                selector
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            ports: Some(vec![ServicePort {
+                name: Some("http".to_string()),
+                port: 80,
+                target_port: Some(target_port),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
@@ -1,10 +1,15 @@
 use crate::defaults::DefaultPolicy;
+use crate::events::reasons;
 use crate::ports::{parse_portset, PortMap, PortSet};
 use ahash::AHashMap as HashMap;
 use anyhow::Result;
 use linkerd_policy_controller_k8s_api as k8s;
 use std::{collections::BTreeSet, num::NonZeroU16};
 
+const OPAQUE_PORTS_ANNOTATION: &str = "config.linkerd.io/opaque-ports";
+const REQUIRE_ID_PORTS_ANNOTATION: &str = "config.linkerd.io/proxy-require-identity-inbound-ports";
+const DEFAULT_POLICY_ANNOTATION: &str = "config.linkerd.io/default-inbound-policy";
+
 /// Holds workload metadata/config that can change.
 #[derive(Debug, PartialEq)]
 pub(crate) struct Meta {
@@ -110,6 +115,56 @@ fn container_http_probe_paths(
         })
 }
 
+/// Describes each of a workload's annotations that is ignored because it is
+/// invalid, as a `(reason, note)` pair.
+pub(crate) fn metadata_warnings(meta: &k8s::ObjectMeta) -> Vec<(&'static str, String)> {
+    let Some(anns) = meta.annotations.as_ref() else {
+        return vec![];
+    };
+
+    let mut warnings = vec![];
+    for annotation in [OPAQUE_PORTS_ANNOTATION, REQUIRE_ID_PORTS_ANNOTATION] {
+        if let Some(Err(error)) = anns.get(annotation).map(|spec| parse_portset(spec)) {
+            warnings.push((
+                reasons::INVALID_ANNOTATION,
+                format!("ignoring invalid {annotation} annotation: {error}"),
+            ));
+        }
+    }
+    if let Err(error) = default_policy(anns) {
+        warnings.push((
+            reasons::INVALID_ANNOTATION,
+            format!("ignoring invalid {DEFAULT_POLICY_ANNOTATION} annotation: {error}"),
+        ));
+    }
+    warnings
+}
+
+/// Describes each of a pod's HTTP probes that is not authorized by default
+/// because its path is invalid, as a `(reason, note)` pair.
+pub(crate) fn pod_probe_warnings(pod: &k8s::PodSpec) -> Vec<(&'static str, String)> {
+    pod.containers
+        .iter()
+        .chain(pod.init_containers.iter().flatten())
+        .flat_map(|c| {
+            (c.liveness_probe.iter())
+                .chain(c.readiness_probe.iter())
+                .chain(c.startup_probe.iter())
+                .filter_map(move |p| {
+                    let path = p.http_get.as_ref()?.path.as_deref()?;
+                    let error = http::Uri::try_from(path).err()?;
+                    Some((
+                        reasons::INVALID_PROBE_PATH,
+                        format!(
+                            "ignoring probe with invalid path {path:?} in container {}: {error}",
+                            c.name
+                        ),
+                    ))
+                })
+        })
+        .collect()
+}
+
 fn named_tcp_port(port: &k8s::ContainerPort) -> Option<(NonZeroU16, &str)> {
     if let Some(ref proto) = port.protocol {
         if !proto.eq_ignore_ascii_case("TCP") {
@@ -165,13 +220,9 @@ impl Settings {
             None
         });
 
-        let opaque_ports =
-            ports_annotation(anns, "config.linkerd.io/opaque-ports").unwrap_or_default();
-        let require_id_ports = ports_annotation(
-            anns,
-            "config.linkerd.io/proxy-require-identity-inbound-ports",
-        )
-        .unwrap_or_default();
+        let opaque_ports = ports_annotation(anns, OPAQUE_PORTS_ANNOTATION).unwrap_or_default();
+        let require_id_ports =
+            ports_annotation(anns, REQUIRE_ID_PORTS_ANNOTATION).unwrap_or_default();
 
         Self {
             default_policy,
@@ -185,7 +236,7 @@ impl Settings {
 fn default_policy(
     ann: &std::collections::BTreeMap<String, String>,
 ) -> Result<Option<DefaultPolicy>> {
-    if let Some(v) = ann.get("config.linkerd.io/default-inbound-policy") {
+    if let Some(v) = ann.get(DEFAULT_POLICY_ANNOTATION) {
         let mode = v.parse()?;
         return Ok(Some(mode));
     }
@@ -25,6 +25,7 @@
 
 mod cluster_info;
 mod defaults;
+pub mod events;
 pub mod inbound;
 pub mod outbound;
 pub mod ports;
@@ -32,4 +33,5 @@ pub mod routes;
 
 pub use cluster_info::ClusterInfo;
 pub use defaults::DefaultPolicy;
+pub use events::Events;
 pub use inbound::authorization_policy;
@@ -1,5 +1,6 @@
 use crate::{
-    ports::{ports_annotation, PortSet},
+    events::{reasons, Events},
+    ports::{parse_portset, ports_annotation, PortSet},
     routes::{self, ExplicitGKN, RouteResource},
     ClusterInfo,
 };
@@ -7,10 +8,11 @@ use ahash::AHashMap as HashMap;
 use anyhow::{bail, ensure, Result};
 use linkerd_policy_controller_core::{
     outbound::{
-        Backend, Backoff, FailureAccrual, Filter, OutboundPolicy, OutboundRoute,
-        OutboundRouteCollection, OutboundRouteRule, TypedOutboundRoute, WeightedService,
+        Backend, Backoff, FailureAccrual, OutboundPolicy, OutboundRoute, OutboundRouteCollection,
+        OutboundRouteRule, ServicePortRef, TypedOutboundRoute, WeightedService,
     },
     routes::{GroupKindNamespaceName, HttpRouteMatch},
+    Timestamped,
 };
 use linkerd_policy_controller_k8s_api::{
     gateway::{self as k8s_gateway_api, BackendObjectReference, HttpBackendRef, ParentReference},
@@ -25,12 +27,15 @@ pub struct Index {
     namespaces: NamespaceIndex,
     services_by_ip: HashMap<IpAddr, ServiceRef>,
     service_info: HashMap<ServiceRef, ServiceInfo>,
+    events: Events,
 }
 
 pub mod metrics;
 
 pub type SharedIndex = Arc<RwLock<Index>>;
 
+const OPAQUE_PORTS_ANNOTATION: &str = "config.linkerd.io/opaque-ports";
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ServiceRef {
     pub name: String,
@@ -60,6 +65,11 @@ struct Namespace {
 struct ServiceInfo {
     opaque_ports: PortSet,
     accrual: Option<FailureAccrual>,
+    named_ports: HashMap<String, NonZeroU16>,
+
+    /// Set for `ExternalName` Services, which are resolved by DNS outside of
+    /// the cluster rather than to endpoints.
+    external_name: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -81,10 +91,11 @@ struct ServiceRoutes {
 
 #[derive(Debug)]
 struct RoutesWatch {
+    authority: String,
     opaque: bool,
     accrual: Option<FailureAccrual>,
     routes: OutboundRouteCollection,
-    watch: watch::Sender<OutboundPolicy>,
+    watch: watch::Sender<Timestamped<OutboundPolicy>>,
 }
 
 impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::HttpRoute> for Index {
@@ -124,11 +135,28 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
         let ns = service.namespace().expect("Service must have a namespace");
         tracing::debug!(name, ns, "indexing service");
         let accrual = parse_accrual_config(service.annotations())
-            .map_err(|error| tracing::error!(%error, service=name, namespace=ns, "failed to parse accrual config"))
+            .map_err(|error| {
+                tracing::error!(%error, service=name, namespace=ns, "failed to parse accrual config");
+                self.events.warn(
+                    &service,
+                    reasons::INVALID_ANNOTATION,
+                    format!("ignoring invalid failure accrual configuration: {error}"),
+                );
+            })
             .unwrap_or_default();
-        let opaque_ports =
-            ports_annotation(service.annotations(), "config.linkerd.io/opaque-ports")
-                .unwrap_or_else(|| self.namespaces.cluster_info.default_opaque_ports.clone());
+        if let Some(Err(error)) = service
+            .annotations()
+            .get(OPAQUE_PORTS_ANNOTATION)
+            .map(|spec| parse_portset(spec))
+        {
+            self.events.warn(
+                &service,
+                reasons::INVALID_ANNOTATION,
+                format!("ignoring invalid {OPAQUE_PORTS_ANNOTATION} annotation: {error}"),
+            );
+        }
+        let opaque_ports = ports_annotation(service.annotations(), OPAQUE_PORTS_ANNOTATION)
+            .unwrap_or_else(|| self.namespaces.cluster_info.default_opaque_ports.clone());
 
         if let Some(cluster_ips) = service
             .spec
@@ -154,9 +182,30 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
             }
         }
 
+        let named_ports = service
+            .spec
+            .iter()
+            .flat_map(|spec| spec.ports.iter().flatten())
+            .filter_map(|sp| {
+                let name = sp.name.clone()?;
+                let port = u16::try_from(sp.port)
+                    .ok()
+                    .and_then(|p| NonZeroU16::try_from(p).ok())?;
+                Some((name, port))
+            })
+            .collect();
+
+        let external_name = service
+            .spec
+            .as_ref()
+            .filter(|spec| spec.type_.as_deref() == Some("ExternalName"))
+            .and_then(|spec| spec.external_name.clone());
+
         let service_info = ServiceInfo {
             opaque_ports,
             accrual,
+            named_ports,
+            external_name,
         };
 
         self.namespaces
@@ -167,7 +216,11 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
                 service_port_routes: Default::default(),
                 namespace: Arc::new(ns),
             })
-            .update_service(service.name_unchecked(), &service_info);
+            .update_service(
+                service.name_unchecked(),
+                &service_info,
+                &self.namespaces.cluster_info,
+            );
 
         self.service_info.insert(
             ServiceRef {
@@ -185,13 +238,20 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
         let service_ref = ServiceRef { name, namespace };
         self.service_info.remove(&service_ref);
         self.services_by_ip.retain(|_, v| *v != service_ref);
+        if let Some(ns) = self.namespaces.by_ns.get_mut(&service_ref.namespace) {
+            ns.update_service(
+                service_ref.name,
+                &ServiceInfo::default(),
+                &self.namespaces.cluster_info,
+            );
+        }
 
         self.reindex_services()
     }
 }
 
 impl Index {
-    pub fn shared(cluster_info: Arc<ClusterInfo>) -> SharedIndex {
+    pub fn shared(cluster_info: Arc<ClusterInfo>, events: Events) -> SharedIndex {
         Arc::new(RwLock::new(Self {
             namespaces: NamespaceIndex {
                 by_ns: HashMap::default(),
@@ -199,6 +259,7 @@ impl Index {
             },
             services_by_ip: HashMap::default(),
             service_info: HashMap::default(),
+            events,
         }))
     }
 
@@ -208,7 +269,7 @@ impl Index {
         service_namespace: String,
         service_port: NonZeroU16,
         source_namespace: String,
-    ) -> Result<watch::Receiver<OutboundPolicy>> {
+    ) -> Result<watch::Receiver<Timestamped<OutboundPolicy>>> {
         let ns = self
             .namespaces
             .by_ns
@@ -238,6 +299,24 @@ impl Index {
         self.services_by_ip.get(&addr).cloned()
     }
 
+    /// Resolves a port on a known Service, looking up named ports in the
+    /// Service's spec.
+    pub fn lookup_service_port(
+        &self,
+        namespace: &str,
+        name: &str,
+        port: &ServicePortRef,
+    ) -> Option<NonZeroU16> {
+        let service = self.service_info.get(&ServiceRef {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        })?;
+        match port {
+            ServicePortRef::Number(port) => Some(*port),
+            ServicePortRef::Name(port) => service.named_ports.get(port).copied(),
+        }
+    }
+
     fn apply(&mut self, route: RouteResource) {
         tracing::debug!(name = route.name(), "indexing route");
 
@@ -274,7 +353,7 @@ impl Index {
 
     fn reindex_services(&mut self) {
         for ns in self.namespaces.by_ns.values_mut() {
-            ns.reindex_services(&self.service_info);
+            ns.reindex_services(&self.namespaces.cluster_info, &self.service_info);
         }
     }
 }
@@ -339,7 +418,11 @@ impl Namespace {
         }
     }
 
-    fn reindex_services(&mut self, service_info: &HashMap<ServiceRef, ServiceInfo>) {
+    fn reindex_services(
+        &mut self,
+        cluster: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+    ) {
         let update_service = |backend: &mut Backend| {
             if let Backend::Service(svc) = backend {
                 let service_ref = ServiceRef {
@@ -347,6 +430,7 @@ impl Namespace {
                     namespace: svc.namespace.clone(),
                 };
                 svc.exists = service_info.contains_key(&service_ref);
+                svc.authority = service_authority(cluster, service_info, &service_ref, svc.port);
             }
         };
 
@@ -367,7 +451,7 @@ impl Namespace {
         }
     }
 
-    fn update_service(&mut self, name: String, service: &ServiceInfo) {
+    fn update_service(&mut self, name: String, service: &ServiceInfo, cluster: &ClusterInfo) {
         tracing::debug!(?name, ?service, "updating service");
 
         for (svc_port, svc_routes) in self.service_port_routes.iter_mut() {
@@ -376,8 +460,12 @@ impl Namespace {
             }
 
             let opaque = service.opaque_ports.contains(&svc_port.port);
+            let authority = match &service.external_name {
+                Some(external_name) => external_authority(external_name, svc_port.port),
+                None => cluster.service_dns_authority(&self.namespace, &name, svc_port.port),
+            };
 
-            svc_routes.update_service(opaque, service.accrual);
+            svc_routes.update_service(authority, opaque, service.accrual);
         }
     }
 
@@ -401,13 +489,11 @@ impl Namespace {
         self.service_port_routes
             .entry(sp.clone())
             .or_insert_with(|| {
-                let authority =
-                    cluster.service_dns_authority(&self.namespace, &sp.service, sp.port);
-
                 let service_ref = ServiceRef {
                     name: sp.service.clone(),
                     namespace: self.namespace.to_string(),
                 };
+                let authority = service_authority(cluster, service_info, &service_ref, sp.port);
 
                 let (opaque, accrual) = match service_info.get(&service_ref) {
                     Some(svc) => (svc.opaque_ports.contains(&sp.port), svc.accrual),
@@ -506,12 +592,7 @@ impl Namespace {
         cluster: &ClusterInfo,
         service_info: &HashMap<ServiceRef, ServiceInfo>,
     ) -> Result<OutboundRouteRule<HttpRouteMatch>> {
-        let matches = rule
-            .matches
-            .into_iter()
-            .flatten()
-            .map(routes::http::try_match)
-            .collect::<Result<_>>()?;
+        let (matches, filters) = routes::http::linkerd_rule(rule.matches, rule.filters)?;
 
         let backends = rule
             .backend_refs
@@ -520,13 +601,6 @@ impl Namespace {
             .filter_map(|b| convert_backend(&self.namespace, b, cluster, service_info))
             .collect();
 
-        let filters = rule
-            .filters
-            .into_iter()
-            .flatten()
-            .map(convert_linkerd_filter)
-            .collect::<Result<_>>()?;
-
         let request_timeout = rule.timeouts.as_ref().and_then(|timeouts| {
             let timeout = time::Duration::from(timeouts.request?);
 
@@ -566,12 +640,7 @@ impl Namespace {
         cluster: &ClusterInfo,
         service_info: &HashMap<ServiceRef, ServiceInfo>,
     ) -> Result<OutboundRouteRule<HttpRouteMatch>> {
-        let matches = rule
-            .matches
-            .into_iter()
-            .flatten()
-            .map(routes::http::try_match)
-            .collect::<Result<_>>()?;
+        let (matches, filters) = routes::http::gateway_rule(rule.matches, rule.filters)?;
 
         let backends = rule
             .backend_refs
@@ -580,13 +649,6 @@ impl Namespace {
             .filter_map(|b| convert_backend(&self.namespace, b, cluster, service_info))
             .collect();
 
-        let filters = rule
-            .filters
-            .into_iter()
-            .flatten()
-            .map(convert_gateway_filter)
-            .collect::<Result<_>>()?;
-
         Ok(OutboundRouteRule {
             matches,
             backends,
@@ -644,7 +706,7 @@ fn convert_backend<BackendRef: Into<HttpBackendRef>>(
     let filters = match filters
         .into_iter()
         .flatten()
-        .map(convert_gateway_filter)
+        .map(routes::http::gateway_filter)
         .collect::<Result<_>>()
     {
         Ok(filters) => filters,
@@ -658,7 +720,7 @@ fn convert_backend<BackendRef: Into<HttpBackendRef>>(
 
     Some(Backend::Service(WeightedService {
         weight: weight.into(),
-        authority: cluster.service_dns_authority(&service_ref.namespace, &name, port),
+        authority: service_authority(cluster, services, &service_ref, port),
         name,
         namespace: service_ref.namespace.to_string(),
         port,
@@ -667,64 +729,27 @@ fn convert_backend<BackendRef: Into<HttpBackendRef>>(
     }))
 }
 
-fn convert_linkerd_filter(filter: linkerd_k8s_api::httproute::HttpRouteFilter) -> Result<Filter> {
-    let filter = match filter {
-        linkerd_k8s_api::httproute::HttpRouteFilter::RequestHeaderModifier {
-            request_header_modifier,
-        } => {
-            let filter = routes::http::header_modifier(request_header_modifier)?;
-            Filter::RequestHeaderModifier(filter)
-        }
-
-        linkerd_k8s_api::httproute::HttpRouteFilter::ResponseHeaderModifier {
-            response_header_modifier,
-        } => {
-            let filter = routes::http::header_modifier(response_header_modifier)?;
-            Filter::RequestHeaderModifier(filter)
-        }
-
-        linkerd_k8s_api::httproute::HttpRouteFilter::RequestRedirect { request_redirect } => {
-            let filter = routes::http::req_redirect(request_redirect)?;
-            Filter::RequestRedirect(filter)
-        }
-    };
-    Ok(filter)
+/// Returns the authority used to discover a Service's endpoints.
+///
+/// `ExternalName` Services have no endpoints in the cluster, so they are
+/// discovered by their external name, as egress traffic would be.
+fn service_authority(
+    cluster: &ClusterInfo,
+    services: &HashMap<ServiceRef, ServiceInfo>,
+    service_ref: &ServiceRef,
+    port: NonZeroU16,
+) -> String {
+    match services
+        .get(service_ref)
+        .and_then(|svc| svc.external_name.as_deref())
+    {
+        Some(external_name) => external_authority(external_name, port),
+        None => cluster.service_dns_authority(&service_ref.namespace, &service_ref.name, port),
+    }
 }
 
-fn convert_gateway_filter<RouteFilter: Into<k8s_gateway_api::HttpRouteFilter>>(
-    filter: RouteFilter,
-) -> Result<Filter> {
-    let filter = filter.into();
-    let filter = match filter {
-        k8s_gateway_api::HttpRouteFilter::RequestHeaderModifier {
-            request_header_modifier,
-        } => {
-            let filter = routes::http::header_modifier(request_header_modifier)?;
-            Filter::RequestHeaderModifier(filter)
-        }
-
-        k8s_gateway_api::HttpRouteFilter::ResponseHeaderModifier {
-            response_header_modifier,
-        } => {
-            let filter = routes::http::header_modifier(response_header_modifier)?;
-            Filter::ResponseHeaderModifier(filter)
-        }
-
-        k8s_gateway_api::HttpRouteFilter::RequestRedirect { request_redirect } => {
-            let filter = routes::http::req_redirect(request_redirect)?;
-            Filter::RequestRedirect(filter)
-        }
-        k8s_gateway_api::HttpRouteFilter::RequestMirror { .. } => {
-            bail!("RequestMirror filter is not supported")
-        }
-        k8s_gateway_api::HttpRouteFilter::URLRewrite { .. } => {
-            bail!("URLRewrite filter is not supported")
-        }
-        k8s_gateway_api::HttpRouteFilter::ExtensionRef { .. } => {
-            bail!("ExtensionRef filter is not supported")
-        }
-    };
-    Ok(filter)
+fn external_authority(external_name: &str, port: NonZeroU16) -> String {
+    format!("{}:{port}", external_name.trim_end_matches('.'))
 }
 
 #[inline]
@@ -785,7 +810,7 @@ impl ServiceRoutes {
             .unwrap_or_default();
 
         self.watches_by_ns.entry(namespace).or_insert_with(|| {
-            let (sender, _) = watch::channel(OutboundPolicy {
+            let (sender, _) = watch::channel(Timestamped::new(OutboundPolicy {
                 port: self.port,
                 opaque: self.opaque,
                 accrual: self.accrual,
@@ -793,9 +818,10 @@ impl ServiceRoutes {
                 name: self.name.to_string(),
                 authority: self.authority.clone(),
                 namespace: self.namespace.to_string(),
-            });
+            }));
 
             RoutesWatch {
+                authority: self.authority.clone(),
                 routes,
                 watch: sender,
                 opaque: self.opaque,
@@ -826,10 +852,12 @@ impl ServiceRoutes {
         }
     }
 
-    fn update_service(&mut self, opaque: bool, accrual: Option<FailureAccrual>) {
+    fn update_service(&mut self, authority: String, opaque: bool, accrual: Option<FailureAccrual>) {
+        self.authority = authority;
         self.opaque = opaque;
         self.accrual = accrual;
         for watch in self.watches_by_ns.values_mut() {
+            watch.authority = self.authority.clone();
             watch.opaque = opaque;
             watch.accrual = accrual;
             watch.send_if_modified();
@@ -883,24 +911,31 @@ impl ServiceRoutes {
 impl RoutesWatch {
     fn send_if_modified(&mut self) {
         self.watch.send_if_modified(|policy| {
-            let mut modified = false;
+            policy.modify(|policy| {
+                let mut modified = false;
 
-            if self.routes != policy.routes {
-                policy.routes = self.routes.clone();
-                modified = true;
-            }
+                if self.routes != policy.routes {
+                    policy.routes = self.routes.clone();
+                    modified = true;
+                }
 
-            if self.opaque != policy.opaque {
-                policy.opaque = self.opaque;
-                modified = true;
-            }
+                if self.authority != policy.authority {
+                    policy.authority = self.authority.clone();
+                    modified = true;
+                }
 
-            if self.accrual != policy.accrual {
-                policy.accrual = self.accrual;
-                modified = true;
-            }
+                if self.opaque != policy.opaque {
+                    policy.opaque = self.opaque;
+                    modified = true;
+                }
+
+                if self.accrual != policy.accrual {
+                    policy.accrual = self.accrual;
+                    modified = true;
+                }
 
-            modified
+                modified
+            })
         });
     }
 
@@ -55,11 +55,49 @@ impl TestConfig {
             default_opaque_ports: Default::default(),
             probe_networks,
         };
-        let index = Index::shared(Arc::new(cluster));
+        let index = Index::shared(Arc::new(cluster), Default::default());
         Self { index }
     }
 }
 
+#[test]
+fn lookup_service_port() {
+    use linkerd_policy_controller_core::outbound::ServicePortRef;
+    use std::num::NonZeroU16;
+
+    let test = TestConfig::default();
+    let mut svc = mk_service("ns", "web", 8080);
+    svc.spec.as_mut().unwrap().ports.as_mut().unwrap()[0].name = Some("http".to_string());
+    test.index.write().apply(svc);
+
+    let index = test.index.read();
+    let port = |p: u16| Some(NonZeroU16::new(p).unwrap());
+    assert_eq!(
+        index.lookup_service_port("ns", "web", &ServicePortRef::Name("http".to_string())),
+        port(8080)
+    );
+    assert_eq!(
+        index.lookup_service_port("ns", "web", &ServicePortRef::Name("admin".to_string())),
+        None
+    );
+    assert_eq!(
+        index.lookup_service_port(
+            "ns",
+            "web",
+            &ServicePortRef::Number(9090.try_into().unwrap())
+        ),
+        port(9090)
+    );
+    assert_eq!(
+        index.lookup_service_port(
+            "ns",
+            "api",
+            &ServicePortRef::Number(8080.try_into().unwrap())
+        ),
+        None
+    );
+}
+
 impl Default for TestConfig {
     fn default() -> TestConfig {
         Self::from_default_policy(DefaultPolicy::Allow {
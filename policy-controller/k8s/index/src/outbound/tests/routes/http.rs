@@ -1,6 +1,6 @@
 use kube::Resource;
 use linkerd_policy_controller_core::{
-    outbound::{Backend, OutboundRouteCollection, WeightedService},
+    outbound::{Backend, OutboundPolicy, OutboundRouteCollection, WeightedService},
     routes::GroupKindNamespaceName,
     POLICY_CONTROLLER_NAME,
 };
@@ -106,6 +106,71 @@ fn backend_service() {
     }
 }
 
+#[test]
+fn external_name_service() {
+    let test = TestConfig::default();
+
+    let mut api = mk_service("ns", "api", 443);
+    let spec = api.spec.as_mut().unwrap();
+    spec.type_ = Some("ExternalName".to_string());
+    spec.external_name = Some("api.example.com".to_string());
+    test.index.write().apply(api);
+
+    let route = mk_route("ns", "route", 443, "api", "api");
+    test.index.write().apply(route);
+
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "api".to_string(),
+            "ns".to_string(),
+            443.try_into().unwrap(),
+            "ns".to_string(),
+        )
+        .expect("api.ns should exist");
+
+    let backend_authority = |policy: &OutboundPolicy| {
+        let OutboundRouteCollection::Http(routes) = &policy.routes else {
+            panic!("expected http route collection");
+        };
+        let backend = routes
+            .values()
+            .next()
+            .expect("route should exist")
+            .rules
+            .first()
+            .expect("rule should exist")
+            .backends
+            .first()
+            .expect("backend should exist");
+        match backend {
+            Backend::Service(WeightedService { authority, .. }) => authority.clone(),
+            backend => panic!("backend should be a service, but got {backend:?}"),
+        }
+    };
+
+    // ExternalName Services are discovered by their external name.
+    {
+        let policy = rx.borrow_and_update();
+        assert_eq!(policy.authority, "api.example.com:443");
+        assert_eq!(backend_authority(&policy), "api.example.com:443");
+    }
+
+    // When the Service no longer has an external name, it is discovered by its
+    // cluster name.
+    test.index.write().apply(mk_service("ns", "api", 443));
+    assert!(rx.has_changed().unwrap());
+    {
+        let policy = rx.borrow_and_update();
+        assert_eq!(policy.authority, "api.ns.svc.cluster.example.com:443");
+        assert_eq!(
+            backend_authority(&policy),
+            "api.ns.svc.cluster.example.com:443"
+        );
+    }
+}
+
 fn mk_route(
     ns: impl ToString,
     name: impl ToString,
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, bail, Result};
-use linkerd_policy_controller_core::routes;
-use linkerd_policy_controller_k8s_api::gateway as api;
+use linkerd_policy_controller_core::{outbound::Filter, routes};
+use linkerd_policy_controller_k8s_api::{gateway as api, policy};
 use std::num::NonZeroU16;
 
 pub fn try_match(
@@ -90,6 +90,110 @@ pub fn query_param_match(query_match: api::HttpQueryParamMatch) -> Result<routes
     }
 }
 
+/// Converts a Gateway API rule's matches and filters as they are served to
+/// proxies.
+///
+/// Routes with a rule that fails to convert are not served at all, so this is
+/// also used to report such routes as not accepted.
+pub fn gateway_rule(
+    matches: Option<Vec<api::HttpRouteMatch>>,
+    filters: Option<Vec<api::HttpRouteFilter>>,
+) -> Result<(Vec<routes::HttpRouteMatch>, Vec<Filter>)> {
+    rule(matches, filters, gateway_filter)
+}
+
+/// Converts a `policy.linkerd.io` rule's matches and filters as they are
+/// served to proxies.
+///
+/// Routes with a rule that fails to convert are not served at all, so this is
+/// also used to report such routes as not accepted.
+pub fn linkerd_rule(
+    matches: Option<Vec<api::HttpRouteMatch>>,
+    filters: Option<Vec<policy::httproute::HttpRouteFilter>>,
+) -> Result<(Vec<routes::HttpRouteMatch>, Vec<Filter>)> {
+    rule(matches, filters, linkerd_filter)
+}
+
+fn rule<F>(
+    matches: Option<Vec<api::HttpRouteMatch>>,
+    filters: Option<Vec<F>>,
+    try_filter: impl Fn(F) -> Result<Filter>,
+) -> Result<(Vec<routes::HttpRouteMatch>, Vec<Filter>)> {
+    let matches = matches
+        .into_iter()
+        .flatten()
+        .map(try_match)
+        .collect::<Result<_>>()?;
+
+    let filters = filters
+        .into_iter()
+        .flatten()
+        .map(try_filter)
+        .collect::<Result<_>>()?;
+
+    Ok((matches, filters))
+}
+
+pub fn linkerd_filter(filter: policy::httproute::HttpRouteFilter) -> Result<Filter> {
+    let filter = match filter {
+        policy::httproute::HttpRouteFilter::RequestHeaderModifier {
+            request_header_modifier,
+        } => {
+            let filter = header_modifier(request_header_modifier)?;
+            Filter::RequestHeaderModifier(filter)
+        }
+
+        policy::httproute::HttpRouteFilter::ResponseHeaderModifier {
+            response_header_modifier,
+        } => {
+            let filter = header_modifier(response_header_modifier)?;
+            Filter::RequestHeaderModifier(filter)
+        }
+
+        policy::httproute::HttpRouteFilter::RequestRedirect { request_redirect } => {
+            let filter = req_redirect(request_redirect)?;
+            Filter::RequestRedirect(filter)
+        }
+    };
+    Ok(filter)
+}
+
+pub fn gateway_filter<RouteFilter: Into<api::HttpRouteFilter>>(
+    filter: RouteFilter,
+) -> Result<Filter> {
+    let filter = filter.into();
+    let filter = match filter {
+        api::HttpRouteFilter::RequestHeaderModifier {
+            request_header_modifier,
+        } => {
+            let filter = header_modifier(request_header_modifier)?;
+            Filter::RequestHeaderModifier(filter)
+        }
+
+        api::HttpRouteFilter::ResponseHeaderModifier {
+            response_header_modifier,
+        } => {
+            let filter = header_modifier(response_header_modifier)?;
+            Filter::ResponseHeaderModifier(filter)
+        }
+
+        api::HttpRouteFilter::RequestRedirect { request_redirect } => {
+            let filter = req_redirect(request_redirect)?;
+            Filter::RequestRedirect(filter)
+        }
+        api::HttpRouteFilter::RequestMirror { .. } => {
+            bail!("RequestMirror filter is not supported")
+        }
+        api::HttpRouteFilter::URLRewrite { .. } => {
+            bail!("URLRewrite filter is not supported")
+        }
+        api::HttpRouteFilter::ExtensionRef { .. } => {
+            bail!("ExtensionRef filter is not supported")
+        }
+    };
+    Ok(filter)
+}
+
 pub fn header_modifier(
     api::HttpRequestHeaderFilter { set, add, remove }: api::HttpRequestHeaderFilter,
 ) -> Result<routes::HeaderModifierFilter> {
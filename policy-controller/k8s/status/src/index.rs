@@ -1,4 +1,5 @@
 use crate::{
+    queue::{PatchQueue, Ready},
     resource_id::{NamespaceGroupKindName, ResourceId},
     routes,
     service::Service,
@@ -11,9 +12,10 @@ use linkerd_policy_controller_k8s_api::{
     self as k8s_core_api, gateway as k8s_gateway_api, policy as linkerd_k8s_api,
     NamespaceResourceScope, Resource, ResourceExt,
 };
+use linkerd_policy_controller_k8s_index::inbound;
 use parking_lot::RwLock;
 use prometheus_client::{
-    metrics::{counter::Counter, histogram::Histogram},
+    metrics::{counter::Counter, gauge::Gauge, histogram::Histogram},
     registry::{Registry, Unit},
 };
 use serde::de::DeserializeOwned;
@@ -29,13 +31,28 @@ pub(crate) const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
 mod conditions {
     pub const RESOLVED_REFS: &str = "ResolvedRefs";
     pub const ACCEPTED: &str = "Accepted";
+    pub const AUTHORIZED: &str = "Authorized";
+    pub const REFERENCED: &str = "Referenced";
+    pub const CONFLICTED: &str = "Conflicted";
 }
 
 mod reasons {
     pub const RESOLVED_REFS: &str = "ResolvedRefs";
+    pub const CONFLICTED: &str = "Conflicted";
     pub const BACKEND_NOT_FOUND: &str = "BackendNotFound";
     pub const INVALID_KIND: &str = "InvalidKind";
     pub const NO_MATCHING_PARENT: &str = "NoMatchingParent";
+    pub const NO_MATCHING_WORKLOADS: &str = "NoMatchingWorkloads";
+    pub const PORT_NOT_FOUND: &str = "PortNotFound";
+    pub const AUTHORIZED: &str = "Authorized";
+    pub const NO_AUTHORIZATIONS: &str = "NoAuthorizations";
+    pub const TARGET_NOT_FOUND: &str = "TargetNotFound";
+    pub const INVALID: &str = "Invalid";
+    pub const AUTHENTICATION_NOT_FOUND: &str = "AuthenticationNotFound";
+    pub const IDENTITY_NOT_FOUND: &str = "IdentityNotFound";
+    pub const REFERENCED: &str = "Referenced";
+    pub const UNREFERENCED: &str = "Unreferenced";
+    pub const UNSUPPORTED_VALUE: &str = "UnsupportedValue";
 }
 
 mod cond_statuses {
@@ -52,6 +69,9 @@ pub struct Controller {
     updates: mpsc::Receiver<Update>,
     patch_timeout: Duration,
 
+    /// Patches waiting to be applied or retried, at most one per resource.
+    queue: PatchQueue,
+
     /// True if this policy controller is the leader — false otherwise.
     leader: bool,
 
@@ -65,6 +85,10 @@ pub struct ControllerMetrics {
     patch_duration: Histogram,
     patch_dequeues: Counter,
     patch_drops: Counter,
+    patch_coalesced: Counter,
+    patch_retries: Counter,
+    patch_conflicts: Counter,
+    patch_queue_depth: Gauge,
 }
 
 pub struct Index {
@@ -80,15 +104,41 @@ pub struct Index {
     /// Maps route ids to a list of their parent and backend refs,
     /// regardless of if those parents have accepted the route.
     route_refs: HashMap<NamespaceGroupKindName, RouteRef>,
-    servers: HashSet<ResourceId>,
+
+    /// Maps server ids to the conditions currently set on their status.
+    servers: HashMap<ResourceId, Vec<k8s_core_api::Condition>>,
     services: HashMap<ResourceId, Service>,
 
+    /// Maps authorization policy ids to the authentications they require and
+    /// the conditions currently set on their status.
+    authorization_policies: HashMap<ResourceId, AuthorizationPolicyRef>,
+
+    /// Maps `MeshTLSAuthentication` and `NetworkAuthentication` ids to their
+    /// identity refs and their current status.
+    authentications: HashMap<NamespaceGroupKindName, AuthenticationRef>,
+
+    /// Service accounts that may be referenced by authorization policies and
+    /// authentications.
+    service_accounts: HashSet<ResourceId>,
+
+    /// Namespaces that may be referenced by authentications.
+    namespaces: HashSet<String>,
+
+    /// Describes how each `Server` applies to the workloads in its namespace
+    /// and whether each `AuthorizationPolicy`'s references resolve.
+    inbound: inbound::SharedIndex,
+
+    /// Resources whose status may have changed since they were last
+    /// reconciled.
+    dirty: HashSet<NamespaceGroupKindName>,
+
     metrics: IndexMetrics,
 }
 
 pub struct IndexMetrics {
     patch_enqueues: Counter,
     patch_channel_full: Counter,
+    reconciles: Counter,
 }
 
 #[derive(Clone, PartialEq)]
@@ -96,6 +146,41 @@ struct RouteRef {
     parents: Vec<routes::ParentReference>,
     backends: Vec<routes::BackendReference>,
     statuses: Vec<k8s_gateway_api::RouteParentStatus>,
+
+    /// Used to detect conflicts between routes that share a parent.
+    hostnames: Vec<String>,
+    matches: Vec<k8s_gateway_api::HttpRouteMatch>,
+    creation_timestamp: Option<DateTime<Utc>>,
+
+    /// Describes why the route cannot be served to proxies, if it cannot.
+    invalid: Option<String>,
+}
+
+#[derive(Clone, PartialEq)]
+struct AuthorizationPolicyRef {
+    /// The `MeshTLSAuthentication`s and `NetworkAuthentication`s required by
+    /// the policy.
+    authentications: Vec<NamespaceGroupKindName>,
+    service_accounts: Vec<ResourceId>,
+
+    /// The `Service` targeted by the policy, if any.
+    target_service: Option<ResourceId>,
+    conditions: Vec<k8s_core_api::Condition>,
+}
+
+#[derive(Clone, PartialEq)]
+struct AuthenticationRef {
+    identity_refs: Vec<IdentityRef>,
+
+    /// The policies, as `namespace/name`, currently set on the status.
+    policies: Vec<String>,
+    conditions: Vec<k8s_core_api::Condition>,
+}
+
+#[derive(Clone, PartialEq)]
+enum IdentityRef {
+    ServiceAccount(ResourceId),
+    Namespace(String),
 }
 
 #[derive(Debug, PartialEq)]
@@ -150,6 +235,34 @@ impl ControllerMetrics {
             patch_drops.clone(),
         );
 
+        let patch_coalesced = Counter::default();
+        prom.register(
+            "patch_coalesced",
+            "Count of patches that replaced a pending patch for the same resource",
+            patch_coalesced.clone(),
+        );
+
+        let patch_retries = Counter::default();
+        prom.register(
+            "patch_retries",
+            "Count of failed patches that were scheduled to be retried",
+            patch_retries.clone(),
+        );
+
+        let patch_conflicts = Counter::default();
+        prom.register(
+            "patch_conflicts",
+            "Count of patches that conflicted with another field manager",
+            patch_conflicts.clone(),
+        );
+
+        let patch_queue_depth = Gauge::default();
+        prom.register(
+            "patch_queue_depth",
+            "Number of resources with a patch waiting to be applied or retried",
+            patch_queue_depth.clone(),
+        );
+
         Self {
             patch_succeeded,
             patch_failed,
@@ -157,6 +270,10 @@ impl ControllerMetrics {
             patch_duration,
             patch_dequeues,
             patch_drops,
+            patch_coalesced,
+            patch_retries,
+            patch_conflicts,
+            patch_queue_depth,
         }
     }
 }
@@ -177,9 +294,17 @@ impl IndexMetrics {
             patch_channel_full.clone(),
         );
 
+        let reconciles = Counter::default();
+        prom.register(
+            "reconciles",
+            "Count of resources whose status was reconciled",
+            reconciles.clone(),
+        );
+
         Self {
             patch_enqueues,
             patch_channel_full,
+            reconciles,
         }
     }
 }
@@ -199,21 +324,31 @@ impl Controller {
             name,
             updates,
             patch_timeout,
+            queue: PatchQueue::default(),
             leader: false,
             metrics,
         }
     }
 
     /// Process updates received from the index; each update is a patch that
-    /// should be applied to update the status of a route. A patch should
+    /// should be applied to update the status of a resource. A patch should
     /// only be applied if we are the holder of the write lease.
+    ///
+    /// Updates are queued so that multiple updates to the same resource are
+    /// coalesced into a single patch, and patches that fail are retried with
+    /// an exponential backoff.
     pub async fn run(mut self) {
-        // Select between the write lease claim changing and receiving updates
-        // from the index. If the lease claim changes, then check if we are
-        // now the leader. If so, we should apply the patches received;
-        // otherwise, we should drain the updates queue but not apply any
-        // patches since another policy controller is responsible for that.
+        // Select between the write lease claim changing, receiving updates
+        // from the index, and the next queued patch becoming ready. If the
+        // lease claim changes, then check if we are now the leader. If so, we
+        // should apply the patches received; otherwise, we should drain the
+        // updates queue but not apply any patches since another policy
+        // controller is responsible for that.
+        //
+        // Updates are preferred over queued patches so that all of the
+        // updates that are available are coalesced before patching.
         loop {
+            let next_ready_at = self.queue.next_ready_at();
             tokio::select! {
                 biased;
                 res = self.claims.changed() => {
@@ -224,24 +359,71 @@ impl Controller {
                     if was_leader != self.leader {
                         tracing::debug!(leader = %self.leader, "Leadership changed");
                     }
+                    if !self.leader {
+                        self.metrics.patch_drops.inc_by(self.queue.len() as u64);
+                        self.queue.clear();
+                    }
                 }
 
-                Some(Update { id, patch}) = self.updates.recv() => {
+                Some(update) = self.updates.recv() => {
                     self.metrics.patch_dequeues.inc();
                     // If this policy controller is not the leader, it should
                     // process through the updates queue but not actually patch
                     // any resources.
                     if self.leader {
-                        if id.gkn.group == linkerd_k8s_api::HttpRoute::group(&()) && id.gkn.kind == linkerd_k8s_api::HttpRoute::kind(&()){
-                            self.patch_status::<linkerd_k8s_api::HttpRoute>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.gkn.group == k8s_gateway_api::HttpRoute::group(&()) && id.gkn.kind == k8s_gateway_api::HttpRoute::kind(&()) {
-                            self.patch_status::<k8s_gateway_api::HttpRoute>(&id.gkn.name, &id.namespace, patch).await;
+                        if self.queue.push(update, time::Instant::now()) {
+                            self.metrics.patch_coalesced.inc();
                         }
                     } else {
                         self.metrics.patch_drops.inc();
                     }
                 }
+
+                _ = sleep_until(next_ready_at), if next_ready_at.is_some() => {
+                    if let Some(ready) = self.queue.pop_ready(time::Instant::now()) {
+                        self.patch(ready).await;
+                    }
+                }
             }
+            self.metrics.patch_queue_depth.set(self.queue.len() as i64);
+        }
+    }
+
+    async fn patch(&mut self, ready: Ready) {
+        let Ready { id, patch, .. } = &ready;
+        let (name, namespace) = (id.gkn.name.as_ref(), id.namespace.as_str());
+        let outcome = match (id.gkn.group.as_ref(), id.gkn.kind.as_ref()) {
+            (POLICY_API_GROUP, "HTTPRoute") => {
+                self.patch_status::<linkerd_k8s_api::HttpRoute>(name, namespace, patch)
+                    .await
+            }
+            (GATEWAY_API_GROUP, "HTTPRoute") => {
+                self.patch_status::<k8s_gateway_api::HttpRoute>(name, namespace, patch)
+                    .await
+            }
+            (POLICY_API_GROUP, "Server") => {
+                self.patch_status::<linkerd_k8s_api::Server>(name, namespace, patch)
+                    .await
+            }
+            (POLICY_API_GROUP, "AuthorizationPolicy") => {
+                self.patch_status::<linkerd_k8s_api::AuthorizationPolicy>(name, namespace, patch)
+                    .await
+            }
+            (POLICY_API_GROUP, "MeshTLSAuthentication") => {
+                self.patch_status::<linkerd_k8s_api::MeshTLSAuthentication>(name, namespace, patch)
+                    .await
+            }
+            (POLICY_API_GROUP, "NetworkAuthentication") => {
+                self.patch_status::<linkerd_k8s_api::NetworkAuthentication>(name, namespace, patch)
+                    .await
+            }
+            _ => PatchOutcome::Done,
+        };
+
+        if outcome == PatchOutcome::Retry {
+            self.metrics.patch_retries.inc();
+            let backoff = self.queue.retry(ready, time::Instant::now());
+            tracing::debug!(?backoff, "Retrying patch");
         }
     }
 
@@ -249,48 +431,103 @@ impl Controller {
         &self,
         name: &str,
         namespace: &str,
-        patch: k8s_core_api::Patch<serde_json::Value>,
-    ) where
+        patch: &k8s_core_api::Patch<serde_json::Value>,
+    ) -> PatchOutcome
+    where
         K: Resource<Scope = NamespaceResourceScope>,
         <K as Resource>::DynamicType: Default,
         K: DeserializeOwned,
     {
-        let patch_params = k8s_core_api::PatchParams::apply(K::group(&Default::default()).as_ref());
+        let kind = K::kind(&Default::default()).to_string();
+        let mut patch_params =
+            k8s_core_api::PatchParams::apply(K::group(&Default::default()).as_ref());
         let api = k8s_core_api::Api::<K>::namespaced(self.client.clone(), namespace);
-        let start = time::Instant::now();
 
-        match time::timeout(
-            self.patch_timeout,
-            api.patch_status(name, &patch_params, &patch),
-        )
-        .await
-        {
-            Ok(Ok(_)) => {
-                self.metrics.patch_succeeded.inc();
-                self.metrics
-                    .patch_duration
-                    .observe(start.elapsed().as_secs_f64());
-            }
-            Ok(Err(error)) => {
-                self.metrics.patch_failed.inc();
-                self.metrics
-                    .patch_duration
-                    .observe(start.elapsed().as_secs_f64());
-                tracing::error!(%namespace, %name, kind = %K::kind(&Default::default()), %error, "Patch failed");
-            }
-            Err(_) => {
-                self.metrics.patch_timeout.inc();
-                tracing::error!(%namespace, %name, kind = %K::kind(&Default::default()), "Patch timed out");
+        loop {
+            let start = time::Instant::now();
+            match time::timeout(
+                self.patch_timeout,
+                api.patch_status(name, &patch_params, patch),
+            )
+            .await
+            {
+                Ok(Ok(_)) => {
+                    self.metrics.patch_succeeded.inc();
+                    self.metrics
+                        .patch_duration
+                        .observe(start.elapsed().as_secs_f64());
+                    return PatchOutcome::Done;
+                }
+                Ok(Err(error)) => {
+                    self.metrics.patch_failed.inc();
+                    self.metrics
+                        .patch_duration
+                        .observe(start.elapsed().as_secs_f64());
+
+                    // The status is owned by this controller, so a conflict
+                    // with another field manager (e.g. an older controller
+                    // version) is resolved by forcing the patch.
+                    if error_code(&error) == Some(409) && !patch_params.force {
+                        self.metrics.patch_conflicts.inc();
+                        tracing::info!(%namespace, %name, %kind, %error, "Patch conflicted; forcing");
+                        patch_params = patch_params.force();
+                        continue;
+                    }
+
+                    if is_retryable(&error) {
+                        tracing::warn!(%namespace, %name, %kind, %error, "Patch failed");
+                        return PatchOutcome::Retry;
+                    }
+                    tracing::error!(%namespace, %name, %kind, %error, "Patch failed");
+                    return PatchOutcome::Done;
+                }
+                Err(_) => {
+                    self.metrics.patch_timeout.inc();
+                    tracing::warn!(%namespace, %name, %kind, "Patch timed out");
+                    return PatchOutcome::Retry;
+                }
             }
         }
     }
 }
 
+async fn sleep_until(deadline: Option<time::Instant>) {
+    if let Some(deadline) = deadline {
+        time::sleep_until(deadline).await
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum PatchOutcome {
+    /// The patch was applied or cannot be applied.
+    Done,
+
+    /// The patch failed with an error that may be transient.
+    Retry,
+}
+
+fn error_code(error: &k8s_core_api::Error) -> Option<u16> {
+    match error {
+        k8s_core_api::Error::Api(k8s_core_api::ErrorResponse { code, .. }) => Some(*code),
+        _ => None,
+    }
+}
+
+/// Returns false for API errors that will recur if the patch is retried,
+/// e.g. because the resource has been deleted or the patch is invalid.
+fn is_retryable(error: &k8s_core_api::Error) -> bool {
+    match error_code(error) {
+        Some(code) => code == 409 || code == 429 || code >= 500,
+        None => true,
+    }
+}
+
 impl Index {
     pub fn shared(
         name: impl ToString,
         claims: Receiver<Arc<Claim>>,
         updates: mpsc::Sender<Update>,
+        inbound: inbound::SharedIndex,
         metrics: IndexMetrics,
     ) -> SharedIndex {
         Arc::new(RwLock::new(Self {
@@ -298,44 +535,64 @@ impl Index {
             claims,
             updates,
             route_refs: HashMap::new(),
-            servers: HashSet::new(),
+            servers: HashMap::new(),
             services: HashMap::new(),
+            authorization_policies: HashMap::new(),
+            authentications: HashMap::new(),
+            service_accounts: HashSet::new(),
+            namespaces: HashSet::new(),
+            inbound,
+            dirty: HashSet::new(),
             metrics,
         }))
     }
 
-    /// When the write leaseholder changes or a time duration has elapsed,
-    /// the index reconciles the statuses for all routes on the cluster.
+    /// Reconciles the statuses of resources as their dependencies change.
+    ///
+    /// Most changes are observed as resources are indexed. Server and
+    /// authorization policy statuses also depend on the workloads and policies
+    /// in the inbound index, so they are reconciled when the inbound index
+    /// reports that their namespace has changed.
     ///
-    /// This reconciliation loop ensures that if errors occur when the
-    /// Controller applies patches or the write leaseholder changes, all
-    /// routes have an up-to-date status.
-    pub async fn run(index: Arc<RwLock<Self>>, reconciliation_period: Duration) {
-        // Clone the claims watch out of the index. This will immediately
-        // drop the read lock on the index so that it is not held for the
-        // lifetime of this function.
-        let mut claims = index.read().claims.clone();
+    /// When the write leaseholder changes, and periodically thereafter, all
+    /// resources are reconciled. This ensures that all resources have an
+    /// up-to-date status, even if a patch could not be applied.
+    pub async fn run(index: Arc<RwLock<Self>>, resync_period: Duration) {
+        // Clone the claims watch and inbound index out of the index. This
+        // will immediately drop the read lock on the index so that it is not
+        // held for the lifetime of this function.
+        let (mut claims, inbound) = {
+            let index = index.read();
+            (index.claims.clone(), index.inbound.clone())
+        };
+        let inbound_changed = inbound.read().changed();
+        let mut resync = time::interval_at(time::Instant::now() + resync_period, resync_period);
+        resync.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
 
         loop {
             tokio::select! {
                 res = claims.changed() => {
                     res.expect("Claims watch must not be dropped");
                     tracing::debug!("Lease holder has changed");
+                    claims.borrow_and_update();
+                    index.write().mark_all_dirty();
+                }
+                _ = inbound_changed.notified() => {
+                    let (namespaces, servers) = {
+                        let mut inbound = inbound.write();
+                        (inbound.take_changed_namespaces(), inbound.take_changed_servers())
+                    };
+                    let mut index = index.write();
+                    index.mark_namespaces_dirty(&namespaces);
+                    index.mark_servers_dirty(&servers);
+                }
+                _ = resync.tick() => {
+                    tracing::debug!("Resyncing all statuses");
+                    index.write().mark_all_dirty();
                 }
-                _ = time::sleep(reconciliation_period) => {}
             }
 
-            // The claimant has changed, or we should attempt to reconcile all
-            //routes to account for any errors. In either case, we should
-            // only proceed if we are the current leader.
-            let claims = claims.borrow_and_update();
-            let index = index.read();
-
-            if !claims.is_current_for(&index.name) {
-                continue;
-            }
-            tracing::debug!(%index.name, "Lease holder reconciling cluster");
-            index.reconcile();
+            index.write().reconcile();
         }
     }
 
@@ -358,15 +615,33 @@ impl Index {
 
     fn parent_status(
         &self,
+        id: &NamespaceGroupKindName,
+        route: &RouteRef,
         parent_ref: &routes::ParentReference,
         backend_condition: k8s_core_api::Condition,
     ) -> Option<k8s_gateway_api::RouteParentStatus> {
+        // A route whose matches overlap with those of a route that takes
+        // precedence is still accepted, since its other matches are served.
+        // The overlap is reported in a separate condition that does not
+        // affect whether the route is accepted.
+        let accepted_conditions = || {
+            if let Some(message) = &route.invalid {
+                return vec![unsupported_value(message)];
+            }
+            let mut conditions = vec![accepted()];
+            conditions.extend(
+                self.conflicting_route(id, route, parent_ref)
+                    .map(conflicted),
+            );
+            conditions
+        };
+
         match parent_ref {
             routes::ParentReference::Server(server) => {
-                let condition = if self.servers.contains(server) {
-                    accepted()
+                let conditions = if self.servers.contains_key(server) {
+                    accepted_conditions()
                 } else {
-                    no_matching_parent()
+                    vec![no_matching_parent()]
                 };
 
                 Some(k8s_gateway_api::RouteParentStatus {
@@ -379,20 +654,21 @@ impl Index {
                         port: None,
                     },
                     controller_name: POLICY_CONTROLLER_NAME.to_string(),
-                    conditions: vec![condition],
+                    conditions,
                 })
             }
             routes::ParentReference::Service(service, port) => {
                 // service is a valid parent if it exists and it has a cluster_ip.
-                let condition = if self
+                let mut conditions = if self
                     .services
                     .get(service)
                     .map_or(false, |svc| svc.valid_parent_service())
                 {
-                    accepted()
+                    accepted_conditions()
                 } else {
-                    no_matching_parent()
+                    vec![no_matching_parent()]
                 };
+                conditions.push(backend_condition);
 
                 Some(k8s_gateway_api::RouteParentStatus {
                     parent_ref: k8s_gateway_api::ParentReference {
@@ -404,13 +680,56 @@ impl Index {
                         port: *port,
                     },
                     controller_name: POLICY_CONTROLLER_NAME.to_string(),
-                    conditions: vec![condition, backend_condition],
+                    conditions,
                 })
             }
             routes::ParentReference::UnknownKind => None,
         }
     }
 
+    /// Returns the oldest route that takes precedence over `route` for at
+    /// least one of its matches on the given parent, if any. Invalid routes
+    /// never take precedence.
+    ///
+    /// Per the Gateway API, the oldest route wins, and then the route that
+    /// appears first in alphabetical order by `{namespace}/{name}`.
+    fn conflicting_route<'r>(
+        &'r self,
+        id: &NamespaceGroupKindName,
+        route: &RouteRef,
+        parent_ref: &routes::ParentReference,
+    ) -> Option<&'r NamespaceGroupKindName> {
+        fn precedence<'a>(
+            id: &'a NamespaceGroupKindName,
+            route: &RouteRef,
+        ) -> (bool, Option<DateTime<Utc>>, &'a str, &'a str) {
+            // Routes with timestamps are preferred over routes without.
+            (
+                route.creation_timestamp.is_none(),
+                route.creation_timestamp,
+                &id.namespace,
+                &id.gkn.name,
+            )
+        }
+
+        fn conflicts(winner: &RouteRef, route: &RouteRef) -> bool {
+            routes::hostnames_overlap(&winner.hostnames, &route.hostnames)
+                && winner.matches.iter().any(|m| route.matches.contains(m))
+        }
+
+        self.route_refs
+            .iter()
+            .filter(|(other_id, other)| {
+                *other_id != id
+                    && other.invalid.is_none()
+                    && precedence(other_id, other) < precedence(id, route)
+                    && other.parents.iter().any(|p| p.overlaps(parent_ref))
+                    && conflicts(other, route)
+            })
+            .min_by(|(a_id, a), (b_id, b)| precedence(a_id, a).cmp(&precedence(b_id, b)))
+            .map(|(other_id, _)| other_id)
+    }
+
     fn backend_condition(
         &self,
         backend_refs: &[routes::BackendReference],
@@ -451,14 +770,13 @@ impl Index {
 
         // Compute a status for each parent_ref which has a kind we support.
         let backend_condition = self.backend_condition(&route.backends);
-        let parent_statuses = route
-            .parents
-            .iter()
-            .filter_map(|parent_ref| self.parent_status(parent_ref, backend_condition.clone()));
+        let parent_statuses = route.parents.iter().filter_map(|parent_ref| {
+            self.parent_status(id, route, parent_ref, backend_condition.clone())
+        });
 
         let all_statuses = unowned_statuses.chain(parent_statuses).collect::<Vec<_>>();
 
-        if eq_time_insensitive(&all_statuses, &route.statuses) {
+        if eq_parent_statuses_time_insensitive(&all_statuses, &route.statuses) {
             return None;
         }
 
@@ -487,21 +805,318 @@ impl Index {
         }
     }
 
-    fn reconcile(&self) {
-        for (id, route) in self.route_refs.iter() {
-            if let Some(patch) = self.make_route_patch(id, route) {
-                match self.updates.try_send(Update {
-                    id: id.clone(),
-                    patch,
-                }) {
-                    Ok(()) => {
-                        self.metrics.patch_enqueues.inc();
-                    }
-                    Err(error) => {
-                        self.metrics.patch_channel_full.inc();
-                        tracing::error!(%id.namespace, route = ?id.gkn, %error, "Failed to send route patch");
-                    }
-                }
+    fn make_server_patch(
+        &self,
+        id: &ResourceId,
+        conditions: &[k8s_core_api::Condition],
+    ) -> Option<k8s_core_api::Patch<serde_json::Value>> {
+        let summary = self
+            .inbound
+            .read()
+            .server_summary(&id.namespace, &id.name)?;
+        let status = linkerd_k8s_api::server::ServerStatus {
+            conditions: server_conditions(&summary),
+        };
+        if eq_conditions_time_insensitive(&status.conditions, conditions) {
+            return None;
+        }
+
+        make_patch(&resource_id::<linkerd_k8s_api::Server>(id), status)
+    }
+
+    fn make_authorization_policy_patch(
+        &self,
+        id: &ResourceId,
+        conditions: &[k8s_core_api::Condition],
+    ) -> Option<k8s_core_api::Patch<serde_json::Value>> {
+        let summary = self
+            .inbound
+            .read()
+            .authorization_policy_summary(&id.namespace, &id.name)?;
+        let status = linkerd_k8s_api::authorization_policy::AuthorizationPolicyStatus {
+            conditions: authorization_policy_conditions(&summary, &self.service_accounts),
+        };
+        if eq_conditions_time_insensitive(&status.conditions, conditions) {
+            return None;
+        }
+
+        make_patch(
+            &resource_id::<linkerd_k8s_api::AuthorizationPolicy>(id),
+            status,
+        )
+    }
+
+    fn make_authentication_patch(
+        &self,
+        id: &NamespaceGroupKindName,
+        authn: &AuthenticationRef,
+    ) -> Option<k8s_core_api::Patch<serde_json::Value>> {
+        let mut policies = self
+            .authorization_policies
+            .iter()
+            .filter(|(_, policy)| policy.authentications.contains(id))
+            .map(|(policy_id, _)| format!("{}/{}", policy_id.namespace, policy_id.name))
+            .collect::<Vec<_>>();
+        policies.sort();
+
+        let mut conditions = Vec::with_capacity(2);
+        if id.gkn.kind == linkerd_k8s_api::MeshTLSAuthentication::kind(&()) {
+            conditions.push(identity_refs_condition(
+                &authn.identity_refs,
+                &self.service_accounts,
+                &self.namespaces,
+            ));
+        }
+        conditions.push(referenced_condition(policies.len()));
+
+        if policies == authn.policies
+            && eq_conditions_time_insensitive(&conditions, &authn.conditions)
+        {
+            return None;
+        }
+
+        let authorization_policy_count = policies.len() as u32;
+        if id.gkn.kind == linkerd_k8s_api::MeshTLSAuthentication::kind(&()) {
+            let status = linkerd_k8s_api::meshtls_authentication::MeshTLSAuthenticationStatus {
+                authorization_policies: policies,
+                authorization_policy_count,
+                conditions,
+            };
+            make_patch(id, status)
+        } else {
+            let status = linkerd_k8s_api::network_authentication::NetworkAuthenticationStatus {
+                authorization_policies: policies,
+                authorization_policy_count,
+                conditions,
+            };
+            make_patch(id, status)
+        }
+    }
+
+    fn is_leader(&self) -> bool {
+        self.claims.borrow().is_current_for(&self.name)
+    }
+
+    /// Sends a patch for each dirty resource whose status has changed. Only
+    /// the leader sends patches; other controllers discard the dirty set,
+    /// since all resources are reconciled when leadership changes.
+    pub(crate) fn reconcile(&mut self) {
+        let dirty = std::mem::take(&mut self.dirty);
+        if dirty.is_empty() {
+            return;
+        }
+        if !self.is_leader() {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+
+        self.metrics.reconciles.inc_by(dirty.len() as u64);
+        for id in dirty.iter() {
+            self.reconcile_resource(id);
+        }
+    }
+
+    fn reconcile_resource(&self, id: &NamespaceGroupKindName) {
+        let resource = || ResourceId::new(id.namespace.clone(), id.gkn.name.to_string());
+        let patch = match (id.gkn.group.as_ref(), id.gkn.kind.as_ref()) {
+            (POLICY_API_GROUP | GATEWAY_API_GROUP, "HTTPRoute") => {
+                let Some(route) = self.route_refs.get(id) else {
+                    return;
+                };
+                self.make_route_patch(id, route)
+            }
+            (POLICY_API_GROUP, "Server") => {
+                let id = resource();
+                let Some(conditions) = self.servers.get(&id) else {
+                    return;
+                };
+                self.make_server_patch(&id, conditions)
+            }
+            (POLICY_API_GROUP, "AuthorizationPolicy") => {
+                let id = resource();
+                let Some(policy) = self.authorization_policies.get(&id) else {
+                    return;
+                };
+                self.make_authorization_policy_patch(&id, &policy.conditions)
+            }
+            (POLICY_API_GROUP, "MeshTLSAuthentication" | "NetworkAuthentication") => {
+                let Some(authn) = self.authentications.get(id) else {
+                    return;
+                };
+                self.make_authentication_patch(id, authn)
+            }
+            _ => None,
+        };
+        if let Some(patch) = patch {
+            self.send_patch(id.clone(), patch);
+        }
+    }
+
+    fn mark_all_dirty(&mut self) {
+        let servers = self
+            .servers
+            .keys()
+            .map(resource_id::<linkerd_k8s_api::Server>);
+        let policies = self
+            .authorization_policies
+            .keys()
+            .map(resource_id::<linkerd_k8s_api::AuthorizationPolicy>);
+        let authentications = self.authentications.keys().cloned();
+        let routes = self.route_refs.keys().cloned();
+        self.dirty.extend(
+            servers
+                .chain(policies)
+                .chain(authentications)
+                .chain(routes)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    /// Marks the servers and authorization policies in the given namespaces
+    /// as dirty, since their statuses depend on the inbound index.
+    pub(crate) fn mark_namespaces_dirty(&mut self, namespaces: &HashSet<String>) {
+        if namespaces.is_empty() {
+            return;
+        }
+        let servers = self
+            .servers
+            .keys()
+            .filter(|id| namespaces.contains(&id.namespace))
+            .map(resource_id::<linkerd_k8s_api::Server>);
+        let policies = self
+            .authorization_policies
+            .keys()
+            .filter(|id| namespaces.contains(&id.namespace))
+            .map(resource_id::<linkerd_k8s_api::AuthorizationPolicy>);
+        self.dirty
+            .extend(servers.chain(policies).collect::<Vec<_>>());
+    }
+
+    /// Marks the given servers, by namespace and name, as dirty, since the
+    /// workloads they select have changed.
+    pub(crate) fn mark_servers_dirty(&mut self, servers: &HashSet<(String, String)>) {
+        let servers = servers
+            .iter()
+            .map(|(namespace, name)| ResourceId::new(namespace.clone(), name.clone()))
+            .filter(|id| self.servers.contains_key(id))
+            .map(|id| resource_id::<linkerd_k8s_api::Server>(&id))
+            .collect::<Vec<_>>();
+        self.dirty.extend(servers);
+    }
+
+    /// Marks each route, other than `id`, that shares a parent with any of
+    /// `parents` as dirty, since their conflicts may have changed.
+    fn mark_siblings_dirty(
+        &mut self,
+        id: &NamespaceGroupKindName,
+        parents: &[routes::ParentReference],
+    ) {
+        let siblings = self
+            .route_refs
+            .iter()
+            .filter(|(sibling_id, sibling)| {
+                *sibling_id != id
+                    && sibling
+                        .parents
+                        .iter()
+                        .any(|sp| parents.iter().any(|p| p.overlaps(sp)))
+            })
+            .map(|(sibling_id, _)| sibling_id.clone())
+            .collect::<Vec<_>>();
+        self.dirty.extend(siblings);
+    }
+
+    /// Marks the routes that reference `service` as a parent or backend as
+    /// dirty.
+    fn mark_service_routes_dirty(&mut self, service: &ResourceId) {
+        let routes =
+            self.route_refs
+                .iter()
+                .filter(|(_, route)| {
+                    route.parents.iter().any(
+                        |p| matches!(p, routes::ParentReference::Service(id, _) if id == service),
+                    ) || route.backends.iter().any(
+                        |b| matches!(b, routes::BackendReference::Service(id) if id == service),
+                    )
+                })
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>();
+        self.dirty.extend(routes);
+    }
+
+    /// Marks the authorization policies that target `service` as dirty.
+    fn mark_service_policies_dirty(&mut self, service: &ResourceId) {
+        let policies = self
+            .authorization_policies
+            .iter()
+            .filter(|(_, policy)| policy.target_service.as_ref() == Some(service))
+            .map(|(id, _)| resource_id::<linkerd_k8s_api::AuthorizationPolicy>(id))
+            .collect::<Vec<_>>();
+        self.dirty.extend(policies);
+    }
+
+    /// Marks the routes that reference `server` as a parent as dirty.
+    fn mark_server_routes_dirty(&mut self, server: &ResourceId) {
+        let routes = self
+            .route_refs
+            .iter()
+            .filter(|(_, route)| {
+                route
+                    .parents
+                    .iter()
+                    .any(|p| matches!(p, routes::ParentReference::Server(id) if id == server))
+            })
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        self.dirty.extend(routes);
+    }
+
+    /// Marks the authorization policies that require `authentication` as
+    /// dirty.
+    fn mark_authentication_policies_dirty(&mut self, authentication: &NamespaceGroupKindName) {
+        let policies = self
+            .authorization_policies
+            .iter()
+            .filter(|(_, policy)| policy.authentications.contains(authentication))
+            .map(|(id, _)| resource_id::<linkerd_k8s_api::AuthorizationPolicy>(id))
+            .collect::<Vec<_>>();
+        self.dirty.extend(policies);
+    }
+
+    /// Marks the resources that reference an identity as dirty.
+    fn mark_identity_dirty(&mut self, identity: &IdentityRef) {
+        let authentications = self
+            .authentications
+            .iter()
+            .filter(|(_, authn)| authn.identity_refs.contains(identity))
+            .map(|(id, _)| id.clone());
+        let policies = self
+            .authorization_policies
+            .iter()
+            .filter(|(_, policy)| match identity {
+                IdentityRef::ServiceAccount(sa) => policy.service_accounts.contains(sa),
+                IdentityRef::Namespace(_) => false,
+            })
+            .map(|(id, _)| resource_id::<linkerd_k8s_api::AuthorizationPolicy>(id));
+        self.dirty
+            .extend(authentications.chain(policies).collect::<Vec<_>>());
+    }
+
+    fn send_patch(
+        &self,
+        id: NamespaceGroupKindName,
+        patch: k8s_core_api::Patch<serde_json::Value>,
+    ) {
+        match self.updates.try_send(Update {
+            id: id.clone(),
+            patch,
+        }) {
+            Ok(()) => {
+                self.metrics.patch_enqueues.inc();
+            }
+            Err(error) => {
+                self.metrics.patch_channel_full.inc();
+                tracing::error!(%id.namespace, resource = ?id.gkn, %error, "Failed to send patch");
             }
         }
     }
@@ -522,9 +1137,23 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::HttpRoute> for Inde
             },
         };
 
+        let invalid =
+            routes::http::invalid_linkerd_rule(resource.spec.rules.as_deref().unwrap_or_default());
+
         // Create the route parents
         let parents = routes::http::make_parents(&namespace, &resource.spec.inner);
 
+        let hostnames = resource.spec.hostnames.clone().unwrap_or_default();
+        let matches = routes::http::make_matches(
+            resource
+                .spec
+                .rules
+                .iter()
+                .flatten()
+                .map(|rule| rule.matches.clone()),
+        );
+        let creation_timestamp = resource.metadata.creation_timestamp.clone().map(|t| t.0);
+
         // Create the route backends
         let backends = routes::http::make_backends(
             &namespace,
@@ -549,6 +1178,10 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::HttpRoute> for Inde
             parents,
             backends,
             statuses,
+            hostnames,
+            matches,
+            creation_timestamp,
+            invalid,
         };
         self.index_route(id, route);
     }
@@ -562,7 +1195,7 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::HttpRoute> for Inde
                 name: name.into(),
             },
         };
-        self.route_refs.remove(&id);
+        self.delete_route(id);
     }
 
     // Since apply only reindexes a single HTTPRoute at a time, there's no need
@@ -584,9 +1217,23 @@ impl kubert::index::IndexNamespacedResource<k8s_gateway_api::HttpRoute> for Inde
             },
         };
 
+        let invalid =
+            routes::http::invalid_gateway_rule(resource.spec.rules.as_deref().unwrap_or_default());
+
         // Create the route parents
         let parents = routes::http::make_parents(&namespace, &resource.spec.inner);
 
+        let hostnames = resource.spec.hostnames.clone().unwrap_or_default();
+        let matches = routes::http::make_matches(
+            resource
+                .spec
+                .rules
+                .iter()
+                .flatten()
+                .map(|rule| rule.matches.clone()),
+        );
+        let creation_timestamp = resource.metadata.creation_timestamp.clone().map(|t| t.0);
+
         // Create the route backends
         let backends = routes::http::make_backends(
             &namespace,
@@ -611,6 +1258,10 @@ impl kubert::index::IndexNamespacedResource<k8s_gateway_api::HttpRoute> for Inde
             parents,
             backends,
             statuses,
+            hostnames,
+            matches,
+            creation_timestamp,
+            invalid,
         };
         self.index_route(id, route);
     }
@@ -624,7 +1275,7 @@ impl kubert::index::IndexNamespacedResource<k8s_gateway_api::HttpRoute> for Inde
                 name: name.into(),
             },
         };
-        self.route_refs.remove(&id);
+        self.delete_route(id);
     }
 
     // Since apply only reindexes a single HTTPRoute at a time, there's no need
@@ -636,32 +1287,269 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::Server> for Index {
         let namespace = resource.namespace().expect("Server must have a namespace");
         let name = resource.name_unchecked();
         let id = ResourceId::new(namespace, name);
+        let conditions = resource
+            .status
+            .map(|status| status.conditions)
+            .unwrap_or_default();
+
+        // Routes that target the server may now be accepted. Policies that
+        // target the server are reconciled once the inbound index has been
+        // updated.
+        self.mark_server_routes_dirty(&id);
+        self.servers.insert(id.clone(), conditions);
+        self.dirty
+            .insert(resource_id::<linkerd_k8s_api::Server>(&id));
+        self.reconcile();
+    }
 
-        self.servers.insert(id);
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
 
-        // If we're not the leader, skip reconciling the cluster.
-        if !self.claims.borrow().is_current_for(&self.name) {
-            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+        if self.servers.remove(&id).is_none() {
             return;
         }
+
+        // Routes that target the server may no longer be accepted.
+        self.mark_server_routes_dirty(&id);
+        self.reconcile();
+    }
+
+    // Since apply only reindexes a single Server at a time, there's no need
+    // to handle resets specially.
+}
+
+impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::AuthorizationPolicy> for Index {
+    fn apply(&mut self, resource: linkerd_k8s_api::AuthorizationPolicy) {
+        let namespace = resource
+            .namespace()
+            .expect("AuthorizationPolicy must have a namespace");
+        let name = resource.name_unchecked();
+        let authentications = resource
+            .spec
+            .required_authentication_refs
+            .iter()
+            .filter(|tgt| {
+                tgt.targets_kind::<linkerd_k8s_api::MeshTLSAuthentication>()
+                    || tgt.targets_kind::<linkerd_k8s_api::NetworkAuthentication>()
+            })
+            .map(|tgt| NamespaceGroupKindName {
+                namespace: tgt.namespace.clone().unwrap_or_else(|| namespace.clone()),
+                gkn: GroupKindName {
+                    group: POLICY_API_GROUP.into(),
+                    kind: if tgt.targets_kind::<linkerd_k8s_api::MeshTLSAuthentication>() {
+                        linkerd_k8s_api::MeshTLSAuthentication::kind(&())
+                    } else {
+                        linkerd_k8s_api::NetworkAuthentication::kind(&())
+                    },
+                    name: tgt.name.clone().into(),
+                },
+            })
+            .collect();
+        let service_accounts = resource
+            .spec
+            .required_authentication_refs
+            .iter()
+            .filter(|tgt| tgt.targets_kind::<k8s_core_api::ServiceAccount>())
+            .map(|tgt| {
+                ResourceId::new(
+                    tgt.namespace.clone().unwrap_or_else(|| namespace.clone()),
+                    tgt.name.clone(),
+                )
+            })
+            .collect();
+        let target_service = resource
+            .spec
+            .target_ref
+            .targets_kind::<k8s_core_api::Service>()
+            .then(|| ResourceId::new(namespace.clone(), resource.spec.target_ref.name.clone()));
+        let id = ResourceId::new(namespace, name);
+        let conditions = resource
+            .status
+            .map(|status| status.conditions)
+            .unwrap_or_default();
+
+        let policy = AuthorizationPolicyRef {
+            authentications,
+            service_accounts,
+            target_service,
+            conditions,
+        };
+        if let Some(prior) = self.authorization_policies.get(&id) {
+            if *prior == policy {
+                return;
+            }
+            self.dirty.extend(prior.authentications.clone());
+        }
+
+        // The policy may change whether its authentications are referenced.
+        self.dirty.extend(policy.authentications.clone());
+        self.authorization_policies.insert(id.clone(), policy);
+        self.dirty
+            .insert(resource_id::<linkerd_k8s_api::AuthorizationPolicy>(&id));
         self.reconcile();
     }
 
     fn delete(&mut self, namespace: String, name: String) {
         let id = ResourceId::new(namespace, name);
 
-        self.servers.remove(&id);
+        let Some(policy) = self.authorization_policies.remove(&id) else {
+            return;
+        };
+
+        // The policy's authentications may no longer be referenced. Servers
+        // that the policy authorized are reconciled once the inbound index
+        // has been updated.
+        self.dirty.extend(policy.authentications);
+        self.reconcile();
+    }
 
-        // If we're not the leader, skip reconciling the cluster.
-        if !self.claims.borrow().is_current_for(&self.name) {
-            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+    // Since apply only reindexes a single AuthorizationPolicy at a time,
+    // there's no need to handle resets specially.
+}
+
+impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::MeshTLSAuthentication> for Index {
+    fn apply(&mut self, resource: linkerd_k8s_api::MeshTLSAuthentication) {
+        let namespace = resource
+            .namespace()
+            .expect("MeshTLSAuthentication must have a namespace");
+        let name = resource.name_unchecked();
+        let identity_refs = resource
+            .spec
+            .identity_refs
+            .iter()
+            .flatten()
+            .filter_map(|tgt| {
+                if tgt.targets_kind::<k8s_core_api::ServiceAccount>() {
+                    let ns = tgt.namespace.clone().unwrap_or_else(|| namespace.clone());
+                    Some(IdentityRef::ServiceAccount(ResourceId::new(
+                        ns,
+                        tgt.name.clone(),
+                    )))
+                } else if tgt.targets_kind::<k8s_core_api::Namespace>() {
+                    Some(IdentityRef::Namespace(tgt.name.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let (policies, conditions) = resource
+            .status
+            .map(|status| (status.authorization_policies, status.conditions))
+            .unwrap_or_default();
+
+        let id = resource_id::<linkerd_k8s_api::MeshTLSAuthentication>(&ResourceId::new(
+            namespace, name,
+        ));
+        self.index_authentication(
+            id,
+            AuthenticationRef {
+                identity_refs,
+                policies,
+                conditions,
+            },
+        );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = resource_id::<linkerd_k8s_api::MeshTLSAuthentication>(&ResourceId::new(
+            namespace, name,
+        ));
+        self.delete_authentication(&id);
+    }
+
+    // Since apply only reindexes a single MeshTLSAuthentication at a time,
+    // there's no need to handle resets specially.
+}
+
+impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::NetworkAuthentication> for Index {
+    fn apply(&mut self, resource: linkerd_k8s_api::NetworkAuthentication) {
+        let namespace = resource
+            .namespace()
+            .expect("NetworkAuthentication must have a namespace");
+        let name = resource.name_unchecked();
+        let (policies, conditions) = resource
+            .status
+            .map(|status| (status.authorization_policies, status.conditions))
+            .unwrap_or_default();
+
+        let id = resource_id::<linkerd_k8s_api::NetworkAuthentication>(&ResourceId::new(
+            namespace, name,
+        ));
+        self.index_authentication(
+            id,
+            AuthenticationRef {
+                identity_refs: vec![],
+                policies,
+                conditions,
+            },
+        );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = resource_id::<linkerd_k8s_api::NetworkAuthentication>(&ResourceId::new(
+            namespace, name,
+        ));
+        self.delete_authentication(&id);
+    }
+
+    // Since apply only reindexes a single NetworkAuthentication at a time,
+    // there's no need to handle resets specially.
+}
+
+impl kubert::index::IndexClusterResource<k8s_core_api::Namespace> for Index {
+    fn apply(&mut self, resource: k8s_core_api::Namespace) {
+        // Only authentications that reference a new namespace may change.
+        let name = resource.name_unchecked();
+        if !self.namespaces.insert(name.clone()) {
             return;
         }
+
+        self.mark_identity_dirty(&IdentityRef::Namespace(name));
         self.reconcile();
     }
 
-    // Since apply only reindexes a single Server at a time, there's no need
-    // to handle resets specially.
+    fn delete(&mut self, name: String) {
+        if !self.namespaces.remove(&name) {
+            return;
+        }
+
+        self.mark_identity_dirty(&IdentityRef::Namespace(name));
+        self.reconcile();
+    }
+
+    // Since apply only reindexes a single Namespace at a time, there's no
+    // need to handle resets specially.
+}
+
+impl kubert::index::IndexNamespacedResource<k8s_core_api::ServiceAccount> for Index {
+    fn apply(&mut self, resource: k8s_core_api::ServiceAccount) {
+        let namespace = resource
+            .namespace()
+            .expect("ServiceAccount must have a namespace");
+        let name = resource.name_unchecked();
+
+        // Only resources that reference a new service account may change.
+        let id = ResourceId::new(namespace, name);
+        if !self.service_accounts.insert(id.clone()) {
+            return;
+        }
+
+        self.mark_identity_dirty(&IdentityRef::ServiceAccount(id));
+        self.reconcile();
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
+        if !self.service_accounts.remove(&id) {
+            return;
+        }
+
+        self.mark_identity_dirty(&IdentityRef::ServiceAccount(id));
+        self.reconcile();
+    }
+
+    // Since apply only reindexes a single ServiceAccount at a time, there's no
+    // need to handle resets specially.
 }
 
 impl kubert::index::IndexNamespacedResource<k8s_core_api::Service> for Index {
@@ -670,26 +1558,24 @@ impl kubert::index::IndexNamespacedResource<k8s_core_api::Service> for Index {
         let name = resource.name_unchecked();
         let id = ResourceId::new(namespace, name);
 
-        self.services.insert(id, resource.into());
+        self.services.insert(id.clone(), resource.into());
 
-        // If we're not the leader, skip reconciling the cluster.
-        if !self.claims.borrow().is_current_for(&self.name) {
-            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
-            return;
-        }
+        // Routes and authorization policies that reference the service may
+        // now resolve or attach.
+        self.mark_service_routes_dirty(&id);
+        self.mark_service_policies_dirty(&id);
         self.reconcile();
     }
 
     fn delete(&mut self, namespace: String, name: String) {
         let id = ResourceId::new(namespace, name);
 
-        self.services.remove(&id);
-
-        // If we're not the leader, skip reconciling the cluster.
-        if !self.claims.borrow().is_current_for(&self.name) {
-            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+        if self.services.remove(&id).is_none() {
             return;
         }
+
+        self.mark_service_routes_dirty(&id);
+        self.mark_service_policies_dirty(&id);
         self.reconcile();
     }
 
@@ -699,55 +1585,92 @@ impl kubert::index::IndexNamespacedResource<k8s_core_api::Service> for Index {
 
 impl Index {
     fn index_route(&mut self, id: NamespaceGroupKindName, route: RouteRef) {
+        let prior_parents = self
+            .route_refs
+            .get(&id)
+            .map(|r| r.parents.clone())
+            .unwrap_or_default();
+
         // Insert into the index; if the route is already in the index, and it hasn't
         // changed, skip creating a patch.
         if !self.update_route(id.clone(), &route) {
             return;
         }
 
-        // If we're not the leader, skip creating a patch and sending an
-        // update to the Controller.
-        if !self.claims.borrow().is_current_for(&self.name) {
-            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+        self.dirty.insert(id.clone());
+        self.reconcile();
+
+        // The route's matches may conflict (or no longer conflict) with
+        // those of routes that share its parents.
+        self.mark_siblings_dirty(&id, &prior_parents);
+        self.mark_siblings_dirty(&id, &route.parents);
+        self.reconcile();
+    }
+
+    fn index_authentication(&mut self, id: NamespaceGroupKindName, authn: AuthenticationRef) {
+        if self.authentications.get(&id) == Some(&authn) {
             return;
         }
+        self.authentications.insert(id.clone(), authn);
 
-        // Create a patch for the route and send it to the Controller so
-        // that it is applied.
-        if let Some(patch) = self.make_route_patch(&id, &route) {
-            match self.updates.try_send(Update {
-                id: id.clone(),
-                patch,
-            }) {
-                Ok(()) => {
-                    self.metrics.patch_enqueues.inc();
-                }
-                Err(error) => {
-                    self.metrics.patch_channel_full.inc();
-                    tracing::error!(%id.namespace, route = ?id.gkn, %error, "Failed to send route patch");
-                }
-            }
+        // Policies that require the authentication may now resolve.
+        self.mark_authentication_policies_dirty(&id);
+        self.dirty.insert(id);
+        self.reconcile();
+    }
+
+    fn delete_authentication(&mut self, id: &NamespaceGroupKindName) {
+        if self.authentications.remove(id).is_none() {
+            return;
         }
+
+        // Policies that require the authentication no longer resolve.
+        self.mark_authentication_policies_dirty(id);
+        self.reconcile();
+    }
+
+    fn delete_route(&mut self, id: NamespaceGroupKindName) {
+        let Some(route) = self.route_refs.remove(&id) else {
+            return;
+        };
+
+        // Routes that conflicted with the deleted route may no longer conflict.
+        self.mark_siblings_dirty(&id, &route.parents);
+        self.reconcile();
     }
 }
 
-pub(crate) fn make_patch<RouteStatus>(
-    route_id: &NamespaceGroupKindName,
-    status: RouteStatus,
+fn resource_id<K>(id: &ResourceId) -> NamespaceGroupKindName
+where
+    K: Resource<DynamicType = ()>,
+{
+    NamespaceGroupKindName {
+        namespace: id.namespace.clone(),
+        gkn: GroupKindName {
+            group: K::group(&()),
+            kind: K::kind(&()),
+            name: id.name.clone().into(),
+        },
+    }
+}
+
+pub(crate) fn make_patch<Status>(
+    id: &NamespaceGroupKindName,
+    status: Status,
 ) -> Option<k8s_core_api::Patch<serde_json::Value>>
 where
-    RouteStatus: serde::Serialize,
+    Status: serde::Serialize,
 {
-    match route_id.api_version() {
+    match id.api_version() {
         Err(error) => {
-            tracing::error!(error = %error, "failed to create patch for route");
+            tracing::error!(error = %error, "failed to create patch for resource");
             None
         }
         Ok(api_version) => {
             let patch = serde_json::json!({
                 "apiVersion": api_version,
-                    "kind": &route_id.gkn.kind,
-                    "name": &route_id.gkn.name,
+                    "kind": &id.gkn.kind,
+                    "name": &id.gkn.name,
                     "status": status,
             });
 
@@ -775,6 +1698,31 @@ fn no_matching_parent() -> k8s_core_api::Condition {
     }
 }
 
+fn conflicted(winner: &NamespaceGroupKindName) -> k8s_core_api::Condition {
+    k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(now()),
+        message: format!(
+            "{} {}/{} takes precedence for one or more matches",
+            winner.gkn.kind, winner.namespace, winner.gkn.name
+        ),
+        observed_generation: None,
+        reason: reasons::CONFLICTED.to_string(),
+        status: cond_statuses::STATUS_TRUE.to_string(),
+        type_: conditions::CONFLICTED.to_string(),
+    }
+}
+
+fn unsupported_value(message: &str) -> k8s_core_api::Condition {
+    k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(now()),
+        message: message.to_string(),
+        observed_generation: None,
+        reason: reasons::UNSUPPORTED_VALUE.to_string(),
+        status: cond_statuses::STATUS_FALSE.to_string(),
+        type_: conditions::ACCEPTED.to_string(),
+    }
+}
+
 fn accepted() -> k8s_core_api::Condition {
     k8s_core_api::Condition {
         last_transition_time: k8s_core_api::Time(now()),
@@ -819,7 +1767,207 @@ fn invalid_backend_kind() -> k8s_core_api::Condition {
     }
 }
 
-fn eq_time_insensitive(
+/// Describes a server's conflicts, the workloads it selects, and the
+/// authorizations that apply to it.
+fn server_conditions(summary: &inbound::ServerSummary) -> Vec<k8s_core_api::Condition> {
+    let accepted = if summary.conflicts.is_empty() {
+        accepted()
+    } else {
+        let conflicts = summary
+            .conflicts
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        k8s_core_api::Condition {
+            last_transition_time: k8s_core_api::Time(now()),
+            message: format!(
+                "the port is already served on one or more workloads by Server {}",
+                conflicts.join(", ")
+            ),
+            observed_generation: None,
+            reason: reasons::CONFLICTED.to_string(),
+            status: cond_statuses::STATUS_FALSE.to_string(),
+            type_: conditions::ACCEPTED.to_string(),
+        }
+    };
+
+    let selected = summary.pods + summary.external_workloads;
+    let (status, reason, message) = if selected == 0 {
+        (
+            cond_statuses::STATUS_FALSE,
+            reasons::NO_MATCHING_WORKLOADS,
+            "selects no pods or external workloads".to_string(),
+        )
+    } else if summary.unresolved_port > 0 {
+        (
+            cond_statuses::STATUS_FALSE,
+            reasons::PORT_NOT_FOUND,
+            format!(
+                "the port is not declared by {} of {} selected workloads",
+                summary.unresolved_port, selected
+            ),
+        )
+    } else {
+        (
+            cond_statuses::STATUS_TRUE,
+            reasons::RESOLVED_REFS,
+            format!(
+                "selects {} pods and {} external workloads",
+                summary.pods, summary.external_workloads
+            ),
+        )
+    };
+    let resolved_refs = k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(now()),
+        message,
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: status.to_string(),
+        type_: conditions::RESOLVED_REFS.to_string(),
+    };
+
+    let authorized = if summary.authorizations == 0 {
+        k8s_core_api::Condition {
+            last_transition_time: k8s_core_api::Time(now()),
+            message: "no authorizations apply, so all traffic is denied".to_string(),
+            observed_generation: None,
+            reason: reasons::NO_AUTHORIZATIONS.to_string(),
+            status: cond_statuses::STATUS_FALSE.to_string(),
+            type_: conditions::AUTHORIZED.to_string(),
+        }
+    } else {
+        k8s_core_api::Condition {
+            last_transition_time: k8s_core_api::Time(now()),
+            message: format!("{} authorizations apply", summary.authorizations),
+            observed_generation: None,
+            reason: reasons::AUTHORIZED.to_string(),
+            status: cond_statuses::STATUS_TRUE.to_string(),
+            type_: conditions::AUTHORIZED.to_string(),
+        }
+    };
+
+    vec![accepted, resolved_refs, authorized]
+}
+
+/// Describes whether an authorization policy's target and required
+/// authentications resolve.
+fn authorization_policy_conditions(
+    summary: &inbound::AuthorizationPolicySummary,
+    service_accounts: &HashSet<ResourceId>,
+) -> Vec<k8s_core_api::Condition> {
+    let (status, reason, message) = if let Some(target) = &summary.missing_target {
+        (
+            cond_statuses::STATUS_FALSE,
+            reasons::TARGET_NOT_FOUND,
+            format!("{target} not found"),
+        )
+    } else if let Some(error) = &summary.error {
+        (cond_statuses::STATUS_FALSE, reasons::INVALID, error.clone())
+    } else {
+        (
+            cond_statuses::STATUS_TRUE,
+            conditions::ACCEPTED,
+            "".to_string(),
+        )
+    };
+    let accepted = k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(now()),
+        message,
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: status.to_string(),
+        type_: conditions::ACCEPTED.to_string(),
+    };
+
+    let missing = summary
+        .missing_authentications
+        .iter()
+        .cloned()
+        .chain(
+            summary
+                .service_accounts
+                .iter()
+                .filter(|(ns, name)| {
+                    !service_accounts.contains(&ResourceId::new(ns.clone(), name.clone()))
+                })
+                .map(|(ns, name)| format!("ServiceAccount {ns}/{name}")),
+        )
+        .collect::<Vec<_>>();
+    let resolved_refs = if missing.is_empty() {
+        resolved_refs()
+    } else {
+        k8s_core_api::Condition {
+            last_transition_time: k8s_core_api::Time(now()),
+            message: format!("{} not found", missing.join(", ")),
+            observed_generation: None,
+            reason: reasons::AUTHENTICATION_NOT_FOUND.to_string(),
+            status: cond_statuses::STATUS_FALSE.to_string(),
+            type_: conditions::RESOLVED_REFS.to_string(),
+        }
+    };
+
+    vec![accepted, resolved_refs]
+}
+
+/// Describes whether each of a `MeshTLSAuthentication`'s identity refs
+/// resolves.
+fn identity_refs_condition(
+    identity_refs: &[IdentityRef],
+    service_accounts: &HashSet<ResourceId>,
+    namespaces: &HashSet<String>,
+) -> k8s_core_api::Condition {
+    let missing = identity_refs
+        .iter()
+        .filter_map(|identity_ref| match identity_ref {
+            IdentityRef::ServiceAccount(id) if !service_accounts.contains(id) => {
+                Some(format!("ServiceAccount {}/{}", id.namespace, id.name))
+            }
+            IdentityRef::Namespace(name) if !namespaces.contains(name) => {
+                Some(format!("Namespace {name}"))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return resolved_refs();
+    }
+
+    k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(now()),
+        message: format!("{} not found", missing.join(", ")),
+        observed_generation: None,
+        reason: reasons::IDENTITY_NOT_FOUND.to_string(),
+        status: cond_statuses::STATUS_FALSE.to_string(),
+        type_: conditions::RESOLVED_REFS.to_string(),
+    }
+}
+
+/// Describes whether any `AuthorizationPolicy` requires an authentication.
+fn referenced_condition(policies: usize) -> k8s_core_api::Condition {
+    let (status, reason, message) = if policies == 0 {
+        (
+            cond_statuses::STATUS_FALSE,
+            reasons::UNREFERENCED,
+            "no AuthorizationPolicy requires this authentication".to_string(),
+        )
+    } else {
+        (
+            cond_statuses::STATUS_TRUE,
+            reasons::REFERENCED,
+            format!("required by {policies} AuthorizationPolicies"),
+        )
+    };
+    k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(now()),
+        message,
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: status.to_string(),
+        type_: conditions::REFERENCED.to_string(),
+    }
+}
+
+fn eq_parent_statuses_time_insensitive(
     left: &[k8s_gateway_api::RouteParentStatus],
     right: &[k8s_gateway_api::RouteParentStatus],
 ) -> bool {
@@ -829,13 +1977,20 @@ fn eq_time_insensitive(
     left.iter().zip(right.iter()).all(|(l, r)| {
         l.parent_ref == r.parent_ref
             && l.controller_name == r.controller_name
-            && l.conditions.len() == r.conditions.len()
-            && l.conditions.iter().zip(r.conditions.iter()).all(|(l, r)| {
-                l.message == r.message
-                    && l.observed_generation == r.observed_generation
-                    && l.reason == r.reason
-                    && l.status == r.status
-                    && l.type_ == r.type_
-            })
+            && eq_conditions_time_insensitive(&l.conditions, &r.conditions)
     })
 }
+
+fn eq_conditions_time_insensitive(
+    left: &[k8s_core_api::Condition],
+    right: &[k8s_core_api::Condition],
+) -> bool {
+    left.len() == right.len()
+        && left.iter().zip(right.iter()).all(|(l, r)| {
+            l.message == r.message
+                && l.observed_generation == r.observed_generation
+                && l.reason == r.reason
+                && l.status == r.status
+                && l.type_ == r.type_
+        })
+}
@@ -1,4 +1,5 @@
 mod index;
+mod queue;
 mod resource_id;
 mod routes;
 mod service;
@@ -6,4 +7,7 @@ mod service;
 #[cfg(test)]
 mod tests;
 
-pub use self::index::{Controller, ControllerMetrics, Index, IndexMetrics};
+pub use self::{
+    index::{Controller, ControllerMetrics, Index, IndexMetrics},
+    routes::HttpRouteMatches,
+};
@@ -0,0 +1,201 @@
+use crate::{index::Update, resource_id::NamespaceGroupKindName};
+use ahash::AHashMap as HashMap;
+use linkerd_policy_controller_k8s_api as k8s_core_api;
+use std::collections::{hash_map::Entry, BTreeMap};
+use tokio::time::{Duration, Instant};
+
+const MIN_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Holds the status patches that the controller has yet to apply, at most one
+/// per resource.
+///
+/// A patch received for a resource that already has a pending patch replaces
+/// it, so that only the latest status is written. A patch that fails is
+/// rescheduled with an exponential backoff, which is preserved when the patch
+/// is replaced.
+#[derive(Debug, Default)]
+pub(crate) struct PatchQueue {
+    pending: HashMap<NamespaceGroupKindName, Pending>,
+
+    /// Orders the pending resources by when they should next be patched. The
+    /// sequence number breaks ties in favor of the earliest scheduled.
+    schedule: BTreeMap<(Instant, u64), NamespaceGroupKindName>,
+    next_seq: u64,
+}
+
+#[derive(Debug)]
+struct Pending {
+    patch: k8s_core_api::Patch<serde_json::Value>,
+    failures: u32,
+}
+
+/// A patch that is ready to be applied.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Ready {
+    pub id: NamespaceGroupKindName,
+    pub patch: k8s_core_api::Patch<serde_json::Value>,
+
+    /// The number of times this resource has failed to be patched.
+    pub failures: u32,
+}
+
+// === impl PatchQueue ===
+
+impl PatchQueue {
+    pub(crate) fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.pending.clear();
+        self.schedule.clear();
+    }
+
+    /// Enqueues an update to be applied immediately, unless the resource is
+    /// backing off. Returns true if the update replaced a pending patch.
+    pub(crate) fn push(&mut self, Update { id, patch }: Update, now: Instant) -> bool {
+        match self.pending.entry(id) {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().patch = patch;
+                true
+            }
+            Entry::Vacant(entry) => {
+                let key = (now, self.next_seq);
+                self.next_seq += 1;
+                self.schedule.insert(key, entry.key().clone());
+                entry.insert(Pending { patch, failures: 0 });
+                false
+            }
+        }
+    }
+
+    /// Reschedules a patch that failed to apply. Returns the backoff before
+    /// it is retried.
+    pub(crate) fn retry(&mut self, ready: Ready, now: Instant) -> Duration {
+        let Ready {
+            id,
+            patch,
+            failures,
+        } = ready;
+        let failures = failures.saturating_add(1);
+        let backoff = backoff(failures);
+
+        // A newer patch is never pending since patches are applied one at a
+        // time, but if one is, it supersedes the failed patch.
+        if let Some(pending) = self.pending.get_mut(&id) {
+            pending.failures = failures;
+            return backoff;
+        }
+
+        let key = (now + backoff, self.next_seq);
+        self.next_seq += 1;
+        self.schedule.insert(key, id.clone());
+        self.pending.insert(id, Pending { patch, failures });
+        backoff
+    }
+
+    /// Returns the time at which the next patch is ready, if any.
+    pub(crate) fn next_ready_at(&self) -> Option<Instant> {
+        self.schedule.first_key_value().map(|((at, _), _)| *at)
+    }
+
+    /// Dequeues the next patch that is ready at `now`.
+    pub(crate) fn pop_ready(&mut self, now: Instant) -> Option<Ready> {
+        let entry = self.schedule.first_entry()?;
+        if entry.key().0 > now {
+            return None;
+        }
+        let id = entry.remove();
+        let Pending {
+            patch, failures, ..
+        } = self
+            .pending
+            .remove(&id)
+            .expect("scheduled patches must be pending");
+        Some(Ready {
+            id,
+            patch,
+            failures,
+        })
+    }
+}
+
+fn backoff(failures: u32) -> Duration {
+    let exp = failures.saturating_sub(1).min(16);
+    MIN_BACKOFF.saturating_mul(1 << exp).min(MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd_policy_controller_core::routes::GroupKindName;
+
+    fn id(name: &str) -> NamespaceGroupKindName {
+        NamespaceGroupKindName {
+            namespace: "ns-0".to_string(),
+            gkn: GroupKindName {
+                group: "policy.linkerd.io".into(),
+                kind: "Server".into(),
+                name: name.to_string().into(),
+            },
+        }
+    }
+
+    fn update(name: &str, value: u32) -> Update {
+        Update {
+            id: id(name),
+            patch: k8s_core_api::Patch::Merge(serde_json::json!({ "value": value })),
+        }
+    }
+
+    #[test]
+    fn coalesces_pending_patches() {
+        let now = Instant::now();
+        let mut queue = PatchQueue::default();
+
+        assert!(!queue.push(update("srv-0", 0), now));
+        assert!(!queue.push(update("srv-1", 0), now));
+        assert!(queue.push(update("srv-0", 1), now));
+        assert_eq!(queue.len(), 2);
+
+        // Patches are dequeued in the order they were first enqueued, with
+        // the latest contents.
+        let ready = queue.pop_ready(now).expect("srv-0 must be ready");
+        assert_eq!(ready.id, id("srv-0"));
+        assert_eq!(ready.patch, update("srv-0", 1).patch);
+        assert_eq!(ready.failures, 0);
+        assert_eq!(queue.pop_ready(now).unwrap().id, id("srv-1"));
+        assert_eq!(queue.pop_ready(now), None);
+        assert_eq!(queue.next_ready_at(), None);
+    }
+
+    #[test]
+    fn retries_with_backoff() {
+        let now = Instant::now();
+        let mut queue = PatchQueue::default();
+        queue.push(update("srv-0", 0), now);
+
+        let ready = queue.pop_ready(now).unwrap();
+        assert_eq!(queue.retry(ready, now), MIN_BACKOFF);
+        assert_eq!(queue.next_ready_at(), Some(now + MIN_BACKOFF));
+        assert_eq!(queue.pop_ready(now), None);
+
+        // A new patch replaces the failed one, but does not reset its backoff.
+        assert!(queue.push(update("srv-0", 1), now));
+        assert_eq!(queue.pop_ready(now), None);
+
+        let now = now + MIN_BACKOFF;
+        let ready = queue.pop_ready(now).expect("srv-0 must be ready");
+        assert_eq!(ready.patch, update("srv-0", 1).patch);
+        assert_eq!(ready.failures, 1);
+        assert_eq!(queue.retry(ready, now), MIN_BACKOFF * 2);
+    }
+
+    #[test]
+    fn backoff_is_bounded() {
+        assert_eq!(backoff(1), MIN_BACKOFF);
+        assert_eq!(backoff(3), MIN_BACKOFF * 4);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+}
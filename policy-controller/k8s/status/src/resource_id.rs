@@ -28,6 +28,16 @@ impl NamespaceGroupKindName {
         match (self.gkn.group.as_ref(), self.gkn.kind.as_ref()) {
             (POLICY_API_GROUP, "HTTPRoute") => Ok(linkerd_k8s_api::HttpRoute::api_version(&())),
             (GATEWAY_API_GROUP, "HTTPRoute") => Ok(k8s_gateway_api::HttpRoute::api_version(&())),
+            (POLICY_API_GROUP, "Server") => Ok(linkerd_k8s_api::Server::api_version(&())),
+            (POLICY_API_GROUP, "AuthorizationPolicy") => {
+                Ok(linkerd_k8s_api::AuthorizationPolicy::api_version(&()))
+            }
+            (POLICY_API_GROUP, "MeshTLSAuthentication") => {
+                Ok(linkerd_k8s_api::MeshTLSAuthentication::api_version(&()))
+            }
+            (POLICY_API_GROUP, "NetworkAuthentication") => {
+                Ok(linkerd_k8s_api::NetworkAuthentication::api_version(&()))
+            }
             (group, kind) => {
                 anyhow::bail!("unknown group + kind combination: ({}, {})", group, kind)
             }
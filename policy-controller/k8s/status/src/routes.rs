@@ -25,7 +25,61 @@ pub enum BackendReference {
     Unknown,
 }
 
+/// Describes the requests that an HTTPRoute matches on each of its parents,
+/// so that routes with identical matches can be detected when they are
+/// admitted.
+pub struct HttpRouteMatches {
+    parents: Vec<ParentReference>,
+    hostnames: Vec<String>,
+    matches: Vec<k8s_gateway_api::HttpRouteMatch>,
+}
+
+impl HttpRouteMatches {
+    pub fn new(
+        namespace: &str,
+        route: &k8s_gateway_api::CommonRouteSpec,
+        hostnames: Option<&[String]>,
+        rules: impl Iterator<Item = Option<Vec<k8s_gateway_api::HttpRouteMatch>>>,
+    ) -> Self {
+        Self {
+            parents: http::make_parents(namespace, route),
+            hostnames: hostnames
+                .unwrap_or_default()
+                .iter()
+                .map(|h| h.to_ascii_lowercase())
+                .collect(),
+            matches: http::make_matches(rules),
+        }
+    }
+
+    /// Returns true if both routes have an identical match, for an identical
+    /// hostname, on a common parent.
+    pub fn duplicates(&self, other: &Self) -> bool {
+        let hostnames = (self.hostnames.is_empty() && other.hostnames.is_empty())
+            || self.hostnames.iter().any(|h| other.hostnames.contains(h));
+        hostnames
+            && self
+                .parents
+                .iter()
+                .any(|p| other.parents.iter().any(|o| p.overlaps(o)))
+            && self.matches.iter().any(|m| other.matches.contains(m))
+    }
+}
+
 impl ParentReference {
+    /// Returns true if both references attach routes to the same parent. A
+    /// Service reference without a port applies to all of the Service's
+    /// ports.
+    pub(crate) fn overlaps(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Server(a), Self::Server(b)) => a == b,
+            (Self::Service(a, a_port), Self::Service(b, b_port)) => {
+                a == b && (a_port.is_none() || b_port.is_none() || a_port == b_port)
+            }
+            _ => false,
+        }
+    }
+
     fn from_parent_ref(
         parent_ref: &k8s_gateway_api::ParentReference,
         default_namespace: &str,
@@ -77,3 +131,25 @@ impl BackendReference {
         }
     }
 }
+
+/// Returns true if a request could match hostnames of both routes. Routes
+/// without hostnames match all hosts.
+pub(crate) fn hostnames_overlap(a: &[String], b: &[String]) -> bool {
+    if a.is_empty() || b.is_empty() {
+        return true;
+    }
+
+    fn matches(pattern: &str, host: &str) -> bool {
+        match pattern.strip_prefix('*') {
+            Some(suffix) => host.len() > suffix.len() && host.ends_with(suffix),
+            None => pattern == host,
+        }
+    }
+
+    a.iter().any(|a| {
+        b.iter().any(|b| {
+            let (a, b) = (a.to_ascii_lowercase(), b.to_ascii_lowercase());
+            matches(&a, &b) || matches(&b, &a)
+        })
+    })
+}
@@ -1,5 +1,6 @@
 use super::{BackendReference, ParentReference};
-use linkerd_policy_controller_k8s_api::gateway as k8s_gateway_api;
+use linkerd_policy_controller_k8s_api::{gateway as k8s_gateway_api, policy as linkerd_k8s_api};
+use linkerd_policy_controller_k8s_index::routes::http as index_http;
 
 pub(crate) fn make_parents(
     namespace: &str,
@@ -13,6 +14,111 @@ pub(crate) fn make_parents(
         .collect()
 }
 
+/// Returns each of a route's matches, with defaults applied and header and
+/// query parameter matches sorted so that equivalent matches compare equal.
+/// A rule without matches matches all requests.
+pub(crate) fn make_matches(
+    rules: impl Iterator<Item = Option<Vec<k8s_gateway_api::HttpRouteMatch>>>,
+) -> Vec<k8s_gateway_api::HttpRouteMatch> {
+    rules
+        .flat_map(|matches| match matches {
+            Some(matches) if !matches.is_empty() => matches,
+            _ => vec![Default::default()],
+        })
+        .map(|m| k8s_gateway_api::HttpRouteMatch {
+            path: m.path.or_else(|| {
+                Some(k8s_gateway_api::HttpPathMatch::PathPrefix {
+                    value: "/".to_string(),
+                })
+            }),
+            headers: m
+                .headers
+                .filter(|hs| !hs.is_empty())
+                .map(normalize_header_matches),
+            query_params: m
+                .query_params
+                .filter(|qs| !qs.is_empty())
+                .map(normalize_query_param_matches),
+            method: m.method.map(|m| m.to_ascii_uppercase()),
+        })
+        .collect()
+}
+
+/// Header names are case-insensitive, and the order in which header matches
+/// are specified is insignificant.
+fn normalize_header_matches(
+    matches: Vec<k8s_gateway_api::HttpHeaderMatch>,
+) -> Vec<k8s_gateway_api::HttpHeaderMatch> {
+    use k8s_gateway_api::HttpHeaderMatch;
+
+    let mut matches = matches
+        .into_iter()
+        .map(|m| match m {
+            HttpHeaderMatch::Exact { name, value } => HttpHeaderMatch::Exact {
+                name: name.to_ascii_lowercase(),
+                value,
+            },
+            HttpHeaderMatch::RegularExpression { name, value } => {
+                HttpHeaderMatch::RegularExpression {
+                    name: name.to_ascii_lowercase(),
+                    value,
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|a, b| header_match_key(a).cmp(&header_match_key(b)));
+    matches.dedup();
+    matches
+}
+
+fn header_match_key(m: &k8s_gateway_api::HttpHeaderMatch) -> (&str, bool, &str) {
+    match m {
+        k8s_gateway_api::HttpHeaderMatch::Exact { name, value } => (name, false, value),
+        k8s_gateway_api::HttpHeaderMatch::RegularExpression { name, value } => (name, true, value),
+    }
+}
+
+/// The order in which query parameter matches are specified is
+/// insignificant.
+fn normalize_query_param_matches(
+    mut matches: Vec<k8s_gateway_api::HttpQueryParamMatch>,
+) -> Vec<k8s_gateway_api::HttpQueryParamMatch> {
+    matches.sort_by(|a, b| query_param_match_key(a).cmp(&query_param_match_key(b)));
+    matches.dedup();
+    matches
+}
+
+fn query_param_match_key(m: &k8s_gateway_api::HttpQueryParamMatch) -> (&str, bool, &str) {
+    match m {
+        k8s_gateway_api::HttpQueryParamMatch::Exact { name, value } => (name, false, value),
+        k8s_gateway_api::HttpQueryParamMatch::RegularExpression { name, value } => {
+            (name, true, value)
+        }
+    }
+}
+
+/// Describes the first rule that the indexes cannot convert, if any. Such
+/// routes are not served to proxies.
+pub(crate) fn invalid_linkerd_rule(
+    rules: &[linkerd_k8s_api::httproute::HttpRouteRule],
+) -> Option<String> {
+    rules.iter().enumerate().find_map(|(idx, rule)| {
+        index_http::linkerd_rule(rule.matches.clone(), rule.filters.clone())
+            .err()
+            .map(|error| format!("spec.rules[{idx}]: {error}"))
+    })
+}
+
+/// Describes the first rule that the indexes cannot convert, if any. Such
+/// routes are not served to proxies.
+pub(crate) fn invalid_gateway_rule(rules: &[k8s_gateway_api::HttpRouteRule]) -> Option<String> {
+    rules.iter().enumerate().find_map(|(idx, rule)| {
+        index_http::gateway_rule(rule.matches.clone(), rule.filters.clone())
+            .err()
+            .map(|error| format!("spec.rules[{idx}]: {error}"))
+    })
+}
+
 pub(crate) fn make_backends(
     namespace: &str,
     backends: impl Iterator<Item = k8s_gateway_api::HttpBackendRef>,
@@ -189,4 +295,38 @@ mod test {
         let unknown = iter.next().unwrap();
         assert!(matches!(unknown, BackendReference::Unknown))
     }
+
+    #[test]
+    fn matches_are_normalized() {
+        let header = |name: &str, value: &str| k8s_gateway_api::HttpHeaderMatch::Exact {
+            name: name.to_string(),
+            value: value.to_string(),
+        };
+        let param = |name: &str, value: &str| k8s_gateway_api::HttpQueryParamMatch::Exact {
+            name: name.to_string(),
+            value: value.to_string(),
+        };
+
+        let matches = make_matches(
+            [
+                Some(vec![k8s_gateway_api::HttpRouteMatch {
+                    headers: Some(vec![header("X-Foo", "1"), header("x-bar", "2")]),
+                    query_params: Some(vec![param("b", "2"), param("a", "1")]),
+                    method: Some("get".to_string()),
+                    ..Default::default()
+                }]),
+                Some(vec![k8s_gateway_api::HttpRouteMatch {
+                    path: Some(k8s_gateway_api::HttpPathMatch::PathPrefix {
+                        value: "/".to_string(),
+                    }),
+                    headers: Some(vec![header("x-bar", "2"), header("x-foo", "1")]),
+                    query_params: Some(vec![param("a", "1"), param("b", "2"), param("a", "1")]),
+                    method: Some("GET".to_string()),
+                }]),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0], matches[1]);
+    }
 }
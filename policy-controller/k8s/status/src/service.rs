@@ -4,17 +4,20 @@ use linkerd_policy_controller_k8s_api as k8s_core_api;
 pub(crate) struct Service {
     cluster_ip: Option<String>,
     type_: Option<String>,
+    external_name: Option<String>,
 }
 
 impl Service {
     pub(crate) fn valid_parent_service(&self) -> bool {
-        let cluster_ip = self
-            .cluster_ip
+        // ExternalName Services have no cluster IP, but their external name
+        // is discoverable.
+        if self.type_.as_deref() == Some("ExternalName") {
+            return self.external_name.as_ref().is_some_and(|n| !n.is_empty());
+        }
+        self.cluster_ip
             .as_ref()
             .filter(|cip| !cip.eq_ignore_ascii_case("none"))
-            .is_some();
-        let external_name = self.type_.as_deref() == Some("ExternalName");
-        cluster_ip && !external_name
+            .is_some()
     }
 }
 
@@ -24,6 +27,7 @@ impl From<k8s_core_api::Service> for Service {
             .map(|spec| Self {
                 cluster_ip: spec.cluster_ip,
                 type_: spec.type_,
+                external_name: spec.external_name,
             })
             .unwrap_or_default()
     }
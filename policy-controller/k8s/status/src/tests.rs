@@ -1 +1,35 @@
+mod authentications;
+mod authorization_policies;
 mod routes;
+mod servers;
+
+use chrono::{DateTime, Utc};
+use linkerd_policy_controller_k8s_api as k8s_core_api;
+use linkerd_policy_controller_k8s_index::{inbound, ClusterInfo, DefaultPolicy};
+
+fn make_inbound_index() -> inbound::SharedIndex {
+    inbound::Index::shared(
+        ClusterInfo {
+            networks: vec!["10.0.0.0/8".parse().unwrap()],
+            control_plane_ns: "linkerd".to_string(),
+            dns_domain: "cluster.local".to_string(),
+            identity_domain: "cluster.local".to_string(),
+            default_policy: DefaultPolicy::Deny,
+            default_detect_timeout: std::time::Duration::from_secs(10),
+            default_opaque_ports: Default::default(),
+            probe_networks: vec![],
+        },
+        Default::default(),
+    )
+}
+
+fn condition(type_: &str, status: &str, reason: &str, message: &str) -> k8s_core_api::Condition {
+    k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(DateTime::<Utc>::MIN_UTC),
+        message: message.to_string(),
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: status.to_string(),
+        type_: type_.to_string(),
+    }
+}
@@ -0,0 +1,122 @@
+use super::condition;
+use crate::{index::POLICY_API_GROUP, resource_id::NamespaceGroupKindName, Index, IndexMetrics};
+use chrono::{DateTime, Utc};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::routes::GroupKindName;
+use linkerd_policy_controller_k8s_api::{self as k8s_core_api, policy as linkerd_k8s_api};
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+
+#[test]
+fn meshtls_authentication_refs() {
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    }));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        super::make_inbound_index(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    kubert::index::IndexClusterResource::apply(
+        &mut *index.write(),
+        k8s_core_api::Namespace {
+            metadata: k8s_core_api::ObjectMeta {
+                name: Some("ns-1".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    assert!(updates_rx.try_recv().is_err());
+
+    index.write().apply(linkerd_k8s_api::MeshTLSAuthentication {
+        metadata: k8s_core_api::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("mtls".to_string()),
+            ..Default::default()
+        },
+        spec: linkerd_k8s_api::MeshTLSAuthenticationSpec {
+            identities: None,
+            identity_refs: Some(vec![
+                linkerd_k8s_api::NamespacedTargetRef {
+                    group: None,
+                    kind: "ServiceAccount".to_string(),
+                    name: "client".to_string(),
+                    namespace: None,
+                },
+                linkerd_k8s_api::NamespacedTargetRef {
+                    group: None,
+                    kind: "Namespace".to_string(),
+                    name: "ns-1".to_string(),
+                    namespace: None,
+                },
+            ]),
+        },
+        status: None,
+    });
+
+    let id = NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: POLICY_API_GROUP.into(),
+            kind: "MeshTLSAuthentication".into(),
+            name: "mtls".into(),
+        },
+    };
+    let status = linkerd_k8s_api::meshtls_authentication::MeshTLSAuthenticationStatus {
+        authorization_policies: vec![],
+        authorization_policy_count: 0,
+        conditions: vec![
+            condition(
+                "ResolvedRefs",
+                "False",
+                "IdentityNotFound",
+                "ServiceAccount ns-0/client not found",
+            ),
+            condition(
+                "Referenced",
+                "False",
+                "Unreferenced",
+                "no AuthorizationPolicy requires this authentication",
+            ),
+        ],
+    };
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(crate::index::make_patch(&id, status).unwrap(), update.patch);
+    assert!(updates_rx.try_recv().is_err());
+
+    // A policy that requires the authentication is listed on its status.
+    index
+        .write()
+        .apply(super::authorization_policies::make_authorization_policy(
+            "ns-0", "authz", "srv", "client",
+        ));
+    let status = linkerd_k8s_api::meshtls_authentication::MeshTLSAuthenticationStatus {
+        authorization_policies: vec!["ns-0/authz".to_string()],
+        authorization_policy_count: 1,
+        conditions: vec![
+            condition(
+                "ResolvedRefs",
+                "False",
+                "IdentityNotFound",
+                "ServiceAccount ns-0/client not found",
+            ),
+            condition(
+                "Referenced",
+                "True",
+                "Referenced",
+                "required by 1 AuthorizationPolicies",
+            ),
+        ],
+    };
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(crate::index::make_patch(&id, status).unwrap(), update.patch);
+    assert!(updates_rx.try_recv().is_err());
+}
@@ -0,0 +1,199 @@
+use super::condition;
+use crate::{index::POLICY_API_GROUP, resource_id::NamespaceGroupKindName, Index, IndexMetrics};
+use chrono::{DateTime, Utc};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::routes::GroupKindName;
+use linkerd_policy_controller_k8s_api::{self as k8s_core_api, policy as linkerd_k8s_api};
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+
+#[test]
+fn authorization_policy_with_missing_refs() {
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    }));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let inbound = super::make_inbound_index();
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        inbound.clone(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    let policy = make_authorization_policy("ns-0", "authz", "srv", "client");
+    inbound.write().apply(policy.clone());
+    index.write().apply(policy);
+
+    let id = NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: POLICY_API_GROUP.into(),
+            kind: "AuthorizationPolicy".into(),
+            name: "authz".into(),
+        },
+    };
+    let status = linkerd_k8s_api::authorization_policy::AuthorizationPolicyStatus {
+        conditions: vec![
+            condition(
+                "Accepted",
+                "False",
+                "TargetNotFound",
+                "Server srv not found",
+            ),
+            condition(
+                "ResolvedRefs",
+                "False",
+                "AuthenticationNotFound",
+                "MeshTLSAuthentication ns-0/mtls, ServiceAccount ns-0/client not found",
+            ),
+        ],
+    };
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(crate::index::make_patch(&id, status).unwrap(), update.patch);
+    assert!(updates_rx.try_recv().is_err());
+
+    // Once the service account exists, only the other references are missing.
+    index.write().apply(k8s_core_api::ServiceAccount {
+        metadata: k8s_core_api::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("client".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let status = linkerd_k8s_api::authorization_policy::AuthorizationPolicyStatus {
+        conditions: vec![
+            condition(
+                "Accepted",
+                "False",
+                "TargetNotFound",
+                "Server srv not found",
+            ),
+            condition(
+                "ResolvedRefs",
+                "False",
+                "AuthenticationNotFound",
+                "MeshTLSAuthentication ns-0/mtls not found",
+            ),
+        ],
+    };
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(crate::index::make_patch(&id, status).unwrap(), update.patch);
+}
+
+#[test]
+fn authorization_policy_with_service_target() {
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    }));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let inbound = super::make_inbound_index();
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        inbound.clone(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    let mut policy = make_authorization_policy("ns-0", "authz", "svc", "client");
+    policy.spec.target_ref = linkerd_k8s_api::LocalTargetRef {
+        group: None,
+        kind: "Service".to_string(),
+        name: "svc".to_string(),
+    };
+    inbound.write().apply(policy.clone());
+    index.write().apply(policy);
+
+    let id = NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: POLICY_API_GROUP.into(),
+            kind: "AuthorizationPolicy".into(),
+            name: "authz".into(),
+        },
+    };
+    let resolved_refs = condition(
+        "ResolvedRefs",
+        "False",
+        "AuthenticationNotFound",
+        "MeshTLSAuthentication ns-0/mtls, ServiceAccount ns-0/client not found",
+    );
+    let status = linkerd_k8s_api::authorization_policy::AuthorizationPolicyStatus {
+        conditions: vec![
+            condition(
+                "Accepted",
+                "False",
+                "TargetNotFound",
+                "Service svc not found",
+            ),
+            resolved_refs.clone(),
+        ],
+    };
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(crate::index::make_patch(&id, status).unwrap(), update.patch);
+    assert!(updates_rx.try_recv().is_err());
+
+    // Once the Service exists, the policy is accepted, even though no server
+    // references the Service.
+    let svc = k8s_core_api::Service {
+        metadata: k8s_core_api::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("svc".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    inbound.write().apply(svc.clone());
+    index.write().apply(svc);
+    let status = linkerd_k8s_api::authorization_policy::AuthorizationPolicyStatus {
+        conditions: vec![condition("Accepted", "True", "Accepted", ""), resolved_refs],
+    };
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(crate::index::make_patch(&id, status).unwrap(), update.patch);
+}
+
+pub(super) fn make_authorization_policy(
+    namespace: &str,
+    name: &str,
+    server: &str,
+    service_account: &str,
+) -> linkerd_k8s_api::AuthorizationPolicy {
+    linkerd_k8s_api::AuthorizationPolicy {
+        metadata: k8s_core_api::ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: linkerd_k8s_api::AuthorizationPolicySpec {
+            target_ref: linkerd_k8s_api::LocalTargetRef {
+                group: Some(POLICY_API_GROUP.to_string()),
+                kind: "Server".to_string(),
+                name: server.to_string(),
+            },
+            required_authentication_refs: vec![
+                linkerd_k8s_api::NamespacedTargetRef {
+                    group: Some(POLICY_API_GROUP.to_string()),
+                    kind: "MeshTLSAuthentication".to_string(),
+                    name: "mtls".to_string(),
+                    namespace: None,
+                },
+                linkerd_k8s_api::NamespacedTargetRef {
+                    group: None,
+                    kind: "ServiceAccount".to_string(),
+                    name: service_account.to_string(),
+                    namespace: None,
+                },
+            ],
+        },
+        status: None,
+    }
+}
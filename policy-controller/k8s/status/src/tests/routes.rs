@@ -21,7 +21,7 @@ fn make_service(
     }
 }
 
-fn make_server(
+pub(super) fn make_server(
     namespace: impl ToString,
     name: impl ToString,
     port: u16,
@@ -43,9 +43,11 @@ fn make_server(
             ..Default::default()
         },
         spec: linkerd_k8s_api::ServerSpec {
-            port,
+            port: Some(port),
+            ports: None,
             selector: linkerd_k8s_api::server::Selector::Pod(pod_labels.into_iter().collect()),
             proxy_protocol,
         },
+        status: None,
     }
 }
@@ -22,6 +22,7 @@ fn linkerd_route_with_no_backends() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -82,6 +83,85 @@ fn linkerd_route_with_no_backends() {
     assert!(updates_rx.try_recv().is_err())
 }
 
+#[test]
+fn linkerd_route_with_external_name_parent() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        super::super::make_inbound_index(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    // Apply an ExternalName parent service, which has no cluster IP.
+    let mut parent = super::make_service("ns-0", "svc");
+    parent.spec = Some(k8s_core_api::ServiceSpec {
+        type_: Some("ExternalName".to_string()),
+        external_name: Some("api.example.com".to_string()),
+        ..Default::default()
+    });
+    index.write().apply(parent.clone());
+
+    // Apply the route.
+    let id = NamespaceGroupKindName {
+        namespace: parent.namespace().as_deref().unwrap().to_string(),
+        gkn: GroupKindName {
+            group: linkerd_k8s_api::HttpRoute::group(&()),
+            kind: linkerd_k8s_api::HttpRoute::kind(&()),
+            name: "route-foo".into(),
+        },
+    };
+    let parent = linkerd_k8s_api::httproute::ParentReference {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: parent.namespace(),
+        name: parent.name_unchecked(),
+        section_name: None,
+        port: Some(8080),
+    };
+
+    let route = make_linkerd_route(&id, parent.clone(), None);
+    index.write().apply(route);
+
+    // Create the expected update.
+    let accepted_condition = k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(DateTime::<Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: "Accepted".to_string(),
+        status: "True".to_string(),
+        type_: "Accepted".to_string(),
+    };
+    // No backends were specified, so we have vacuously resolved them all.
+    let backend_condition = k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(DateTime::<Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: "ResolvedRefs".to_string(),
+        status: "True".to_string(),
+        type_: "ResolvedRefs".to_string(),
+    };
+    let parent_status = k8s_gateway_api::RouteParentStatus {
+        parent_ref: parent,
+        controller_name: POLICY_CONTROLLER_NAME.to_string(),
+        conditions: vec![accepted_condition, backend_condition],
+    };
+    let status = make_status(vec![parent_status]);
+    let patch = crate::index::make_patch(&id, status).unwrap();
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_err())
+}
+
 #[test]
 fn gateway_route_with_no_backends() {
     let hostname = "test";
@@ -95,6 +175,7 @@ fn gateway_route_with_no_backends() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -167,6 +248,7 @@ fn linkerd_route_with_valid_backends() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -278,6 +360,7 @@ fn gateway_route_with_valid_backends() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -389,6 +472,7 @@ fn linkerd_route_with_invalid_backend() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -496,6 +580,7 @@ fn gateway_route_with_invalid_backend() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -590,6 +675,85 @@ fn gateway_route_with_invalid_backend() {
     assert!(updates_rx.try_recv().is_err())
 }
 
+#[test]
+fn gateway_route_with_unsupported_filter() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        super::super::make_inbound_index(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    // Apply the parent service
+    let parent = super::make_service("ns-0", "svc");
+    index.write().apply(parent.clone());
+
+    // Apply a route with a filter that proxies do not support.
+    let parent = k8s_gateway_api::ParentReference {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: parent.namespace(),
+        name: parent.name_unchecked(),
+        section_name: None,
+        port: Some(8080),
+    };
+    let id = NamespaceGroupKindName {
+        namespace: parent.namespace.as_deref().unwrap().to_string(),
+        gkn: GroupKindName {
+            group: k8s_gateway_api::HttpRoute::group(&()),
+            kind: k8s_gateway_api::HttpRoute::kind(&()),
+            name: "route-foo".into(),
+        },
+    };
+    let mut route = make_gateway_route(&id, parent.clone(), None);
+    route.spec.rules.as_mut().unwrap()[0].filters =
+        Some(vec![k8s_gateway_api::HttpRouteFilter::URLRewrite {
+            url_rewrite: k8s_gateway_api::HttpUrlRewriteFilter {
+                hostname: Some("example.com".to_string()),
+                path: None,
+            },
+        }]);
+    index.write().apply(route);
+
+    // The route is not served to proxies, so it is not accepted.
+    let accepted_condition = k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(DateTime::<Utc>::MIN_UTC),
+        message: "spec.rules[0]: URLRewrite filter is not supported".to_string(),
+        observed_generation: None,
+        reason: "UnsupportedValue".to_string(),
+        status: "False".to_string(),
+        type_: "Accepted".to_string(),
+    };
+    let backend_condition = k8s_core_api::Condition {
+        last_transition_time: k8s_core_api::Time(DateTime::<Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: "ResolvedRefs".to_string(),
+        status: "True".to_string(),
+        type_: "ResolvedRefs".to_string(),
+    };
+    let parent_status = k8s_gateway_api::RouteParentStatus {
+        parent_ref: parent,
+        controller_name: POLICY_CONTROLLER_NAME.to_string(),
+        conditions: vec![accepted_condition, backend_condition],
+    };
+    let status = make_status(vec![parent_status]);
+    let patch = crate::index::make_patch(&id, status).unwrap();
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_err())
+}
+
 #[test]
 fn linkerd_route_accepted_after_server_create() {
     let hostname = "test";
@@ -603,6 +767,7 @@ fn linkerd_route_accepted_after_server_create() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -683,6 +848,7 @@ fn gateway_route_accepted_after_server_create() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -763,6 +929,7 @@ fn linkerd_route_rejected_after_server_delete() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -849,6 +1016,7 @@ fn gateway_route_rejected_after_server_delete() {
         hostname,
         claims_rx,
         updates_tx,
+        super::super::make_inbound_index(),
         IndexMetrics::register(&mut Default::default()),
     );
 
@@ -922,6 +1090,204 @@ fn gateway_route_rejected_after_server_delete() {
     assert!(updates_rx.try_recv().is_err());
 }
 
+#[test]
+fn conflicting_routes() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        super::super::make_inbound_index(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    let server = super::make_server(
+        "ns-0",
+        "srv-8080",
+        8080,
+        vec![("app", "server")],
+        vec![("app", "server")],
+        None,
+    );
+    index.write().apply(server);
+
+    let parent = k8s_gateway_api::ParentReference {
+        group: Some(POLICY_API_GROUP.to_string()),
+        kind: Some("Server".to_string()),
+        namespace: Some("ns-0".to_string()),
+        name: "srv-8080".to_string(),
+        section_name: None,
+        port: None,
+    };
+    let mk_id = |name: &'static str| NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: k8s_gateway_api::HttpRoute::group(&()),
+            kind: k8s_gateway_api::HttpRoute::kind(&()),
+            name: name.into(),
+        },
+    };
+    let older_id = mk_id("route-older");
+    let newer_id = mk_id("route-newer");
+    let mut older = make_gateway_route(&older_id, parent.clone(), None);
+    older.metadata.creation_timestamp = Some(k8s_core_api::Time(DateTime::<Utc>::MIN_UTC));
+    let newer = make_gateway_route(&newer_id, parent, None);
+
+    // The older route is accepted.
+    index.write().apply(older);
+    let accepted = make_status(vec![make_parent_status(
+        "ns-0", "srv-8080", "Accepted", "True", "Accepted",
+    )]);
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(older_id, update.id);
+    assert_eq!(
+        crate::index::make_patch(&older_id, accepted.clone()).unwrap(),
+        update.patch
+    );
+
+    // The newer route has the same match on the same parent, so it
+    // conflicts with the older route. It is still accepted.
+    index.write().apply(newer);
+    let conflicted = make_conflicted_parent_status("ns-0", "srv-8080", "route-older");
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(newer_id, update.id);
+    assert_eq!(
+        crate::index::make_patch(&newer_id, make_status(vec![conflicted])).unwrap(),
+        update.patch
+    );
+
+    // The older route's status is unchanged.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(older_id, update.id);
+    assert_eq!(
+        crate::index::make_patch(&older_id, accepted.clone()).unwrap(),
+        update.patch
+    );
+    assert!(updates_rx.try_recv().is_err());
+
+    // When the older route is deleted, the newer route no longer conflicts.
+    {
+        let mut index = index.write();
+        <Index as IndexNamespacedResource<k8s_gateway_api::HttpRoute>>::delete(
+            &mut index,
+            "ns-0".to_string(),
+            "route-older".to_string(),
+        );
+    }
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(newer_id, update.id);
+    assert_eq!(
+        crate::index::make_patch(&newer_id, accepted).unwrap(),
+        update.patch
+    );
+    assert!(updates_rx.try_recv().is_err());
+}
+
+#[test]
+fn conflicted_routes_take_precedence() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        super::super::make_inbound_index(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    let server = super::make_server(
+        "ns-0",
+        "srv-8080",
+        8080,
+        vec![("app", "server")],
+        vec![("app", "server")],
+        None,
+    );
+    index.write().apply(server);
+
+    let parent = k8s_gateway_api::ParentReference {
+        group: Some(POLICY_API_GROUP.to_string()),
+        kind: Some("Server".to_string()),
+        namespace: Some("ns-0".to_string()),
+        name: "srv-8080".to_string(),
+        section_name: None,
+        port: None,
+    };
+    let mk_id = |name: &'static str| NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: k8s_gateway_api::HttpRoute::group(&()),
+            kind: k8s_gateway_api::HttpRoute::kind(&()),
+            name: name.into(),
+        },
+    };
+    let baz = k8s_gateway_api::HttpRouteMatch {
+        path: Some(k8s_gateway_api::HttpPathMatch::PathPrefix {
+            value: "/baz".to_string(),
+        }),
+        ..Default::default()
+    };
+
+    // The oldest route matches `/foo/bar`; the middle route matches both
+    // `/foo/bar` and `/baz`; and the newest route matches only `/baz`.
+    let oldest_id = mk_id("route-oldest");
+    let mut oldest = make_gateway_route(&oldest_id, parent.clone(), None);
+    oldest.metadata.creation_timestamp = Some(k8s_core_api::Time(DateTime::<Utc>::MIN_UTC));
+    let middle_id = mk_id("route-middle");
+    let mut middle = make_gateway_route(&middle_id, parent.clone(), None);
+    middle.metadata.creation_timestamp = Some(k8s_core_api::Time(
+        DateTime::<Utc>::MIN_UTC + chrono::Duration::seconds(1),
+    ));
+    middle.spec.rules.as_mut().unwrap()[0]
+        .matches
+        .as_mut()
+        .unwrap()
+        .push(baz.clone());
+    let newest_id = mk_id("route-newest");
+    let mut newest = make_gateway_route(&newest_id, parent, None);
+    newest.spec.rules.as_mut().unwrap()[0].matches = Some(vec![baz]);
+
+    index.write().apply(oldest);
+    index.write().apply(middle);
+    index.write().apply(newest);
+
+    let mut patches = std::collections::HashMap::new();
+    while let Ok(update) = updates_rx.try_recv() {
+        patches.insert(update.id, update.patch);
+    }
+
+    let accepted = make_status(vec![make_parent_status(
+        "ns-0", "srv-8080", "Accepted", "True", "Accepted",
+    )]);
+    assert_eq!(
+        patches[&oldest_id],
+        crate::index::make_patch(&oldest_id, accepted).unwrap(),
+    );
+    let conflicted = make_conflicted_parent_status("ns-0", "srv-8080", "route-oldest");
+    assert_eq!(
+        patches[&middle_id],
+        crate::index::make_patch(&middle_id, make_status(vec![conflicted])).unwrap(),
+    );
+    // The middle route is accepted even though it conflicts with the oldest
+    // route, so its `/baz` match takes precedence over the newest route's.
+    let conflicted = make_conflicted_parent_status("ns-0", "srv-8080", "route-middle");
+    assert_eq!(
+        patches[&newest_id],
+        crate::index::make_patch(&newest_id, make_status(vec![conflicted])).unwrap(),
+    );
+}
+
 fn make_status(
     parents: Vec<k8s_gateway_api::RouteParentStatus>,
 ) -> k8s_gateway_api::HttpRouteStatus {
@@ -999,6 +1365,22 @@ fn make_gateway_route(
     }
 }
 
+/// Returns the status of an accepted route whose matches conflict with those
+/// of the `winner` route.
+fn make_conflicted_parent_status(
+    namespace: &str,
+    name: &str,
+    winner: &str,
+) -> k8s_gateway_api::RouteParentStatus {
+    let mut status = make_parent_status(namespace, name, "Accepted", "True", "Accepted");
+    let mut conflicted =
+        make_parent_status(namespace, name, "Conflicted", "True", "Conflicted").conditions;
+    conflicted[0].message =
+        format!("HTTPRoute {namespace}/{winner} takes precedence for one or more matches");
+    status.conditions.extend(conflicted);
+    status
+}
+
 fn make_parent_status(
     namespace: impl ToString,
     name: impl ToString,
@@ -0,0 +1,159 @@
+use crate::{index::POLICY_API_GROUP, resource_id::NamespaceGroupKindName, Index, IndexMetrics};
+use chrono::{DateTime, Utc};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::routes::GroupKindName;
+use linkerd_policy_controller_k8s_api::{self as k8s, policy as linkerd_k8s_api};
+use std::sync::Arc;
+
+use super::condition;
+use tokio::sync::{mpsc, watch};
+
+#[test]
+fn server_without_workloads() {
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    }));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let inbound = super::make_inbound_index();
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        inbound.clone(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    let server = super::routes::make_server("ns-0", "srv", 8080, None, Some(("app", "app")), None);
+    inbound.write().apply(server.clone());
+    index.write().apply(server);
+
+    let id = NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: POLICY_API_GROUP.into(),
+            kind: "Server".into(),
+            name: "srv".into(),
+        },
+    };
+    let status = linkerd_k8s_api::server::ServerStatus {
+        conditions: vec![
+            condition("Accepted", "True", "Accepted", ""),
+            condition(
+                "ResolvedRefs",
+                "False",
+                "NoMatchingWorkloads",
+                "selects no pods or external workloads",
+            ),
+            condition(
+                "Authorized",
+                "False",
+                "NoAuthorizations",
+                "no authorizations apply, so all traffic is denied",
+            ),
+        ],
+    };
+    let patch = crate::index::make_patch(&id, status.clone()).unwrap();
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_err());
+
+    // Once the status is set on the server, no further patches are needed.
+    let mut server =
+        super::routes::make_server("ns-0", "srv", 8080, None, Some(("app", "app")), None);
+    server.status = Some(status);
+    index.write().apply(server);
+    assert!(updates_rx.try_recv().is_err());
+}
+
+#[test]
+fn server_observes_workload_changes() {
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    }));
+    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let inbound = super::make_inbound_index();
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        inbound.clone(),
+        IndexMetrics::register(&mut Default::default()),
+    );
+
+    let server = super::routes::make_server("ns-0", "srv", 8080, None, Some(("app", "app")), None);
+    inbound.write().apply(server.clone());
+    index.write().apply(server);
+    updates_rx
+        .try_recv()
+        .expect("server status must be patched");
+
+    // Nothing is reconciled until the inbound index reports a change.
+    inbound.write().take_changed_namespaces();
+    index.write().reconcile();
+    assert!(updates_rx.try_recv().is_err());
+
+    inbound.write().apply(k8s::Pod {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("pod-0".to_string()),
+            labels: Some(
+                [("app".to_string(), "app".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+        spec: Some(k8s::PodSpec {
+            containers: vec![k8s::Container {
+                name: "app".to_string(),
+                ports: Some(vec![k8s::ContainerPort {
+                    container_port: 8080,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    // Only the server that selects the pod is reported as changed.
+    assert!(inbound.write().take_changed_namespaces().is_empty());
+    let servers = inbound.write().take_changed_servers();
+    assert_eq!(
+        servers,
+        Some(("ns-0".to_string(), "srv".to_string()))
+            .into_iter()
+            .collect()
+    );
+    index.write().mark_servers_dirty(&servers);
+    index.write().reconcile();
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(update.id.gkn.name, "srv");
+    let status = linkerd_k8s_api::server::ServerStatus {
+        conditions: vec![
+            condition("Accepted", "True", "Accepted", ""),
+            condition(
+                "ResolvedRefs",
+                "True",
+                "ResolvedRefs",
+                "selects 1 pods and 0 external workloads",
+            ),
+            condition(
+                "Authorized",
+                "False",
+                "NoAuthorizations",
+                "no authorizations apply, so all traffic is denied",
+            ),
+        ],
+    };
+    assert_eq!(
+        crate::index::make_patch(&update.id, status).unwrap(),
+        update.patch
+    );
+    assert!(updates_rx.try_recv().is_err());
+}
@@ -1,25 +1,54 @@
 use super::validation;
-use crate::k8s::policy::{
-    httproute, server::Selector, AuthorizationPolicy, AuthorizationPolicySpec, HttpRoute,
-    HttpRouteSpec, LocalTargetRef, MeshTLSAuthentication, MeshTLSAuthenticationSpec,
-    NamespacedTargetRef, NetworkAuthentication, NetworkAuthenticationSpec, Server,
-    ServerAuthorization, ServerAuthorizationSpec, ServerSpec,
+use crate::k8s::{
+    self, labels,
+    policy::{
+        httproute,
+        server::{Port, PortRange, PortRangeError, ProxyProtocol, Selector},
+        AuthorizationPolicy, AuthorizationPolicySpec, HttpRoute, HttpRouteSpec, LocalTargetRef,
+        MeshTLSAuthentication, MeshTLSAuthenticationSpec, NamespacedTargetRef,
+        NetworkAuthentication, NetworkAuthenticationSpec, Server, ServerAuthorization,
+        ServerAuthorizationSpec, ServerSpec,
+    },
 };
 use anyhow::{anyhow, bail, ensure, Result};
 use futures::future;
 use hyper::{body::Buf, http, Body, Request, Response};
-use k8s_openapi::api::core::v1::{Namespace, ServiceAccount};
+use k8s_openapi::{
+    api::core::v1::{Namespace, ServiceAccount},
+    apimachinery::pkg::util::intstr::IntOrString,
+};
 use kube::{core::DynamicObject, Resource, ResourceExt};
 use linkerd_policy_controller_core as core;
 use linkerd_policy_controller_k8s_index as index;
+use linkerd_policy_controller_k8s_status as status;
 use serde::de::DeserializeOwned;
-use std::task;
+use std::{num::NonZeroU16, task};
 use thiserror::Error;
 use tracing::{debug, info, trace, warn};
 
 #[derive(Clone)]
 pub struct Admission {
     client: kube::Client,
+    ref_checks: RefChecks,
+
+    /// Whether HTTPRoutes with matches identical to another route's are
+    /// denied.
+    reject_duplicate_matches: bool,
+}
+
+/// Controls whether admission checks that the resources referenced by a
+/// policy exist.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RefChecks {
+    /// References are not checked.
+    #[default]
+    Disabled,
+
+    /// Missing references are reported as admission warnings.
+    Warn,
+
+    /// Resources with missing references are denied.
+    Deny,
 }
 
 #[derive(Debug, Error)]
@@ -31,6 +60,31 @@ pub enum Error {
     Json(#[from] serde_json::Error),
 }
 
+// === impl RefChecks ===
+
+impl std::str::FromStr for RefChecks {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "disabled" => Ok(Self::Disabled),
+            "warn" => Ok(Self::Warn),
+            "deny" => Ok(Self::Deny),
+            s => Err(anyhow!("invalid reference check mode: {:?}", s)),
+        }
+    }
+}
+
+/// Describes the references of a policy that do not resolve.
+#[derive(Debug, Default)]
+struct PolicyRefs {
+    /// References to resources that do not exist.
+    missing: Vec<String>,
+
+    /// References that could not be looked up.
+    unchecked: Vec<String>,
+}
+
 type Review = kube::core::admission::AdmissionReview<DynamicObject>;
 type AdmissionRequest = kube::core::admission::AdmissionRequest<DynamicObject>;
 type AdmissionResponse = kube::core::admission::AdmissionResponse;
@@ -38,7 +92,9 @@ type AdmissionReview = kube::core::admission::AdmissionReview<DynamicObject>;
 
 #[async_trait::async_trait]
 trait Validate<T> {
-    async fn validate(self, ns: &str, name: &str, spec: T) -> Result<()>;
+    /// Validates a spec, returning warnings to be reported to the client if
+    /// the spec is admitted.
+    async fn validate(self, ns: &str, name: &str, spec: T) -> Result<Vec<String>>;
 }
 
 // === impl AdmissionService ===
@@ -92,8 +148,16 @@ impl hyper::service::Service<Request<Body>> for Admission {
 }
 
 impl Admission {
-    pub fn new(client: kube::Client) -> Self {
-        Self { client }
+    pub fn new(
+        client: kube::Client,
+        ref_checks: RefChecks,
+        reject_duplicate_matches: bool,
+    ) -> Self {
+        Self {
+            client,
+            ref_checks,
+            reject_duplicate_matches,
+        }
     }
 
     async fn admit(self, req: AdmissionRequest) -> AdmissionResponse {
@@ -147,12 +211,189 @@ impl Admission {
             }
         };
 
-        if let Err(error) = self.validate(&ns, &name, spec).await {
-            info!(%error, %ns, %name, %kind, "Denied");
-            return rsp.deny(error);
+        match self.validate(&ns, &name, spec).await {
+            Ok(warnings) if warnings.is_empty() => rsp,
+            Ok(warnings) => {
+                info!(?warnings, %ns, %name, %kind, "Admitted with warnings");
+                let mut rsp = rsp;
+                rsp.warnings = Some(warnings);
+                rsp
+            }
+            Err(error) => {
+                info!(%error, %ns, %name, %kind, "Denied");
+                rsp.deny(error)
+            }
+        }
+    }
+
+    /// Checks that the resources referenced by an `AuthorizationPolicy`
+    /// exist, returning a description of each missing reference and of each
+    /// reference that could not be checked.
+    ///
+    /// References are looked up against the API server rather than the
+    /// index, since the index may lag behind resources created in the same
+    /// apply. Lookup failures are reported as warnings so that, e.g., missing
+    /// RBAC does not prevent policies from being admitted.
+    async fn check_policy_refs(&self, ns: &str, spec: &AuthorizationPolicySpec) -> PolicyRefs {
+        let mut refs = PolicyRefs::default();
+
+        let tgt = &spec.target_ref;
+        let target_exists = if tgt.targets_kind::<Server>() {
+            Some(self.exists::<Server>(ns, &tgt.name).await)
+        } else if tgt.targets_kind::<HttpRoute>() {
+            Some(self.exists::<HttpRoute>(ns, &tgt.name).await)
+        } else if tgt.targets_kind::<k8s::Service>() {
+            Some(self.exists::<k8s::Service>(ns, &tgt.name).await)
+        } else {
+            // Namespace targets must refer to the policy's own namespace.
+            None
+        };
+        if let Some(exists) = target_exists {
+            refs.record(
+                format!("targetRef {} {ns}/{}", tgt.canonical_kind(), tgt.name),
+                exists,
+            );
+        }
+
+        for authn in spec.required_authentication_refs.iter() {
+            let authn_ns = authn.namespace.as_deref().unwrap_or(ns);
+            let exists = if authn.targets_kind::<MeshTLSAuthentication>() {
+                self.exists::<MeshTLSAuthentication>(authn_ns, &authn.name)
+                    .await
+            } else if authn.targets_kind::<NetworkAuthentication>() {
+                self.exists::<NetworkAuthentication>(authn_ns, &authn.name)
+                    .await
+            } else if authn.targets_kind::<ServiceAccount>() {
+                self.exists::<ServiceAccount>(authn_ns, &authn.name).await
+            } else {
+                continue;
+            };
+            refs.record(
+                format!(
+                    "requiredAuthenticationRef {} {authn_ns}/{}",
+                    authn.canonical_kind(),
+                    authn.name
+                ),
+                exists,
+            );
+        }
+
+        refs
+    }
+
+    /// Fails if another HTTPRoute in the namespace has a match identical to
+    /// one of `route`'s, for an identical hostname, on a common parent, since
+    /// only one of the routes could ever be selected for such requests.
+    ///
+    /// Lookup failures are logged and otherwise ignored.
+    async fn check_duplicate_matches<T>(
+        &self,
+        ns: &str,
+        name: &str,
+        route: &status::HttpRouteMatches,
+    ) -> Result<()>
+    where
+        T: Resource<DynamicType = ()>,
+    {
+        let linkerd_routes = self.list::<HttpRoute>(ns).await.into_iter().map(|r| {
+            let matches = status::HttpRouteMatches::new(
+                ns,
+                &r.spec.inner,
+                r.spec.hostnames.as_deref(),
+                r.spec.rules.into_iter().flatten().map(|r| r.matches),
+            );
+            (HttpRoute::group(&()), r.metadata.name, matches)
+        });
+        let gateway_routes = self
+            .list::<k8s_gateway_api::HttpRoute>(ns)
+            .await
+            .into_iter()
+            .map(|r| {
+                let matches = status::HttpRouteMatches::new(
+                    ns,
+                    &r.spec.inner,
+                    r.spec.hostnames.as_deref(),
+                    r.spec.rules.into_iter().flatten().map(|r| r.matches),
+                );
+                (
+                    k8s_gateway_api::HttpRoute::group(&()),
+                    r.metadata.name,
+                    matches,
+                )
+            });
+
+        for (group, other, matches) in linkerd_routes.chain(gateway_routes) {
+            let Some(other) = other else {
+                continue;
+            };
+            if group == T::group(&()) && other == name {
+                continue;
+            }
+            ensure!(
+                !route.duplicates(&matches),
+                "a match is identical to one of HTTPRoute.{group} {ns}/{other} on a common parent"
+            );
+        }
+        Ok(())
+    }
+
+    /// Lists the resources in a namespace, or returns an empty list if the
+    /// lookup failed.
+    async fn list<T>(&self, ns: &str) -> Vec<T>
+    where
+        T: Resource<Scope = kube::core::NamespaceResourceScope>
+            + Clone
+            + DeserializeOwned
+            + std::fmt::Debug,
+        T::DynamicType: Default,
+    {
+        match kube::Api::<T>::namespaced(self.client.clone(), ns)
+            .list(&Default::default())
+            .await
+        {
+            Ok(list) => list.items,
+            Err(error) => {
+                let kind = T::kind(&Default::default()).to_string();
+                warn!(%error, %ns, %kind, "Failed to list resources");
+                vec![]
+            }
+        }
+    }
+
+    /// Returns whether the named resource exists. Lookup failures are logged.
+    async fn exists<T>(&self, ns: &str, name: &str) -> Result<bool, kube::Error>
+    where
+        T: Resource<Scope = kube::core::NamespaceResourceScope>
+            + Clone
+            + DeserializeOwned
+            + std::fmt::Debug,
+        T::DynamicType: Default,
+    {
+        match kube::Api::<T>::namespaced(self.client.clone(), ns)
+            .get_opt(name)
+            .await
+        {
+            Ok(obj) => Ok(obj.is_some()),
+            Err(error) => {
+                let kind = T::kind(&Default::default()).to_string();
+                warn!(%error, %ns, %name, %kind, "Failed to look up referenced resource");
+                Err(error)
+            }
         }
+    }
+}
+
+// === impl PolicyRefs ===
 
-        rsp
+impl PolicyRefs {
+    fn record(&mut self, reference: String, exists: Result<bool, kube::Error>) {
+        match exists {
+            Ok(true) => {}
+            Ok(false) => self.missing.push(format!("{reference} not found")),
+            Err(error) => self
+                .unchecked
+                .push(format!("{reference} could not be checked: {error}")),
+        }
     }
 }
 
@@ -207,6 +448,12 @@ fn validate_policy_target(ns: &str, tgt: &LocalTargetRef) -> Result<()> {
         return Ok(());
     }
 
+    // Policies that target a Service apply to the Servers that select
+    // workloads through it.
+    if tgt.targets_kind::<k8s::Service>() {
+        return Ok(());
+    }
+
     if tgt.targets_kind::<Namespace>() {
         if tgt.name != ns {
             bail!("cannot target another namespace: {}", tgt.name);
@@ -219,7 +466,12 @@ fn validate_policy_target(ns: &str, tgt: &LocalTargetRef) -> Result<()> {
 
 #[async_trait::async_trait]
 impl Validate<AuthorizationPolicySpec> for Admission {
-    async fn validate(self, ns: &str, _name: &str, spec: AuthorizationPolicySpec) -> Result<()> {
+    async fn validate(
+        self,
+        ns: &str,
+        _name: &str,
+        spec: AuthorizationPolicySpec,
+    ) -> Result<Vec<String>> {
         validate_policy_target(ns, &spec.target_ref)?;
 
         let mtls_authns_count = spec
@@ -269,10 +521,18 @@ impl Validate<AuthorizationPolicySpec> for Admission {
             bail!("unsupported authentication kind(s): {}", kinds.join(", "));
         }
 
+        let refs = match self.ref_checks {
+            RefChecks::Disabled => PolicyRefs::default(),
+            RefChecks::Warn | RefChecks::Deny => self.check_policy_refs(ns, &spec).await,
+        };
+        if self.ref_checks == RefChecks::Deny && !refs.missing.is_empty() {
+            bail!("{}", refs.missing.join("; "));
+        }
+
         // Confirm that the index will be able to read this spec.
         index::authorization_policy::validate(spec)?;
 
-        Ok(())
+        Ok(refs.missing.into_iter().chain(refs.unchecked).collect())
     }
 }
 
@@ -293,7 +553,12 @@ fn validate_identity_ref(id: &NamespacedTargetRef) -> Result<()> {
 
 #[async_trait::async_trait]
 impl Validate<MeshTLSAuthenticationSpec> for Admission {
-    async fn validate(self, _ns: &str, _name: &str, spec: MeshTLSAuthenticationSpec) -> Result<()> {
+    async fn validate(
+        self,
+        _ns: &str,
+        _name: &str,
+        spec: MeshTLSAuthenticationSpec,
+    ) -> Result<Vec<String>> {
         for id in spec.identities.iter().flatten() {
             if let Err(err) = validation::validate_identity(id) {
                 bail!("id {} is invalid: {}", id, err);
@@ -304,7 +569,7 @@ impl Validate<MeshTLSAuthenticationSpec> for Admission {
             validate_identity_ref(id)?;
         }
 
-        Ok(())
+        Ok(vec![])
     }
 }
 
@@ -314,62 +579,203 @@ impl Validate<ServerSpec> for Admission {
     //
     // TODO(ver) this isn't rigorous about detecting servers that select the same port if one port
     // specifies a numeric port and the other specifies the port's name.
-    async fn validate(self, ns: &str, name: &str, spec: ServerSpec) -> Result<()> {
+    async fn validate(self, ns: &str, name: &str, spec: ServerSpec) -> Result<Vec<String>> {
+        ensure!(
+            spec.port.is_some() != spec.ports.is_some(),
+            "exactly one of port or ports must be set"
+        );
+        // The destination controller discovers opaque ports from Servers'
+        // `port` field and pod or external workload selectors, and does not
+        // read port lists or Service references.
+        let opaque = spec.proxy_protocol == Some(ProxyProtocol::Opaque);
+        ensure!(
+            !opaque || spec.ports.is_none(),
+            "opaque Servers must set port rather than ports"
+        );
+        ensure!(
+            !opaque || !matches!(spec.selector, Selector::Service(_)),
+            "opaque Servers must set podSelector or externalWorkloadSelector rather than serviceRef"
+        );
+        let ports = ServerPorts::parse(&spec)?;
+
         // Since we can't ensure that the local index is up-to-date with the API server (i.e.
         // updates may be delayed), we issue an API request to get the latest state of servers in
         // the namespace.
-        let servers = kube::Api::<Server>::namespaced(self.client, ns)
+        let servers = kube::Api::<Server>::namespaced(self.client.clone(), ns)
             .list(&kube::api::ListParams::default())
-            .await?;
-        for server in servers.items.into_iter() {
+            .await?
+            .items;
+        // Servers that reference Services are compared by the workloads and
+        // ports that the Services target.
+        let services = if std::iter::once(&spec)
+            .chain(servers.iter().map(|srv| &srv.spec))
+            .any(|spec| matches!(spec.selector, Selector::Service(_)))
+        {
+            kube::Api::<k8s::Service>::namespaced(self.client.clone(), ns)
+                .list(&kube::api::ListParams::default())
+                .await?
+                .items
+        } else {
+            vec![]
+        };
+
+        let selection = Selection::resolve(&spec.selector, &ports, &services);
+        for server in servers.into_iter() {
             let server_name = server.name_unchecked();
-            if server_name != name
-                && server.spec.port == spec.port
-                && Self::overlaps(&server.spec.selector, &spec.selector)
-            {
+            if server_name == name {
+                continue;
+            }
+            // Existing servers are not rejected for invalid ports.
+            let Ok(server_ports) = ServerPorts::parse(&server.spec) else {
+                continue;
+            };
+            let server_selection =
+                Selection::resolve(&server.spec.selector, &server_ports, &services);
+            if !selection.workloads.overlaps(&server_selection.workloads) {
+                continue;
+            }
+            if let Some(port) = selection.ports.overlap(&server_selection.ports) {
                 let server_ns = server.namespace();
                 let server_ns = server_ns.as_deref().unwrap_or("default");
                 bail!(
                     "Server spec '{server_ns}/{server_name}' already defines a policy \
-                    for port {}, and selects pods that would be selected by this Server",
-                    server.spec.port,
+                    for port {port}, and selects pods that would be selected by this Server",
                 );
             }
         }
 
-        Ok(())
+        Ok(self.server_warnings(ns, &spec, &ports.names).await)
     }
 }
 
 impl Admission {
-    /// Detects whether two pod selectors can select the same pod
-    //
-    // TODO(ver) We can probably detect overlapping selectors more effectively. For
-    // example, if `left` selects pods with 'foo=bar' and `right` selects pods with
-    // 'foo', we should indicate the selectors overlap. It's a bit tricky to work
-    // through all of the cases though, so we'll just punt for now.
-    fn overlaps(left: &Selector, right: &Selector) -> bool {
-        match (left, right) {
-            (Selector::Pod(ps_left), Selector::Pod(ps_right)) => {
-                if ps_left.selects_all() || ps_right.selects_all() {
-                    return true;
-                }
+    /// Warns when a Server selects no workloads or when its named port is not
+    /// declared by any of the workloads it selects. Neither is an error, since
+    /// workloads may be created after the Server.
+    async fn server_warnings(&self, ns: &str, spec: &ServerSpec, names: &[String]) -> Vec<String> {
+        // Lists the TCP port names declared by each selected workload. Only
+        // the selected workloads are listed, so that large namespaces need not
+        // be read in their entirety. The label query may select a superset of
+        // the workloads (see `labels::Selector`'s `Display`), so the listed
+        // workloads are filtered by the selector as well.
+        let (kind, workloads) = match &spec.selector {
+            Selector::Service(svc) => {
+                return self.service_server_warnings(ns, &svc.name, names).await
             }
-            (Selector::ExternalWorkload(et_left), Selector::ExternalWorkload(et_right)) => {
-                if et_left.selects_all() || et_right.selects_all() {
-                    return true;
-                }
+            Selector::Pod(selector) => {
+                let pods = kube::Api::<k8s::Pod>::namespaced(self.client.clone(), ns)
+                    .list(&kube::api::ListParams::default().labels(&selector.to_string()))
+                    .await;
+                let workloads = pods.map(|pods| {
+                    pods.items
+                        .into_iter()
+                        .filter(|pod| selector.matches(&labels::Labels::from(pod.labels().clone())))
+                        .map(|pod| {
+                            pod.spec
+                                .into_iter()
+                                .flat_map(|spec| spec.containers)
+                                .flat_map(|c| c.ports.into_iter().flatten())
+                                .filter(|p| is_tcp(p.protocol.as_deref()))
+                                .filter_map(|p| p.name)
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                });
+                ("pods", workloads)
             }
-            (_, _) => return false,
+            Selector::ExternalWorkload(selector) => {
+                let externals = kube::Api::<k8s::external_workload::ExternalWorkload>::namespaced(
+                    self.client.clone(),
+                    ns,
+                )
+                .list(&kube::api::ListParams::default().labels(&selector.to_string()))
+                .await;
+                let workloads = externals.map(|externals| {
+                    externals
+                        .items
+                        .into_iter()
+                        .filter(|ew| selector.matches(&labels::Labels::from(ew.labels().clone())))
+                        .map(|ew| {
+                            ew.spec
+                                .ports
+                                .into_iter()
+                                .flatten()
+                                .filter(|p| is_tcp(p.protocol.as_deref()))
+                                .filter_map(|p| p.name)
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                });
+                ("external workloads", workloads)
+            }
+        };
+        let workloads = match workloads {
+            Ok(workloads) => workloads,
+            Err(error) => {
+                warn!(%error, %ns, "Failed to list {kind} selected by Server");
+                return vec![];
+            }
+        };
+
+        if workloads.is_empty() {
+            return vec![format!(
+                "Server does not select any {kind} in namespace {ns}"
+            )];
         }
 
-        left == right
+        names
+            .iter()
+            .filter(|name| !workloads.iter().any(|ports| ports.iter().any(|p| p == *name)))
+            .map(|name| {
+                format!(
+                    "none of the {} {kind} selected by this Server declare a TCP port named '{name}'",
+                    workloads.len(),
+                )
+            })
+            .collect()
+    }
+
+    /// Warns when a Server references a Service that does not exist or that
+    /// does not declare the Server's named ports.
+    async fn service_server_warnings(&self, ns: &str, name: &str, names: &[String]) -> Vec<String> {
+        let svc = kube::Api::<k8s::Service>::namespaced(self.client.clone(), ns)
+            .get_opt(name)
+            .await;
+        let svc = match svc {
+            Ok(Some(svc)) => svc,
+            Ok(None) => {
+                return vec![format!("Service {name} does not exist in namespace {ns}")];
+            }
+            Err(error) => {
+                warn!(%error, %ns, %name, "Failed to get Service referenced by Server");
+                return vec![];
+            }
+        };
+
+        let ports = svc
+            .spec
+            .and_then(|spec| spec.ports)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| is_tcp(p.protocol.as_deref()))
+            .filter_map(|p| p.name)
+            .collect::<Vec<_>>();
+        names
+            .iter()
+            .filter(|port| !ports.iter().any(|p| p == *port))
+            .map(|port| format!("Service {name} does not declare a TCP port named '{port}'"))
+            .collect()
     }
 }
 
 #[async_trait::async_trait]
 impl Validate<NetworkAuthenticationSpec> for Admission {
-    async fn validate(self, _ns: &str, _name: &str, spec: NetworkAuthenticationSpec) -> Result<()> {
+    async fn validate(
+        self,
+        _ns: &str,
+        _name: &str,
+        spec: NetworkAuthenticationSpec,
+    ) -> Result<Vec<String>> {
         if spec.networks.is_empty() {
             bail!("at least one network must be specified");
         }
@@ -392,13 +798,18 @@ impl Validate<NetworkAuthenticationSpec> for Admission {
             }
         }
 
-        Ok(())
+        Ok(vec![])
     }
 }
 
 #[async_trait::async_trait]
 impl Validate<ServerAuthorizationSpec> for Admission {
-    async fn validate(self, _ns: &str, _name: &str, spec: ServerAuthorizationSpec) -> Result<()> {
+    async fn validate(
+        self,
+        _ns: &str,
+        _name: &str,
+        spec: ServerAuthorizationSpec,
+    ) -> Result<Vec<String>> {
         if let Some(mtls) = spec.client.mesh_tls.as_ref() {
             if spec.client.unauthenticated {
                 bail!("`unauthenticated` must be false if `mesh_tls` is specified");
@@ -435,10 +846,223 @@ impl Validate<ServerAuthorizationSpec> for Admission {
             }
         }
 
-        Ok(())
+        Ok(vec![
+            "ServerAuthorization is deprecated; use AuthorizationPolicy instead".to_string(),
+        ])
     }
 }
 
+/// The ports selected by a Server. Port ranges are not expanded, since they
+/// may span many ports.
+#[derive(Clone, Debug, Default)]
+struct ServerPorts {
+    numbers: index::ports::PortSet,
+    ranges: Vec<PortRange>,
+    names: Vec<String>,
+}
+
+/// The workloads and ports selected by a Server.
+#[derive(Debug)]
+struct Selection {
+    workloads: Workloads,
+    ports: ServerPorts,
+}
+
+#[derive(Debug)]
+enum Workloads {
+    Pods(labels::Selector),
+    External(labels::Selector),
+    /// A Service's selector, which applies to both pods and external
+    /// workloads.
+    Any(labels::Selector),
+    None,
+}
+
+// === impl ServerPorts ===
+
+impl ServerPorts {
+    fn parse(spec: &ServerSpec) -> Result<Self> {
+        let mut ports = Self::default();
+        for port in spec.port_refs() {
+            match port {
+                Port::Number(port) => {
+                    ports.numbers.insert(*port);
+                }
+                Port::Range(range) => ports.ranges.push(*range),
+                // Port names must contain a letter, so a name without one is
+                // a malformed port range.
+                Port::Name(name) if !name.contains(|c: char| c.is_ascii_alphabetic()) => {
+                    let error = name
+                        .parse::<PortRange>()
+                        .err()
+                        .unwrap_or(PortRangeError::Syntax);
+                    bail!("invalid port range '{name}': {error}");
+                }
+                Port::Name(name) => ports.names.push(name.clone()),
+            }
+        }
+        Ok(ports)
+    }
+
+    fn insert_target(&mut self, target: Option<&IntOrString>, port: NonZeroU16) {
+        let target = match target {
+            None => Ok(port),
+            Some(IntOrString::Int(n)) => match u16::try_from(*n).ok().and_then(NonZeroU16::new) {
+                Some(n) => Ok(n),
+                None => return,
+            },
+            Some(IntOrString::String(name)) => name.parse().map_err(|_| name),
+        };
+        match target {
+            Ok(n) => {
+                self.numbers.insert(n);
+            }
+            Err(name) => {
+                if !self.names.contains(name) {
+                    self.names.push(name.clone());
+                }
+            }
+        }
+    }
+
+    fn contains(&self, port: NonZeroU16) -> bool {
+        self.numbers.contains(&port) || self.ranges.iter().any(|r| r.contains(port))
+    }
+
+    /// Returns a port that is selected by both servers, if any.
+    fn overlap(&self, other: &Self) -> Option<String> {
+        let numbers = self
+            .numbers
+            .iter()
+            .filter(|port| other.contains(**port))
+            .chain(other.numbers.iter().filter(|port| self.contains(**port)))
+            .copied();
+        // The first port of the intersection of two ranges is the greater of
+        // their first ports.
+        let ranges = self.ranges.iter().flat_map(|a| {
+            other
+                .ranges
+                .iter()
+                .filter(|b| a.first() <= b.last() && b.first() <= a.last())
+                .map(|b| a.first().max(b.first()))
+        });
+        if let Some(port) = numbers.chain(ranges).min() {
+            return Some(port.to_string());
+        }
+        self.names
+            .iter()
+            .find(|name| other.names.contains(name))
+            .map(|name| name.to_string())
+    }
+}
+
+// === impl Selection ===
+
+impl Selection {
+    /// Resolves the workloads and ports selected by a Server. A Server that
+    /// references a Service selects the workloads that the Service selects, on
+    /// the ports targeted by the referenced Service ports.
+    fn resolve(selector: &Selector, ports: &ServerPorts, services: &[k8s::Service]) -> Self {
+        let svc = match selector {
+            Selector::Pod(selector) => {
+                return Self {
+                    workloads: Workloads::Pods(selector.clone()),
+                    ports: ports.clone(),
+                }
+            }
+            Selector::ExternalWorkload(selector) => {
+                return Self {
+                    workloads: Workloads::External(selector.clone()),
+                    ports: ports.clone(),
+                }
+            }
+            Selector::Service(svc) => svc,
+        };
+
+        // A Service that does not exist, or that has no selector, selects no
+        // workloads.
+        let Some(spec) = services
+            .iter()
+            .find(|s| s.metadata.name.as_deref() == Some(&*svc.name))
+            .and_then(|s| s.spec.as_ref())
+        else {
+            return Self {
+                workloads: Workloads::None,
+                ports: ServerPorts::default(),
+            };
+        };
+        let workloads = match spec.selector.as_ref().filter(|s| !s.is_empty()) {
+            Some(selector) => Workloads::Any(selector.clone().into_iter().collect()),
+            None => Workloads::None,
+        };
+
+        let mut targets = ServerPorts::default();
+        for sp in spec.ports.iter().flatten() {
+            let Some(port) = u16::try_from(sp.port).ok().and_then(NonZeroU16::new) else {
+                continue;
+            };
+            let selected = ports.contains(port)
+                || sp
+                    .name
+                    .as_ref()
+                    .map_or(false, |name| ports.names.contains(name));
+            if selected && is_tcp(sp.protocol.as_deref()) {
+                targets.insert_target(sp.target_port.as_ref(), port);
+            }
+        }
+        Self {
+            workloads,
+            ports: targets,
+        }
+    }
+}
+
+// === impl Workloads ===
+
+impl Workloads {
+    /// Detects whether two selections can select the same workload
+    //
+    // TODO(ver) We can probably detect overlapping selectors more effectively. For
+    // example, if `left` selects pods with 'foo=bar' and `right` selects pods with
+    // 'foo', we should indicate the selectors overlap. It's a bit tricky to work
+    // through all of the cases though, so we'll just punt for now.
+    fn overlaps(&self, other: &Self) -> bool {
+        let (left, right) = match (self, other) {
+            (Self::Pods(left), Self::Pods(right))
+            | (Self::External(left), Self::External(right))
+            | (Self::Any(left), Self::Pods(right) | Self::External(right))
+            | (Self::Pods(left) | Self::External(left), Self::Any(right))
+            | (Self::Any(left), Self::Any(right)) => (left, right),
+            _ => return false,
+        };
+        left.selects_all() || right.selects_all() || left == right
+    }
+}
+
+fn is_tcp(protocol: Option<&str>) -> bool {
+    protocol.map_or(true, |p| p.eq_ignore_ascii_case("TCP"))
+}
+
+/// Warns when a redirect filter does not change the request's URL, so that
+/// clients would be redirected to the same location.
+fn redirect_warning(redirect: &k8s_gateway_api::HttpRequestRedirectFilter) -> Option<String> {
+    let k8s_gateway_api::HttpRequestRedirectFilter {
+        scheme,
+        hostname,
+        path,
+        port,
+        ..
+    } = redirect;
+    if scheme.is_none() && hostname.is_none() && path.is_none() && port.is_none() {
+        return Some(
+            "requestRedirect filter does not modify the scheme, hostname, path, or port, \
+            so requests would be redirected to their original location"
+                .to_string(),
+        );
+    }
+    None
+}
+
 fn validate_match(
     httproute::HttpRouteMatch {
         path,
@@ -466,7 +1090,7 @@ fn validate_match(
 
 #[async_trait::async_trait]
 impl Validate<HttpRouteSpec> for Admission {
-    async fn validate(self, _ns: &str, _name: &str, spec: HttpRouteSpec) -> Result<()> {
+    async fn validate(self, ns: &str, name: &str, spec: HttpRouteSpec) -> Result<Vec<String>> {
         fn validate_filter(filter: httproute::HttpRouteFilter) -> Result<()> {
             match filter {
                 httproute::HttpRouteFilter::RequestHeaderModifier {
@@ -504,10 +1128,18 @@ impl Validate<HttpRouteSpec> for Admission {
             Ok(())
         }
 
+        let route = status::HttpRouteMatches::new(
+            ns,
+            &spec.inner,
+            spec.hostnames.as_deref(),
+            spec.rules.iter().flatten().map(|r| r.matches.clone()),
+        );
+
         // Validate the rules in this spec.
         // This is essentially equivalent to the indexer's conversion function
         // from `HttpRouteSpec` to `InboundRouteBinding`, except that we don't
         // actually allocate stuff in order to return an `InboundRouteBinding`.
+        let mut warnings = vec![];
         for httproute::HttpRouteRule {
             filters,
             matches,
@@ -520,6 +1152,9 @@ impl Validate<HttpRouteSpec> for Admission {
             }
 
             for f in filters.into_iter().flatten() {
+                if let httproute::HttpRouteFilter::RequestRedirect { request_redirect } = &f {
+                    warnings.extend(redirect_warning(request_redirect));
+                }
                 validate_filter(f)?;
             }
 
@@ -528,7 +1163,12 @@ impl Validate<HttpRouteSpec> for Admission {
             }
         }
 
-        Ok(())
+        if self.reject_duplicate_matches {
+            self.check_duplicate_matches::<HttpRoute>(ns, name, &route)
+                .await?;
+        }
+
+        Ok(warnings)
     }
 }
 
@@ -536,10 +1176,10 @@ impl Validate<HttpRouteSpec> for Admission {
 impl Validate<k8s_gateway_api::HttpRouteSpec> for Admission {
     async fn validate(
         self,
-        _ns: &str,
-        _name: &str,
+        ns: &str,
+        name: &str,
         spec: k8s_gateway_api::HttpRouteSpec,
-    ) -> Result<()> {
+    ) -> Result<Vec<String>> {
         fn validate_filter(filter: k8s_gateway_api::HttpRouteFilter) -> Result<()> {
             match filter {
                 k8s_gateway_api::HttpRouteFilter::RequestHeaderModifier {
@@ -557,10 +1197,18 @@ impl Validate<k8s_gateway_api::HttpRouteSpec> for Admission {
             }
         }
 
+        let route = status::HttpRouteMatches::new(
+            ns,
+            &spec.inner,
+            spec.hostnames.as_deref(),
+            spec.rules.iter().flatten().map(|r| r.matches.clone()),
+        );
+
         // Validate the rules in this spec.
         // This is essentially equivalent to the indexer's conversion function
         // from `HttpRouteSpec` to `InboundRouteBinding`, except that we don't
         // actually allocate stuff in order to return an `InboundRouteBinding`.
+        let mut warnings = vec![];
         for k8s_gateway_api::HttpRouteRule {
             filters, matches, ..
         } in spec.rules.into_iter().flatten()
@@ -570,10 +1218,18 @@ impl Validate<k8s_gateway_api::HttpRouteSpec> for Admission {
             }
 
             for f in filters.into_iter().flatten() {
+                if let k8s_gateway_api::HttpRouteFilter::RequestRedirect { request_redirect } = &f {
+                    warnings.extend(redirect_warning(request_redirect));
+                }
                 validate_filter(f)?;
             }
         }
 
-        Ok(())
+        if self.reject_duplicate_matches {
+            self.check_duplicate_matches::<k8s_gateway_api::HttpRoute>(ns, name, &route)
+                .await?;
+        }
+
+        Ok(warnings)
     }
 }
@@ -0,0 +1,188 @@
+use crate::k8s::{self, api::core::v1::ObjectReference};
+use ahash::AHashMap as HashMap;
+use kube::runtime::events::{Event, EventType, Recorder, Reporter};
+use kubert::lease::Claim;
+use linkerd_policy_controller_k8s_index::events::Warning;
+use std::sync::Arc;
+use tokio::{
+    sync::{mpsc, watch},
+    time::{Duration, Instant},
+};
+
+const CONTROLLER_NAME: &str = "linkerd.io/policy-controller";
+
+/// Publishes warnings emitted by the indexes as Kubernetes events.
+///
+/// Indexes are updated whenever a watch restarts, so the same warning is
+/// published at most once per `interval`. As with status updates, only the
+/// holder of the write lease publishes events.
+pub struct EventPublisher {
+    claims: watch::Receiver<Arc<Claim>>,
+    client: k8s::Client,
+    name: String,
+    warnings: mpsc::Receiver<Warning>,
+    limiter: RateLimiter,
+    leader: bool,
+}
+
+#[derive(Debug)]
+struct RateLimiter {
+    interval: Duration,
+    last_published: HashMap<WarningKey, Instant>,
+    last_pruned: Instant,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct WarningKey {
+    kind: Option<String>,
+    namespace: Option<String>,
+    name: Option<String>,
+    reason: &'static str,
+    note: String,
+}
+
+// === impl EventPublisher ===
+
+impl EventPublisher {
+    pub fn new(
+        claims: watch::Receiver<Arc<Claim>>,
+        client: k8s::Client,
+        name: String,
+        warnings: mpsc::Receiver<Warning>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            claims,
+            client,
+            name,
+            warnings,
+            limiter: RateLimiter::new(interval, Instant::now()),
+            leader: false,
+        }
+    }
+
+    pub async fn run(mut self) {
+        loop {
+            tokio::select! {
+                biased;
+                res = self.claims.changed() => {
+                    res.expect("Claims watch must not be dropped");
+                    let claim = self.claims.borrow_and_update();
+                    self.leader = claim.is_current_for(&self.name);
+                }
+
+                Some(warning) = self.warnings.recv() => {
+                    // Warnings are dropped when this controller is not the
+                    // leader, since another controller publishes them.
+                    if !self.leader || !self.limiter.check(&warning, Instant::now()) {
+                        continue;
+                    }
+                    self.publish(warning).await;
+                }
+            }
+        }
+    }
+
+    async fn publish(
+        &self,
+        Warning {
+            object,
+            reason,
+            note,
+        }: Warning,
+    ) {
+        let reporter = Reporter {
+            controller: CONTROLLER_NAME.to_string(),
+            instance: Some(self.name.clone()),
+        };
+        let event = Event {
+            type_: EventType::Warning,
+            reason: reason.to_string(),
+            note: Some(note),
+            action: "Index".to_string(),
+            secondary: None,
+        };
+        let kind = object.kind.clone().unwrap_or_default();
+        let namespace = object.namespace.clone().unwrap_or_default();
+        let name = object.name.clone().unwrap_or_default();
+        if let Err(error) = Recorder::new(self.client.clone(), reporter, object)
+            .publish(event)
+            .await
+        {
+            tracing::warn!(%error, %kind, %namespace, %name, %reason, "Failed to publish event");
+        }
+    }
+}
+
+// === impl RateLimiter ===
+
+impl RateLimiter {
+    fn new(interval: Duration, now: Instant) -> Self {
+        Self {
+            interval,
+            last_published: HashMap::default(),
+            last_pruned: now,
+        }
+    }
+
+    /// Returns true if the warning has not been published within the
+    /// interval, recording that it is published now.
+    fn check(&mut self, warning: &Warning, now: Instant) -> bool {
+        if now.saturating_duration_since(self.last_pruned) >= self.interval {
+            let interval = self.interval;
+            self.last_published
+                .retain(|_, published| now.saturating_duration_since(*published) < interval);
+            self.last_pruned = now;
+        }
+
+        let key = WarningKey::new(&warning.object, warning.reason, &warning.note);
+        match self.last_published.get(&key) {
+            Some(published) if now.saturating_duration_since(*published) < self.interval => false,
+            _ => {
+                self.last_published.insert(key, now);
+                true
+            }
+        }
+    }
+}
+
+impl WarningKey {
+    fn new(object: &ObjectReference, reason: &'static str, note: &str) -> Self {
+        Self {
+            kind: object.kind.clone(),
+            namespace: object.namespace.clone(),
+            name: object.name.clone(),
+            reason,
+            note: note.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd_policy_controller_k8s_index::events::{object_ref, reasons};
+
+    #[test]
+    fn rate_limits_identical_warnings() {
+        let start = Instant::now();
+        let interval = Duration::from_secs(60);
+        let mut limiter = RateLimiter::new(interval, start);
+
+        let warning = |name: &str, note: &str| Warning {
+            object: object_ref::<k8s::Pod>("ns-0", name),
+            reason: reasons::INVALID_ANNOTATION,
+            note: note.to_string(),
+        };
+
+        assert!(limiter.check(&warning("pod-0", "bad"), start));
+        assert!(!limiter.check(&warning("pod-0", "bad"), start + interval / 2));
+        assert!(limiter.check(&warning("pod-0", "worse"), start + interval / 2));
+        assert!(limiter.check(&warning("pod-1", "bad"), start + interval / 2));
+        assert!(limiter.check(&warning("pod-0", "bad"), start + interval));
+
+        // Stale entries are pruned.
+        assert!(limiter.check(&warning("pod-2", "bad"), start + interval * 3));
+        assert_eq!(limiter.last_published.len(), 1);
+    }
+}
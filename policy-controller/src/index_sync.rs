@@ -0,0 +1,127 @@
+use futures::prelude::*;
+use kube::runtime::watcher;
+use tokio::sync::watch;
+use tonic::service::Interceptor;
+
+/// Tracks whether the resource watches that feed the indexes have synced.
+///
+/// A watch is synced once the index has applied its initial list of
+/// resources. Until all watches are synced, the indexes may serve incomplete
+/// policies.
+#[derive(Debug, Default)]
+pub struct IndexSync {
+    watches: Vec<watch::Receiver<bool>>,
+}
+
+/// Rejects gRPC requests with `UNAVAILABLE` until the indexes have synced, so
+/// that clients retry rather than observe incomplete policies.
+#[derive(Clone, Debug)]
+pub struct RequireSynced(watch::Receiver<bool>);
+
+// === impl IndexSync ===
+
+impl IndexSync {
+    /// Wraps a watch's event stream so that it is marked as synced once its
+    /// initial list has been indexed.
+    pub fn track<K>(
+        &mut self,
+        events: impl Stream<Item = watcher::Event<K>> + Send + 'static,
+    ) -> impl Stream<Item = watcher::Event<K>> + Send + 'static
+    where
+        K: Send + 'static,
+    {
+        let (tx, rx) = watch::channel(false);
+        self.watches.push(rx);
+
+        stream::unfold((events.boxed(), false), move |(mut events, restarted)| {
+            // The index applies each event before it polls for the next
+            // one, so the initial list has been indexed once the event
+            // following it is requested.
+            if restarted {
+                tx.send_if_modified(|synced| !std::mem::replace(synced, true));
+            }
+            async move {
+                let event = events.next().await?;
+                let restarted = matches!(event, watcher::Event::Restarted(_));
+                Some((event, (events, restarted)))
+            }
+        })
+    }
+
+    /// Returns a receiver that is updated to `true` once all tracked watches
+    /// have synced.
+    pub fn spawn(self) -> watch::Receiver<bool> {
+        let (tx, rx) = watch::channel(self.watches.is_empty());
+        tokio::spawn(async move {
+            for mut watch in self.watches {
+                if watch.wait_for(|synced| *synced).await.is_err() {
+                    return;
+                }
+            }
+            tracing::info!("Indexes synced");
+            let _ = tx.send(true);
+        });
+        rx
+    }
+}
+
+// === impl RequireSynced ===
+
+impl RequireSynced {
+    pub fn new(synced: watch::Receiver<bool>) -> Self {
+        Self(synced)
+    }
+}
+
+impl Interceptor for RequireSynced {
+    fn call(&mut self, req: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        if !*self.0.borrow() {
+            return Err(tonic::Status::unavailable("indexes have not yet synced"));
+        }
+        Ok(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::k8s;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    #[tokio::test]
+    async fn synced_after_initial_list_indexed() {
+        let mut sync = IndexSync::default();
+        let (pods_tx, pods_rx) = mpsc::unbounded_channel();
+        let mut pods = Box::pin(sync.track::<k8s::Pod>(UnboundedReceiverStream::new(pods_rx)));
+        let (svcs_tx, svcs_rx) = mpsc::unbounded_channel();
+        let mut svcs = Box::pin(sync.track::<k8s::Service>(UnboundedReceiverStream::new(svcs_rx)));
+        let mut synced = sync.spawn();
+
+        pods_tx.send(watcher::Event::Restarted(vec![])).unwrap();
+        svcs_tx.send(watcher::Event::Restarted(vec![])).unwrap();
+        assert!(pods.next().await.is_some());
+        assert!(svcs.next().await.is_some());
+        tokio::task::yield_now().await;
+        assert!(!*synced.borrow_and_update());
+
+        // Each watch is synced once the index requests the next event.
+        assert!(pods.next().now_or_never().is_none());
+        tokio::task::yield_now().await;
+        assert!(!*synced.borrow_and_update());
+        assert!(svcs.next().now_or_never().is_none());
+        synced.changed().await.unwrap();
+        assert!(*synced.borrow());
+    }
+
+    #[test]
+    fn requires_synced() {
+        let (tx, rx) = watch::channel(false);
+        let mut interceptor = RequireSynced::new(rx);
+        let status = interceptor.call(tonic::Request::new(())).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+
+        tx.send_replace(true);
+        assert!(interceptor.call(tonic::Request::new(())).is_ok());
+    }
+}
@@ -1,20 +1,28 @@
 #![deny(warnings, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 mod admission;
+mod events;
 pub mod index_list;
+pub mod index_sync;
 mod validation;
-pub use self::admission::Admission;
+pub use self::{
+    admission::{Admission, RefChecks},
+    events::EventPublisher,
+};
 use anyhow::Result;
 use linkerd_policy_controller_core::inbound::{
     DiscoverInboundServer, InboundServer, InboundServerStream,
 };
 use linkerd_policy_controller_core::outbound::{
     DiscoverOutboundPolicy, OutboundDiscoverTarget, OutboundPolicy, OutboundPolicyStream,
+    ServicePortRef,
 };
 pub use linkerd_policy_controller_core::IpNet;
 pub use linkerd_policy_controller_grpc as grpc;
 pub use linkerd_policy_controller_k8s_api as k8s;
-pub use linkerd_policy_controller_k8s_index::{inbound, outbound, ClusterInfo, DefaultPolicy};
+pub use linkerd_policy_controller_k8s_index::{
+    inbound, outbound, ClusterInfo, DefaultPolicy, Events,
+};
 use std::{net::IpAddr, num::NonZeroU16};
 
 #[derive(Clone, Debug)]
@@ -53,7 +61,7 @@ impl DiscoverInboundServer<(grpc::workload::Workload, NonZeroU16)> for InboundDi
         };
 
         if let Ok(rx) = rx {
-            let server = (*rx.borrow()).clone();
+            let server = rx.borrow().clone().into_inner();
             Ok(Some(server))
         } else {
             Ok(None)
@@ -83,6 +91,19 @@ impl DiscoverInboundServer<(grpc::workload::Workload, NonZeroU16)> for InboundDi
     }
 }
 
+impl grpc::authz::WorkloadIdentity for InboundDiscover {
+    fn workload_identity(&self, workload: &grpc::workload::Workload) -> Option<String> {
+        let index = self.0.read();
+        let id = match &workload.kind {
+            grpc::workload::Kind::Pod(name) => index.pod_identity(&workload.namespace, name),
+            grpc::workload::Kind::External(name) => {
+                index.external_workload_identity(&workload.namespace, name)
+            }
+        };
+        id.map(ToString::to_string)
+    }
+}
+
 #[async_trait::async_trait]
 impl DiscoverOutboundPolicy<OutboundDiscoverTarget> for OutboundDiscover {
     async fn get_outbound_policy(
@@ -106,7 +127,7 @@ impl DiscoverOutboundPolicy<OutboundDiscoverTarget> for OutboundDiscover {
                 return Ok(None);
             }
         };
-        let policy = (*rx.borrow()).clone();
+        let policy = rx.borrow().clone().into_inner();
         Ok(Some(policy))
     }
 
@@ -148,4 +169,13 @@ impl DiscoverOutboundPolicy<OutboundDiscoverTarget> for OutboundDiscover {
                 },
             )
     }
+
+    fn lookup_service_port(
+        &self,
+        namespace: &str,
+        name: &str,
+        port: &ServicePortRef,
+    ) -> Option<NonZeroU16> {
+        self.0.read().lookup_service_port(namespace, name, port)
+    }
 }
@@ -9,15 +9,27 @@ use k8s_openapi::api::coordination::v1 as coordv1;
 use kube::{api::PatchParams, runtime::watcher};
 use kubert::LeaseManager;
 use linkerd_policy_controller::{
-    grpc, inbound, index_list::IndexList, k8s, outbound, Admission, ClusterInfo, DefaultPolicy,
-    InboundDiscover, IpNet, OutboundDiscover,
+    grpc::{self, authz::ClientAuthz, limits::WatchLimits, metrics::GrpcServerMetrics},
+    inbound,
+    index_list::IndexList,
+    index_sync::{IndexSync, RequireSynced},
+    k8s, outbound, Admission, ClusterInfo, DefaultPolicy, EventPublisher, Events, InboundDiscover,
+    IpNet, OutboundDiscover, RefChecks,
 };
 use linkerd_policy_controller_k8s_index::ports::parse_portset;
 use linkerd_policy_controller_k8s_status::{self as status};
 use prometheus_client::registry::Registry;
-use std::{net::SocketAddr, sync::Arc};
-use tokio::{sync::mpsc, time::Duration};
-use tonic::transport::Server;
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+use tokio::{
+    sync::{mpsc, watch},
+    time::Duration,
+};
+use tonic::{
+    codec::CompressionEncoding,
+    codegen::InterceptedService,
+    server::NamedService,
+    transport::{Certificate, Identity, Server, ServerTlsConfig},
+};
 use tracing::{info, info_span, instrument, Instrument};
 
 #[cfg(all(target_os = "linux", target_arch = "x86_64", target_env = "gnu"))]
@@ -28,11 +40,16 @@ const DETECT_TIMEOUT: Duration = Duration::from_secs(10);
 const LEASE_DURATION: Duration = Duration::from_secs(30);
 const LEASE_NAME: &str = "policy-controller-write";
 const RENEW_GRACE_PERIOD: Duration = Duration::from_secs(1);
-const RECONCILIATION_PERIOD: Duration = Duration::from_secs(10);
-// The maximum number of status patches to buffer. As a conservative estimate,
-// we assume that sending a patch will take at least 1ms, so we set the buffer
-// size to be the same as the reconciliation period in milliseconds.
-const STATUS_UPDATE_QUEUE_SIZE: usize = RECONCILIATION_PERIOD.as_millis() as usize;
+// The maximum number of status patches to buffer. Patches are only sent for
+// resources whose status has changed, and the controller coalesces patches
+// for the same resource, so this only needs to absorb bursts, e.g. when all
+// resources are resynced.
+const STATUS_UPDATE_QUEUE_SIZE: usize = 10_000;
+// The maximum number of warnings to buffer for publishing as events. Warnings
+// that overflow the buffer are only logged.
+const EVENTS_QUEUE_SIZE: usize = 1_000;
+// Identical warnings about an object are published at most once per interval.
+const EVENTS_INTERVAL: Duration = Duration::from_secs(5 * 60);
 
 #[derive(Debug, Parser)]
 #[clap(name = "policy", about = "A policy resource prototype")]
@@ -60,9 +77,30 @@ struct Args {
     #[clap(long)]
     admission_controller_disabled: bool,
 
+    /// Controls whether the admission controller checks that resources
+    /// referenced by an AuthorizationPolicy exist: `disabled`, `warn`, or
+    /// `deny`.
+    #[clap(long, default_value = "disabled")]
+    admission_ref_checks: RefChecks,
+
+    /// Causes the admission controller to deny HTTPRoutes that have a match
+    /// identical to one of another route's, for an identical hostname, on a
+    /// common parent. Such routes are otherwise admitted, and the conflict is
+    /// reported in their status.
+    #[clap(long)]
+    admission_reject_duplicate_matches: bool,
+
     #[clap(long, default_value = "0.0.0.0:8090")]
     grpc_addr: SocketAddr,
 
+    /// The address on which the policy simulation gRPC API is served. The API
+    /// is intended for operators and does not authorize its clients, so it is
+    /// only served when this is set, and it should be bound to an address
+    /// that workloads cannot reach (e.g. `127.0.0.1:9996`, for use with
+    /// `kubectl port-forward`).
+    #[clap(long)]
+    simulation_addr: Option<SocketAddr>,
+
     /// Network CIDRs of pod IPs.
     ///
     /// The default includes all private networks.
@@ -96,6 +134,47 @@ struct Args {
 
     #[clap(long, default_value = "5000")]
     patch_timeout_ms: u64,
+
+    /// The interval at which the statuses of all resources are reconciled,
+    /// in addition to when the resources they depend on change.
+    #[clap(long, default_value = "600")]
+    status_resync_interval_secs: u64,
+
+    /// Updates to a policy watch that are published within this window of one
+    /// another are coalesced, so that only the latest is sent.
+    #[clap(long, default_value = "100")]
+    grpc_update_debounce_ms: u64,
+
+    /// A comma-separated list of the compression encodings that the policy
+    /// gRPC APIs accept and, when the client supports them, use for responses.
+    /// Only `gzip` is supported; `none` disables compression.
+    #[clap(long, default_value = "gzip")]
+    grpc_compression: GrpcCompression,
+
+    /// The maximum number of concurrent policy watches that may be opened on
+    /// behalf of a single workload.
+    #[clap(long, default_value = "1000")]
+    grpc_max_watches_per_client: usize,
+
+    /// The maximum number of concurrent policy watches across all clients.
+    #[clap(long, default_value = "100000")]
+    grpc_max_watches: usize,
+
+    /// A PEM-encoded certificate chain that the policy gRPC server presents to
+    /// clients. When set, along with `--grpc-tls-key` and
+    /// `--grpc-tls-client-ca`, clients must authenticate with a certificate
+    /// and may only discover policies for the workload whose identity matches
+    /// it.
+    #[clap(long)]
+    grpc_tls_cert: Option<PathBuf>,
+
+    /// The PEM-encoded private key for `--grpc-tls-cert`.
+    #[clap(long)]
+    grpc_tls_key: Option<PathBuf>,
+
+    /// The PEM-encoded trust anchors used to verify client certificates.
+    #[clap(long)]
+    grpc_tls_client_ca: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -107,7 +186,10 @@ async fn main() -> Result<()> {
         log_format,
         server,
         grpc_addr,
+        simulation_addr,
         admission_controller_disabled,
+        admission_ref_checks,
+        admission_reject_duplicate_matches,
         identity_domain,
         cluster_domain,
         cluster_networks: IpNets(cluster_networks),
@@ -117,8 +199,32 @@ async fn main() -> Result<()> {
         probe_networks,
         default_opaque_ports,
         patch_timeout_ms,
+        status_resync_interval_secs,
+        grpc_update_debounce_ms,
+        grpc_compression: GrpcCompression(grpc_compression),
+        grpc_max_watches_per_client,
+        grpc_max_watches,
+        grpc_tls_cert,
+        grpc_tls_key,
+        grpc_tls_client_ca,
     } = Args::parse();
 
+    let grpc_tls = match (grpc_tls_cert, grpc_tls_key, grpc_tls_client_ca) {
+        (None, None, None) => None,
+        (Some(cert), Some(key), Some(client_ca)) => {
+            let identity = Identity::from_pem(std::fs::read(cert)?, std::fs::read(key)?);
+            let client_ca = Certificate::from_pem(std::fs::read(client_ca)?);
+            Some(
+                ServerTlsConfig::new()
+                    .identity(identity)
+                    .client_ca_root(client_ca),
+            )
+        }
+        _ => {
+            bail!("--grpc-tls-cert, --grpc-tls-key, and --grpc-tls-client-ca must be set together")
+        }
+    };
+
     let server = if admission_controller_disabled {
         None
     } else {
@@ -141,8 +247,9 @@ async fn main() -> Result<()> {
 
     // Build the API index data structures which will maintain information
     // necessary for serving the inbound policy and outbound policy gRPC APIs.
-    let inbound_index = inbound::Index::shared(cluster_info.clone());
-    let outbound_index = outbound::Index::shared(cluster_info);
+    let (events, events_rx) = Events::channel(EVENTS_QUEUE_SIZE);
+    let inbound_index = inbound::Index::shared(cluster_info.clone(), events.clone());
+    let outbound_index = outbound::Index::shared(cluster_info, events);
 
     let mut prom = <Registry>::default();
     let resource_status = prom.sub_registry_with_prefix("resource_status");
@@ -157,6 +264,10 @@ async fn main() -> Result<()> {
         prom.sub_registry_with_prefix("inbound_index"),
         inbound_index.clone(),
     );
+    let grpc_metrics = GrpcMetrics {
+        inbound: GrpcServerMetrics::register(prom.sub_registry_with_prefix("inbound_grpc")),
+        outbound: GrpcServerMetrics::register(prom.sub_registry_with_prefix("outbound_grpc")),
+    };
 
     let mut runtime = kubert::Runtime::builder()
         .with_log(log_level, log_format)
@@ -188,25 +299,32 @@ async fn main() -> Result<()> {
         hostname.clone(),
         claims.clone(),
         updates_tx,
+        inbound_index.clone(),
         status_index_metrcs,
     );
 
-    // Spawn resource watches.
+    // Spawn resource watches, tracking when each has synced its initial
+    // state.
+    let mut index_sync = IndexSync::default();
 
-    let pods = runtime
-        .watch_all::<k8s::Pod>(watcher::Config::default().labels("linkerd.io/control-plane-ns"));
+    let pods =
+        index_sync.track(runtime.watch_all::<k8s::Pod>(
+            watcher::Config::default().labels("linkerd.io/control-plane-ns"),
+        ));
     tokio::spawn(
         kubert::index::namespaced(inbound_index.clone(), pods).instrument(info_span!("pods")),
     );
 
-    let external_workloads =
-        runtime.watch_all::<k8s::external_workload::ExternalWorkload>(watcher::Config::default());
+    let external_workloads = index_sync.track(
+        runtime.watch_all::<k8s::external_workload::ExternalWorkload>(watcher::Config::default()),
+    );
     tokio::spawn(
         kubert::index::namespaced(inbound_index.clone(), external_workloads)
             .instrument(info_span!("external_workloads")),
     );
 
-    let servers = runtime.watch_all::<k8s::policy::Server>(watcher::Config::default());
+    let servers =
+        index_sync.track(runtime.watch_all::<k8s::policy::Server>(watcher::Config::default()));
     let servers_indexes = IndexList::new(inbound_index.clone())
         .push(status_index.clone())
         .shared();
@@ -214,35 +332,59 @@ async fn main() -> Result<()> {
         kubert::index::namespaced(servers_indexes, servers).instrument(info_span!("servers")),
     );
 
-    let server_authzs =
-        runtime.watch_all::<k8s::policy::ServerAuthorization>(watcher::Config::default());
+    let server_authzs = index_sync
+        .track(runtime.watch_all::<k8s::policy::ServerAuthorization>(watcher::Config::default()));
     tokio::spawn(
         kubert::index::namespaced(inbound_index.clone(), server_authzs)
             .instrument(info_span!("serverauthorizations")),
     );
 
-    let authz_policies =
-        runtime.watch_all::<k8s::policy::AuthorizationPolicy>(watcher::Config::default());
+    let authz_policies = index_sync
+        .track(runtime.watch_all::<k8s::policy::AuthorizationPolicy>(watcher::Config::default()));
+    let authz_policies_indexes = IndexList::new(inbound_index.clone())
+        .push(status_index.clone())
+        .shared();
     tokio::spawn(
-        kubert::index::namespaced(inbound_index.clone(), authz_policies)
+        kubert::index::namespaced(authz_policies_indexes, authz_policies)
             .instrument(info_span!("authorizationpolicies")),
     );
 
-    let mtls_authns =
-        runtime.watch_all::<k8s::policy::MeshTLSAuthentication>(watcher::Config::default());
+    let namespaces =
+        index_sync.track(runtime.watch_all::<k8s::Namespace>(watcher::Config::default()));
+    tokio::spawn(
+        kubert::index::cluster(status_index.clone(), namespaces)
+            .instrument(info_span!("namespaces")),
+    );
+
+    let service_accounts =
+        index_sync.track(runtime.watch_all::<k8s::ServiceAccount>(watcher::Config::default()));
+    tokio::spawn(
+        kubert::index::namespaced(status_index.clone(), service_accounts)
+            .instrument(info_span!("serviceaccounts")),
+    );
+
+    let mtls_authns = index_sync
+        .track(runtime.watch_all::<k8s::policy::MeshTLSAuthentication>(watcher::Config::default()));
+    let mtls_authns_indexes = IndexList::new(inbound_index.clone())
+        .push(status_index.clone())
+        .shared();
     tokio::spawn(
-        kubert::index::namespaced(inbound_index.clone(), mtls_authns)
+        kubert::index::namespaced(mtls_authns_indexes, mtls_authns)
             .instrument(info_span!("meshtlsauthentications")),
     );
 
-    let network_authns =
-        runtime.watch_all::<k8s::policy::NetworkAuthentication>(watcher::Config::default());
+    let network_authns = index_sync
+        .track(runtime.watch_all::<k8s::policy::NetworkAuthentication>(watcher::Config::default()));
+    let network_authns_indexes = IndexList::new(inbound_index.clone())
+        .push(status_index.clone())
+        .shared();
     tokio::spawn(
-        kubert::index::namespaced(inbound_index.clone(), network_authns)
+        kubert::index::namespaced(network_authns_indexes, network_authns)
             .instrument(info_span!("networkauthentications")),
     );
 
-    let http_routes = runtime.watch_all::<k8s::policy::HttpRoute>(watcher::Config::default());
+    let http_routes =
+        index_sync.track(runtime.watch_all::<k8s::policy::HttpRoute>(watcher::Config::default()));
     let http_routes_indexes = IndexList::new(inbound_index.clone())
         .push(outbound_index.clone())
         .push(status_index.clone())
@@ -252,38 +394,74 @@ async fn main() -> Result<()> {
             .instrument(info_span!("httproutes.policy.linkerd.io")),
     );
 
-    let gateway_http_routes =
-        runtime.watch_all::<k8s_gateway_api::HttpRoute>(watcher::Config::default());
+    let gateway_http_routes = index_sync
+        .track(runtime.watch_all::<k8s_gateway_api::HttpRoute>(watcher::Config::default()));
     tokio::spawn(
         kubert::index::namespaced(http_routes_indexes, gateway_http_routes)
             .instrument(info_span!("httproutes.gateway.networking.k8s.io")),
     );
 
-    let services = runtime.watch_all::<k8s::Service>(watcher::Config::default());
-    let services_indexes = IndexList::new(outbound_index.clone())
+    let services = index_sync.track(runtime.watch_all::<k8s::Service>(watcher::Config::default()));
+    let services_indexes = IndexList::new(inbound_index.clone())
+        .push(outbound_index.clone())
         .push(status_index.clone())
         .shared();
     tokio::spawn(
         kubert::index::namespaced(services_indexes, services).instrument(info_span!("services")),
     );
 
+    let synced = index_sync.spawn();
+
+    // The controller is not ready until its indexes have synced.
+    let initialized = runtime.initialized_handle();
+    tokio::spawn({
+        let mut synced = synced.clone();
+        async move {
+            let _ = synced.wait_for(|synced| *synced).await;
+            drop(initialized);
+        }
+    });
+
     // Spawn the status Controller reconciliation.
     tokio::spawn(
-        status::Index::run(status_index.clone(), RECONCILIATION_PERIOD)
-            .instrument(info_span!("status::Index")),
+        status::Index::run(
+            status_index.clone(),
+            Duration::from_secs(status_resync_interval_secs),
+        )
+        .instrument(info_span!("status::Index")),
     );
 
     // Run the gRPC server, serving results by looking up against the index handle.
     tokio::spawn(grpc(
-        grpc_addr,
-        cluster_domain,
-        cluster_networks,
-        inbound_index,
-        outbound_index,
+        GrpcConfig {
+            addr: grpc_addr,
+            simulation_addr,
+            cluster_domain,
+            cluster_networks,
+            update_debounce: Duration::from_millis(grpc_update_debounce_ms),
+            compression: grpc_compression,
+            watch_limits: WatchLimits::new(grpc_max_watches_per_client, grpc_max_watches),
+            tls: grpc_tls,
+        },
+        GrpcIndexes {
+            inbound: inbound_index,
+            outbound: outbound_index,
+            synced,
+        },
+        grpc_metrics,
         runtime.shutdown_handle(),
     ));
 
     let client = runtime.client();
+    let event_publisher = EventPublisher::new(
+        claims.clone(),
+        client.clone(),
+        hostname.clone(),
+        events_rx,
+        EVENTS_INTERVAL,
+    );
+    tokio::spawn(event_publisher.run().instrument(info_span!("events")));
+
     let status_controller = status::Controller::new(
         claims,
         client,
@@ -299,7 +477,13 @@ async fn main() -> Result<()> {
     );
 
     let client = runtime.client();
-    let runtime = runtime.spawn_server(|| Admission::new(client));
+    let runtime = runtime.spawn_server(|| {
+        Admission::new(
+            client,
+            admission_ref_checks,
+            admission_reject_duplicate_matches,
+        )
+    });
 
     // Block the main thread on the shutdown signal. Once it fires, wait for the background tasks to
     // complete before exiting.
@@ -313,6 +497,43 @@ async fn main() -> Result<()> {
 #[derive(Clone, Debug)]
 struct IpNets(Vec<IpNet>);
 
+#[derive(Clone, Debug)]
+struct GrpcCompression(Vec<CompressionEncoding>);
+
+/// Configures the policy gRPC server.
+struct GrpcConfig {
+    addr: SocketAddr,
+
+    /// When set, the policy simulation API is served on its own listener at
+    /// this address.
+    simulation_addr: Option<SocketAddr>,
+
+    cluster_domain: String,
+    cluster_networks: Vec<IpNet>,
+    update_debounce: Duration,
+    compression: Vec<CompressionEncoding>,
+    watch_limits: WatchLimits,
+
+    /// When set, the server requires mTLS and authorizes clients by their
+    /// identity.
+    tls: Option<ServerTlsConfig>,
+}
+
+/// The indexes that back the policy gRPC APIs.
+struct GrpcIndexes {
+    inbound: inbound::SharedIndex,
+    outbound: outbound::SharedIndex,
+
+    /// Updated to `true` once the indexes have synced their initial state.
+    synced: watch::Receiver<bool>,
+}
+
+/// Metrics for each of the policy gRPC APIs.
+struct GrpcMetrics {
+    inbound: GrpcServerMetrics,
+    outbound: GrpcServerMetrics,
+}
+
 impl std::str::FromStr for IpNets {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -323,31 +544,171 @@ impl std::str::FromStr for IpNets {
     }
 }
 
+impl std::str::FromStr for GrpcCompression {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "none" {
+            return Ok(Self(vec![]));
+        }
+        s.split(',')
+            .map(|encoding| match encoding {
+                "gzip" => Ok(CompressionEncoding::Gzip),
+                _ => bail!("unsupported compression encoding: {encoding}"),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
 #[instrument(skip_all, fields(port = %addr.port()))]
 async fn grpc(
-    addr: SocketAddr,
-    cluster_domain: String,
-    cluster_networks: Vec<IpNet>,
-    inbound_index: inbound::SharedIndex,
-    outbound_index: outbound::SharedIndex,
+    GrpcConfig {
+        addr,
+        simulation_addr,
+        cluster_domain,
+        cluster_networks,
+        update_debounce,
+        compression,
+        watch_limits,
+        tls,
+    }: GrpcConfig,
+    GrpcIndexes {
+        inbound: inbound_index,
+        outbound: outbound_index,
+        mut synced,
+    }: GrpcIndexes,
+    metrics: GrpcMetrics,
     drain: drain::Watch,
 ) -> Result<()> {
     let inbound_discover = InboundDiscover::new(inbound_index);
-    let inbound_svc =
-        grpc::inbound::InboundPolicyServer::new(inbound_discover, cluster_networks, drain.clone())
-            .svc();
-
     let outbound_discover = OutboundDiscover::new(outbound_index);
-    let outbound_svc =
-        grpc::outbound::OutboundPolicyServer::new(outbound_discover, cluster_domain, drain.clone())
-            .svc();
+    let authz = if tls.is_some() {
+        ClientAuthz::identity(inbound_discover.clone())
+    } else {
+        ClientAuthz::disabled()
+    };
+    // Requests are rejected until the indexes have synced, since they would
+    // otherwise be served incomplete policies.
+    let require_synced = RequireSynced::new(synced.clone());
+
+    if let Some(addr) = simulation_addr {
+        let simulation = grpc::simulate::PolicySimulationServer::new(
+            inbound_discover.clone(),
+            outbound_discover.clone(),
+            cluster_networks.clone(),
+            cluster_domain.clone(),
+        );
+        tokio::spawn(simulation_grpc(
+            addr,
+            simulation,
+            require_synced.clone(),
+            drain.clone(),
+        ));
+    }
+
+    let mut inbound_svc = grpc::inbound::InboundPolicyServer::new(
+        inbound_discover,
+        cluster_networks,
+        drain.clone(),
+        metrics.inbound,
+        update_debounce,
+        authz.clone(),
+        watch_limits.clone(),
+    )
+    .svc();
+    let mut outbound_svc = grpc::outbound::OutboundPolicyServer::new(
+        outbound_discover,
+        cluster_domain,
+        drain.clone(),
+        metrics.outbound,
+        update_debounce,
+        authz,
+        watch_limits,
+    )
+    .svc();
+    // Responses are only compressed when the client advertises support for
+    // the encoding.
+    for encoding in compression {
+        inbound_svc = inbound_svc
+            .accept_compressed(encoding)
+            .send_compressed(encoding);
+        outbound_svc = outbound_svc
+            .accept_compressed(encoding)
+            .send_compressed(encoding);
+    }
+    let inbound_svc = InterceptedService::new(inbound_svc, require_synced.clone());
+    let outbound_svc = InterceptedService::new(outbound_svc, require_synced);
+
+    let (health, health_svc) = grpc::health::HealthServer::new(
+        [service_name(&inbound_svc), service_name(&outbound_svc)],
+        drain.clone(),
+    );
+    let health_svc = health_svc.svc();
+    let reflection_svc = grpc::reflection::ReflectionServer::new(
+        [
+            service_name(&health_svc),
+            service_name(&inbound_svc),
+            service_name(&outbound_svc),
+        ],
+        drain.clone(),
+    )
+    .svc();
+
+    let mut builder = Server::builder();
+    if let Some(tls) = tls {
+        builder = builder.tls_config(tls)?;
+    }
 
     let (close_tx, close_rx) = tokio::sync::oneshot::channel();
     tokio::pin! {
-        let srv = Server::builder().add_service(inbound_svc).add_service(outbound_svc).serve_with_shutdown(addr, close_rx.map(|_| {}));
+        let srv = builder.add_service(health_svc).add_service(inbound_svc).add_service(outbound_svc).add_service(reflection_svc).serve_with_shutdown(addr, close_rx.map(|_| {}));
     }
 
+    // The policy APIs are not serving until the indexes have synced, nor once
+    // the server starts draining.
+    let drained = async {
+        if synced.wait_for(|synced| *synced).await.is_ok() {
+            health.set_serving();
+        }
+        let handle = drain.signaled().await;
+        health.set_not_serving();
+        handle
+    };
+
     info!(%addr, "policy gRPC server listening");
+    tokio::select! {
+        res = (&mut srv) => res?,
+        handle = drained => {
+            let _ = close_tx.send(());
+            handle.release_after(srv).await?
+        }
+    }
+    Ok(())
+}
+
+/// Serves the policy simulation API on its own listener, since it does not
+/// authorize clients and so must not be exposed to workloads.
+#[instrument(skip_all, fields(port = %addr.port()))]
+async fn simulation_grpc(
+    addr: SocketAddr,
+    simulation: grpc::simulate::PolicySimulationServer<InboundDiscover, OutboundDiscover>,
+    require_synced: RequireSynced,
+    drain: drain::Watch,
+) -> Result<()> {
+    let simulation_svc = InterceptedService::new(simulation.svc(), require_synced);
+    let reflection_svc =
+        grpc::reflection::ReflectionServer::new([service_name(&simulation_svc)], drain.clone())
+            .svc();
+
+    let (close_tx, close_rx) = tokio::sync::oneshot::channel();
+    tokio::pin! {
+        let srv = Server::builder()
+            .add_service(simulation_svc)
+            .add_service(reflection_svc)
+            .serve_with_shutdown(addr, close_rx.map(|_| {}));
+    }
+
+    info!(%addr, "policy simulation gRPC server listening");
     tokio::select! {
         res = (&mut srv) => res?,
         handle = drain.signaled() => {
@@ -358,6 +719,10 @@ async fn grpc(
     Ok(())
 }
 
+fn service_name<S: NamedService>(_: &S) -> &'static str {
+    S::NAME
+}
+
 async fn init_lease(client: Client, ns: &str, deployment_name: &str) -> Result<LeaseManager> {
     // Fetch the policy-controller deployment so that we can use it as an owner
     // reference of the Lease.
@@ -1,4 +1,9 @@
 use crate::with_temp_ns;
+use kube::core::{
+    admission::{AdmissionResponse, AdmissionReview},
+    DynamicObject,
+};
+use linkerd_policy_controller::{Admission, RefChecks};
 
 pub async fn accepts<F, T>(f: F)
 where
@@ -41,3 +46,61 @@ where
     })
     .await;
 }
+
+/// Reviews the creation of a resource with an admission controller that is
+/// configured with the given reference checks, returning its response.
+///
+/// This exercises the admission controller directly, since its warnings are
+/// not exposed to API clients and its configuration is fixed by the
+/// installation.
+pub async fn review<T>(client: &kube::Client, ref_checks: RefChecks, obj: &T) -> AdmissionResponse
+where
+    T: kube::Resource<DynamicType = ()> + serde::Serialize,
+{
+    review_with(Admission::new(client.clone(), ref_checks, false), obj).await
+}
+
+/// Reviews the creation of a resource with the given admission controller,
+/// returning its response.
+pub async fn review_with<T>(mut admission: Admission, obj: &T) -> AdmissionResponse
+where
+    T: kube::Resource<DynamicType = ()> + serde::Serialize,
+{
+    let review = serde_json::json!({
+        "apiVersion": "admission.k8s.io/v1",
+        "kind": "AdmissionReview",
+        "request": {
+            "uid": "linkerd-policy-test",
+            "kind": {
+                "group": T::group(&()),
+                "version": T::version(&()),
+                "kind": T::kind(&()),
+            },
+            "resource": {
+                "group": T::group(&()),
+                "version": T::version(&()),
+                "resource": T::plural(&()),
+            },
+            "namespace": obj.meta().namespace,
+            "name": obj.meta().name,
+            "operation": "CREATE",
+            "userInfo": {},
+            "object": obj,
+        },
+    });
+    let req = hyper::Request::post("/")
+        .body(hyper::Body::from(
+            serde_json::to_vec(&review).expect("review must serialize"),
+        ))
+        .expect("request must be valid");
+
+    let rsp = hyper::service::Service::call(&mut admission, req)
+        .await
+        .expect("admission must respond");
+    let body = hyper::body::to_bytes(rsp.into_body())
+        .await
+        .expect("response body must be readable");
+    let review: AdmissionReview<DynamicObject> =
+        serde_json::from_slice(&body).expect("response must be an admission review");
+    review.response.expect("review must have a response")
+}
@@ -44,9 +44,11 @@ pub fn server(ns: &str) -> k8s::policy::Server {
             selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::from_iter(Some((
                 "app", "web",
             )))),
-            port: k8s::policy::server::Port::Name("http".to_string()),
+            port: Some(k8s::policy::server::Port::Name("http".to_string())),
+            ports: None,
             proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
         },
+        status: None,
     }
 }
 
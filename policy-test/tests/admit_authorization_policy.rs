@@ -1,8 +1,13 @@
+use linkerd_policy_controller::RefChecks;
 use linkerd_policy_controller_k8s_api::{
     self as api,
-    policy::{AuthorizationPolicy, AuthorizationPolicySpec, LocalTargetRef, NamespacedTargetRef},
+    policy::{
+        server::{Port, Selector},
+        AuthorizationPolicy, AuthorizationPolicySpec, LocalTargetRef, MeshTLSAuthentication,
+        MeshTLSAuthenticationSpec, NamespacedTargetRef, Server, ServerSpec,
+    },
 };
-use linkerd_policy_test::admission;
+use linkerd_policy_test::{admission, create, with_temp_ns};
 
 #[tokio::test(flavor = "current_thread")]
 async fn accepts_valid() {
@@ -33,6 +38,7 @@ async fn accepts_valid() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
@@ -66,6 +72,7 @@ async fn accepts_targets_namespace() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
@@ -99,6 +106,7 @@ async fn rejects_targets_other_namespace() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
@@ -132,6 +140,7 @@ async fn accepts_targets_route() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
@@ -157,6 +166,7 @@ async fn accepts_valid_with_only_meshtls() {
                 namespace: None,
             }],
         },
+        status: None,
     })
     .await;
 }
@@ -182,6 +192,7 @@ async fn accepts_valid_with_only_network() {
                 namespace: Some("linkerd".to_string()),
             }],
         },
+        status: None,
     })
     .await;
 }
@@ -202,6 +213,7 @@ async fn accepts_empty_required_authentications() {
             },
             required_authentication_refs: vec![],
         },
+        status: None,
     })
     .await;
 }
@@ -267,6 +279,7 @@ async fn rejects_target_ref_deployment() {
                 name: "cluster-nets".to_string(),
             }],
         },
+        status: None,
     })
     .await;
 }
@@ -300,6 +313,7 @@ async fn rejects_duplicate_mtls_authns() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
@@ -333,6 +347,122 @@ async fn rejects_duplicate_network_authns() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn ignores_missing_refs_when_disabled() {
+    with_temp_ns(|client, ns| async move {
+        let rsp = admission::review(&client, RefChecks::Disabled, &mk_policy(&ns)).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(rsp.warnings, None);
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn warns_about_missing_refs() {
+    with_temp_ns(|client, ns| async move {
+        let rsp = admission::review(&client, RefChecks::Warn, &mk_policy(&ns)).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(
+            rsp.warnings,
+            Some(vec![
+                format!("targetRef Server.policy.linkerd.io {ns}/api not found"),
+                format!(
+                    "requiredAuthenticationRef MeshTLSAuthentication.policy.linkerd.io \
+                    {ns}/mtls-clients not found"
+                ),
+            ])
+        );
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn denies_missing_refs() {
+    with_temp_ns(|client, ns| async move {
+        let rsp = admission::review(&client, RefChecks::Deny, &mk_policy(&ns)).await;
+        assert!(!rsp.allowed);
+        assert_eq!(
+            rsp.result.message,
+            format!(
+                "targetRef Server.policy.linkerd.io {ns}/api not found; \
+                requiredAuthenticationRef MeshTLSAuthentication.policy.linkerd.io \
+                {ns}/mtls-clients not found"
+            )
+        );
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_existing_refs() {
+    with_temp_ns(|client, ns| async move {
+        create(
+            &client,
+            Server {
+                metadata: api::ObjectMeta {
+                    namespace: Some(ns.clone()),
+                    name: Some("api".to_string()),
+                    ..Default::default()
+                },
+                spec: ServerSpec {
+                    selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "api")))),
+                    port: Some(Port::Number(8080.try_into().unwrap())),
+                    ports: None,
+                    proxy_protocol: None,
+                },
+                status: None,
+            },
+        )
+        .await;
+        create(
+            &client,
+            MeshTLSAuthentication {
+                metadata: api::ObjectMeta {
+                    namespace: Some(ns.clone()),
+                    name: Some("mtls-clients".to_string()),
+                    ..Default::default()
+                },
+                spec: MeshTLSAuthenticationSpec {
+                    identities: Some(vec!["*".to_string()]),
+                    identity_refs: None,
+                },
+                status: None,
+            },
+        )
+        .await;
+
+        let rsp = admission::review(&client, RefChecks::Deny, &mk_policy(&ns)).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(rsp.warnings, None);
+    })
+    .await;
+}
+
+fn mk_policy(ns: &str) -> AuthorizationPolicy {
+    AuthorizationPolicy {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: AuthorizationPolicySpec {
+            target_ref: LocalTargetRef {
+                group: Some("policy.linkerd.io".to_string()),
+                kind: "Server".to_string(),
+                name: "api".to_string(),
+            },
+            required_authentication_refs: vec![NamespacedTargetRef {
+                group: Some("policy.linkerd.io".to_string()),
+                kind: "MeshTLSAuthentication".to_string(),
+                name: "mtls-clients".to_string(),
+                namespace: None,
+            }],
+        },
+        status: None,
+    }
+}
@@ -1,5 +1,6 @@
+use linkerd_policy_controller::{Admission, RefChecks};
 use linkerd_policy_controller_k8s_api::{self as api, policy::httproute::*};
-use linkerd_policy_test::admission;
+use linkerd_policy_test::{admission, with_temp_ns};
 
 #[tokio::test(flavor = "current_thread")]
 async fn accepts_valid() {
@@ -83,6 +84,84 @@ async fn rejects_relative_redirect_path() {
     .await;
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_duplicate_match() {
+    with_temp_ns(|client, ns| async move {
+        let route = |name: &str| HttpRoute {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            spec: HttpRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: Some(vec![server_parent_ref(&ns)]),
+                },
+                hostnames: None,
+                rules: Some(rules()),
+            },
+            status: None,
+        };
+
+        let api = kube::Api::namespaced(client.clone(), &ns);
+        api.create(&kube::api::PostParams::default(), &route("test0"))
+            .await
+            .expect("resource must apply");
+
+        // Duplicate matches are only rejected when the check is enabled.
+        let rsp = admission::review(&client, RefChecks::Disabled, &route("test1")).await;
+        assert!(rsp.allowed);
+
+        let admission = Admission::new(client.clone(), RefChecks::Disabled, true);
+        let rsp = admission::review_with(admission.clone(), &route("test1")).await;
+        assert!(!rsp.allowed);
+
+        // The route itself can still be updated.
+        let rsp = admission::review_with(admission, &route("test0")).await;
+        assert!(rsp.allowed);
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn warns_about_noop_redirect() {
+    with_temp_ns(|client, ns| async move {
+        let route = HttpRoute {
+            metadata: meta(&ns),
+            spec: HttpRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: Some(vec![server_parent_ref(&ns)]),
+                },
+                hostnames: None,
+                rules: Some(vec![HttpRouteRule {
+                    filters: Some(vec![HttpRouteFilter::RequestRedirect {
+                        request_redirect: HttpRequestRedirectFilter {
+                            scheme: None,
+                            hostname: None,
+                            path: None,
+                            port: None,
+                            status_code: Some(301),
+                        },
+                    }]),
+                    ..rules().remove(0)
+                }]),
+            },
+            status: None,
+        };
+        let rsp = admission::review(&client, RefChecks::Disabled, &route).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(
+            rsp.warnings,
+            Some(vec![
+                "requestRedirect filter does not modify the scheme, hostname, path, or port, \
+                so requests would be redirected to their original location"
+                    .to_string()
+            ])
+        );
+    })
+    .await;
+}
+
 fn server_parent_ref(ns: impl ToString) -> ParentReference {
     ParentReference {
         group: Some("policy.linkerd.io".to_string()),
@@ -21,6 +21,7 @@ async fn accepts_valid_ref() {
             }]),
             ..Default::default()
         },
+        status: None,
     })
     .await;
 }
@@ -42,6 +43,7 @@ async fn accepts_ns_ref() {
             }]),
             ..Default::default()
         },
+        status: None,
     })
     .await;
 }
@@ -63,6 +65,7 @@ async fn rejects_namespaced_namespace() {
             }]),
             ..Default::default()
         },
+        status: None,
     })
     .await;
 }
@@ -79,6 +82,7 @@ async fn accepts_strings() {
             identities: Some(vec!["example.id".to_string()]),
             ..Default::default()
         },
+        status: None,
     })
     .await;
 }
@@ -92,6 +96,7 @@ async fn rejects_empty() {
             ..Default::default()
         },
         spec: MeshTLSAuthenticationSpec::default(),
+        status: None,
     })
     .await;
 }
@@ -113,6 +118,7 @@ async fn rejects_both_refs_and_strings() {
                 namespace: None,
             }]),
         },
+        status: None,
     })
     .await;
 }
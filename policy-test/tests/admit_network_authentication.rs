@@ -24,6 +24,7 @@ async fn accepts_valid() {
                 },
             ],
         },
+        status: None,
     })
     .await;
 }
@@ -42,6 +43,7 @@ async fn accepts_ip_except() {
                 except: Some(vec!["10.1.1.1".parse().unwrap()]),
             }],
         },
+        status: None,
     })
     .await;
 }
@@ -60,6 +62,7 @@ async fn rejects_except_whole_cidr() {
                 except: Some(vec!["10.1.0.0/16".parse().unwrap()]),
             }],
         },
+        status: None,
     })
     .await;
 }
@@ -78,6 +81,7 @@ async fn rejects_except_not_in_cidr() {
                 except: Some(vec!["10.1.2.0/24".parse().unwrap()]),
             }],
         },
+        status: None,
     })
     .await;
 }
@@ -137,6 +141,7 @@ async fn rejects_empty() {
             ..Default::default()
         },
         spec: NetworkAuthenticationSpec { networks: vec![] },
+        status: None,
     })
     .await;
 }
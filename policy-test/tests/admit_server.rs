@@ -1,8 +1,9 @@
+use linkerd_policy_controller::RefChecks;
 use linkerd_policy_controller_k8s_api::{
     self as api,
-    policy::server::{Port, ProxyProtocol, Selector, Server, ServerSpec},
+    policy::server::{Port, ProxyProtocol, Selector, Server, ServerSpec, ServiceRef},
 };
-use linkerd_policy_test::{admission, with_temp_ns};
+use linkerd_policy_test::{admission, create, with_temp_ns};
 
 #[tokio::test(flavor = "current_thread")]
 async fn accepts_valid() {
@@ -14,9 +15,11 @@ async fn accepts_valid() {
         },
         spec: ServerSpec {
             selector: Selector::Pod(api::labels::Selector::default()),
-            port: Port::Number(80.try_into().unwrap()),
+            port: Some(Port::Number(80.try_into().unwrap())),
+            ports: None,
             proxy_protocol: None,
         },
+        status: None,
     })
     .await;
 }
@@ -32,9 +35,11 @@ async fn accepts_server_updates() {
             },
             spec: ServerSpec {
                 selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "test")))),
-                port: Port::Number(80.try_into().unwrap()),
+                port: Some(Port::Number(80.try_into().unwrap())),
+                ports: None,
                 proxy_protocol: None,
             },
+            status: None,
         };
 
         let api = kube::Api::namespaced(client, &ns);
@@ -58,7 +63,8 @@ async fn rejects_identitical_pod_selector() {
     with_temp_ns(|client, ns| async move {
         let spec = ServerSpec {
             selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "test")))),
-            port: Port::Number(80.try_into().unwrap()),
+            port: Some(Port::Number(80.try_into().unwrap())),
+            ports: None,
             proxy_protocol: None,
         };
 
@@ -71,6 +77,7 @@ async fn rejects_identitical_pod_selector() {
                 ..Default::default()
             },
             spec: spec.clone(),
+            status: None,
         };
         api.create(&kube::api::PostParams::default(), &test0)
             .await
@@ -83,6 +90,217 @@ async fn rejects_identitical_pod_selector() {
                 ..Default::default()
             },
             spec,
+            status: None,
+        };
+        api.create(&kube::api::PostParams::default(), &test1)
+            .await
+            .expect_err("resource must not apply");
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_overlapping_port_ranges() {
+    with_temp_ns(|client, ns| async move {
+        let api = kube::Api::namespaced(client, &ns);
+
+        let test0 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some("test0".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "test")))),
+                port: None,
+                ports: Some(vec![
+                    Port::Number(80.try_into().unwrap()),
+                    Port::Range("9000-9010".parse().unwrap()),
+                ]),
+                proxy_protocol: None,
+            },
+            status: None,
+        };
+        api.create(&kube::api::PostParams::default(), &test0)
+            .await
+            .expect("resource must apply");
+
+        let mk_server = |name: &str, ports: Vec<Port>| Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "test")))),
+                port: None,
+                ports: Some(ports),
+                proxy_protocol: None,
+            },
+            status: None,
+        };
+
+        let test1 = mk_server("test1", vec![Port::Number(9005.try_into().unwrap())]);
+        api.create(&kube::api::PostParams::default(), &test1)
+            .await
+            .expect_err("resource must not apply");
+
+        let test2 = mk_server("test2", vec![Port::Range("9010-9020".parse().unwrap())]);
+        api.create(&kube::api::PostParams::default(), &test2)
+            .await
+            .expect_err("resource must not apply");
+
+        let test3 = mk_server("test3", vec![Port::Range("9011-9020".parse().unwrap())]);
+        api.create(&kube::api::PostParams::default(), &test3)
+            .await
+            .expect("resource must apply");
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_port_and_ports() {
+    admission::rejects(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            selector: Selector::Pod(api::labels::Selector::default()),
+            port: Some(Port::Number(80.try_into().unwrap())),
+            ports: Some(vec![Port::Number(81.try_into().unwrap())]),
+            proxy_protocol: None,
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_reversed_port_range() {
+    admission::rejects(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            selector: Selector::Pod(api::labels::Selector::default()),
+            port: None,
+            ports: Some(vec![Port::Name("9010-9000".to_string())]),
+            proxy_protocol: None,
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_opaque_port_list() {
+    admission::rejects(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            selector: Selector::Pod(api::labels::Selector::default()),
+            port: None,
+            ports: Some(vec![Port::Number(5432.try_into().unwrap())]),
+            proxy_protocol: Some(ProxyProtocol::Opaque),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_opaque_service_ref() {
+    admission::rejects(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            selector: Selector::Service(ServiceRef {
+                name: "web".to_string(),
+            }),
+            port: Some(Port::Number(5432.try_into().unwrap())),
+            ports: None,
+            proxy_protocol: Some(ProxyProtocol::Opaque),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_pod_selector_overlapping_service_ref() {
+    with_temp_ns(|client, ns| async move {
+        create(
+            &client,
+            api::Service {
+                metadata: api::ObjectMeta {
+                    namespace: Some(ns.clone()),
+                    name: Some("web".to_string()),
+                    ..Default::default()
+                },
+                spec: Some(api::ServiceSpec {
+                    selector: Some(
+                        std::iter::once(("app".to_string(), "web".to_string())).collect(),
+                    ),
+                    ports: Some(vec![api::ServicePort {
+                        name: Some("http".to_string()),
+                        port: 80,
+                        target_port: Some(api::apimachinery::pkg::util::intstr::IntOrString::Int(
+                            8080,
+                        )),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        let api = kube::Api::namespaced(client, &ns);
+        let test0 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some("test0".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                selector: Selector::Service(ServiceRef {
+                    name: "web".to_string(),
+                }),
+                port: Some(Port::Name("http".to_string())),
+                ports: None,
+                proxy_protocol: None,
+            },
+            status: None,
+        };
+        api.create(&kube::api::PostParams::default(), &test0)
+            .await
+            .expect("resource must apply");
+
+        // The Service's port targets the same pods' port.
+        let test1 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test1".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "web")))),
+                port: Some(Port::Number(8080.try_into().unwrap())),
+                ports: None,
+                proxy_protocol: None,
+            },
+            status: None,
         };
         api.create(&kube::api::PostParams::default(), &test1)
             .await
@@ -104,9 +322,11 @@ async fn rejects_all_pods_selected() {
             },
             spec: ServerSpec {
                 selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "test")))),
-                port: Port::Number(80.try_into().unwrap()),
+                port: Some(Port::Number(80.try_into().unwrap())),
+                ports: None,
                 proxy_protocol: Some(ProxyProtocol::Http2),
             },
+            status: None,
         };
         api.create(&kube::api::PostParams::default(), &test0)
             .await
@@ -120,10 +340,12 @@ async fn rejects_all_pods_selected() {
             },
             spec: ServerSpec {
                 selector: Selector::Pod(api::labels::Selector::default()),
-                port: Port::Number(80.try_into().unwrap()),
+                port: Some(Port::Number(80.try_into().unwrap())),
+                ports: None,
                 // proxy protocol doesn't factor into the selection
                 proxy_protocol: Some(ProxyProtocol::Http1),
             },
+            status: None,
         };
         api.create(&kube::api::PostParams::default(), &test1)
             .await
@@ -179,3 +401,87 @@ async fn rejects_invalid_proxy_protocol() {
     })
     .await;
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn warns_when_no_pods_are_selected() {
+    with_temp_ns(|client, ns| async move {
+        let server = mk_server(&ns, Port::Number(8080.try_into().unwrap()));
+        let rsp = admission::review(&client, RefChecks::Disabled, &server).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(
+            rsp.warnings,
+            Some(vec![format!(
+                "Server does not select any pods in namespace {ns}"
+            )])
+        );
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn warns_when_selected_pods_lack_port_name() {
+    with_temp_ns(|client, ns| async move {
+        // Only the pod matching the Server's selector is considered.
+        create(&client, mk_pod(&ns, "api", "admin-http")).await;
+        create(&client, mk_pod(&ns, "other", "http")).await;
+
+        let server = mk_server(&ns, Port::Name("http".to_string()));
+        let rsp = admission::review(&client, RefChecks::Disabled, &server).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(
+            rsp.warnings,
+            Some(vec![
+                "none of the 1 pods selected by this Server declare a TCP port named 'http'"
+                    .to_string()
+            ])
+        );
+
+        let server = mk_server(&ns, Port::Name("admin-http".to_string()));
+        let rsp = admission::review(&client, RefChecks::Disabled, &server).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(rsp.warnings, None);
+    })
+    .await;
+}
+
+fn mk_server(ns: &str, port: Port) -> Server {
+    Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            selector: Selector::Pod(api::labels::Selector::from_iter(Some(("app", "api")))),
+            port: Some(port),
+            ports: None,
+            proxy_protocol: None,
+        },
+        status: None,
+    }
+}
+
+fn mk_pod(ns: &str, app: &str, port_name: &str) -> api::Pod {
+    api::Pod {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(app.to_string()),
+            labels: Some(std::iter::once(("app".to_string(), app.to_string())).collect()),
+            ..Default::default()
+        },
+        spec: Some(api::PodSpec {
+            containers: vec![api::Container {
+                name: "pause".to_string(),
+                image: Some("gcr.io/google_containers/pause:3.2".to_string()),
+                ports: Some(vec![api::ContainerPort {
+                    name: Some(port_name.to_string()),
+                    container_port: 8080,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
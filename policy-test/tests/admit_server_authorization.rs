@@ -1,10 +1,11 @@
+use linkerd_policy_controller::RefChecks;
 use linkerd_policy_controller_k8s_api::{
     self as api,
     policy::server_authorization::{
         Client, MeshTls, Network, Server, ServerAuthorization, ServerAuthorizationSpec,
     },
 };
-use linkerd_policy_test::admission;
+use linkerd_policy_test::{admission, with_temp_ns};
 
 #[tokio::test(flavor = "current_thread")]
 async fn accepts_valid() {
@@ -241,3 +242,36 @@ async fn rejects_invalid_cidr() {
     })
     .await;
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn warns_about_deprecation() {
+    with_temp_ns(|client, ns| async move {
+        let saz = ServerAuthorization {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test".to_string()),
+                ..Default::default()
+            },
+            spec: ServerAuthorizationSpec {
+                server: Server {
+                    name: Some("test".to_string()),
+                    selector: None,
+                },
+                client: Client {
+                    networks: None,
+                    unauthenticated: true,
+                    mesh_tls: None,
+                },
+            },
+        };
+        let rsp = admission::review(&client, RefChecks::Disabled, &saz).await;
+        assert!(rsp.allowed, "{:?}", rsp.result);
+        assert_eq!(
+            rsp.warnings,
+            Some(vec![
+                "ServerAuthorization is deprecated; use AuthorizationPolicy instead".to_string()
+            ])
+        );
+    })
+    .await;
+}
@@ -573,6 +573,7 @@ fn authz_policy(
             target_ref: target,
             required_authentication_refs: authns.into_iter().collect(),
         },
+        status: None,
     }
 }
 
@@ -587,6 +588,7 @@ fn all_authenticated(ns: &str) -> k8s::policy::MeshTLSAuthentication {
             identity_refs: None,
             identities: Some(vec!["*".to_string()]),
         },
+        status: None,
     }
 }
 
@@ -606,6 +608,7 @@ fn ns_authenticated(ns: &str) -> k8s::policy::MeshTLSAuthentication {
             }]),
             identities: None,
         },
+        status: None,
     }
 }
 
@@ -628,6 +631,7 @@ fn allow_ips(
                 })
                 .collect(),
         },
+        status: None,
     }
 }
 
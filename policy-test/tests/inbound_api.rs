@@ -177,6 +177,7 @@ async fn server_with_authorization_policy() {
                         },
                     ],
                 },
+                status: None,
             },
         )
         .await;
@@ -195,6 +196,7 @@ async fn server_with_authorization_policy() {
                         k8s::policy::NamespacedTargetRef::from_resource(&all_nets),
                     ],
                 },
+                status: None,
             },
         )
         .await;
@@ -334,6 +336,7 @@ async fn server_with_http_route() {
                         },
                     ],
                 },
+                status: None,
             },
         )
         .await;
@@ -351,6 +354,7 @@ async fn server_with_http_route() {
                         k8s::policy::NamespacedTargetRef::from_resource(&all_nets),
                     ],
                 },
+                status: None,
             },
         )
         .await;
@@ -631,9 +635,11 @@ fn mk_admin_server(ns: &str, name: &str) -> k8s::policy::Server {
         },
         spec: k8s::policy::ServerSpec {
             selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::default()),
-            port: k8s::policy::server::Port::Number(4191.try_into().unwrap()),
+            port: Some(k8s::policy::server::Port::Number(4191.try_into().unwrap())),
+            ports: None,
             proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
         },
+        status: None,
     }
 }
 
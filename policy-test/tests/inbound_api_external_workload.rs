@@ -74,6 +74,7 @@ async fn external_workload_srv_with_authorization_policy() {
                     },
                     required_authentication_refs: vec![],
                 },
+                status: None,
             },
         )
         .await;
@@ -295,6 +296,7 @@ async fn external_workload_srv_with_http_route() {
                         },
                     ],
                 },
+                status: None,
             },
         )
         .await;
@@ -312,6 +314,7 @@ async fn external_workload_srv_with_http_route() {
                         k8s::policy::NamespacedTargetRef::from_resource(&all_networks),
                     ],
                 },
+                status: None,
             },
         )
         .await;
@@ -457,8 +460,10 @@ fn mk_http_server(ns: &str, name: &str) -> k8s::policy::Server {
             selector: k8s::policy::server::Selector::ExternalWorkload(
                 k8s::labels::Selector::default(),
             ),
-            port: k8s::policy::server::Port::Name("http".to_string()),
+            port: Some(k8s::policy::server::Port::Name("http".to_string())),
+            ports: None,
             proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
         },
+        status: None,
     }
 }
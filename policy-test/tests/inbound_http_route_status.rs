@@ -20,9 +20,11 @@ async fn inbound_accepted_parent() {
                 selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::from_iter(
                     Some(("app", server_name)),
                 )),
-                port: k8s::policy::server::Port::Name("http".to_string()),
+                port: Some(k8s::policy::server::Port::Name("http".to_string())),
+                ports: None,
                 proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
             },
+            status: None,
         };
         let server = create(&client, server).await;
         let srv_ref = vec![k8s::policy::httproute::ParentReference {
@@ -97,9 +99,11 @@ async fn inbound_multiple_parents() {
                 selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::from_iter(
                     Some(("app", "test-valid-server")),
                 )),
-                port: k8s::policy::server::Port::Name("http".to_string()),
+                port: Some(k8s::policy::server::Port::Name("http".to_string())),
+                ports: None,
                 proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
             },
+            status: None,
         };
         let _server = create(&client, server).await;
 
@@ -145,9 +149,11 @@ async fn inbound_no_parent_ref_patch() {
                 selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::from_iter(
                     Some(("app", server_name)),
                 )),
-                port: k8s::policy::server::Port::Name("http".to_string()),
+                port: Some(k8s::policy::server::Port::Name("http".to_string())),
+                ports: None,
                 proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
             },
+            status: None,
         };
         let server = create(&client, server).await;
         let srv_ref = vec![k8s::policy::httproute::ParentReference {
@@ -234,9 +240,11 @@ async fn inbound_accepted_reconcile_no_parent() {
                 selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::from_iter(
                     Some(("app", server_name)),
                 )),
-                port: k8s::policy::server::Port::Name("http".to_string()),
+                port: Some(k8s::policy::server::Port::Name("http".to_string())),
+                ports: None,
                 proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
             },
+            status: None,
         };
         create(&client, server).await;
 
@@ -283,9 +291,11 @@ async fn inbound_accepted_reconcile_parent_delete() {
                 selector: k8s::policy::server::Selector::Pod(k8s::labels::Selector::from_iter(
                     Some(("app", server_name)),
                 )),
-                port: k8s::policy::server::Port::Name("http".to_string()),
+                port: Some(k8s::policy::server::Port::Name("http".to_string())),
+                ports: None,
                 proxy_protocol: Some(k8s::policy::server::ProxyProtocol::Http1),
             },
+            status: None,
         };
         create(&client, server).await;
 